@@ -14,6 +14,10 @@ pub struct LapseModel {
     /// Bucket-specific coefficients
     pub bucket_coefficients: BucketCoefficients,
 
+    /// Change in treasury yield (decimal, e.g. 0.01 = +100bp) for the
+    /// interest-sensitive term. Scenario input, set from ProjectionConfig::treasury_change
+    pub treasury_change: f64,
+
     /// Pre-calculated base linear predictor by policy year (excluding ITM and bucket terms)
     /// Index 0 = policy year 1, etc.
     /// These are for the reference bucket [0, 50000)
@@ -37,6 +41,8 @@ pub struct LapseCoefficients {
     pub income_poly2: f64,
     /// Income × shock year interaction
     pub income_shock: f64,
+    /// Interest-sensitive effect on I(pmax(0, ChangeInTreasury))
+    pub treasury: f64,
 }
 
 impl Default for LapseCoefficients {
@@ -49,6 +55,7 @@ impl Default for LapseCoefficients {
             income_poly1: -0.0475413329089944,  // poly1:IncomeStartedY
             income_poly2: 0.000672806312295235, // poly2:IncomeStartedY
             income_shock: -0.317088440996024,   // IncomeStartedY:shock_year
+            treasury: 35.4408097077316,         // I(pmax(0, ChangeInTreasury))
         }
     }
 }
@@ -215,10 +222,14 @@ impl LapseModel {
         if let Some(&income_itm) = loaded.surrender_model.get("IncomeStartedY:I(pmax(0.5, pmin(1, ITMness)))") {
             coefficients.income_itm_low = income_itm;
         }
+        if let Some(&treasury) = loaded.surrender_model.get("I(pmax(0, ChangeInTreasury))") {
+            coefficients.treasury = treasury;
+        }
 
         Self {
             coefficients,
             bucket_coefficients: BucketCoefficients::default(),
+            treasury_change: 0.0,
             // Pre-calculated values for bucket [200000, Inf) (index 3)
             // These exclude ITM terms but INCLUDE bucket effects for [200000, Inf)
             // Bucket adjustments are calculated as differences from this base
@@ -245,6 +256,7 @@ impl LapseModel {
         Self {
             coefficients: LapseCoefficients::default(),
             bucket_coefficients: BucketCoefficients::default(),
+            treasury_change: 0.0,
             // Pre-calculated values for bucket [200000, Inf) (index 3)
             // These exclude ITM terms but INCLUDE bucket effects for [200000, Inf)
             // Bucket adjustments are calculated as differences from this base
@@ -352,8 +364,21 @@ impl LapseModel {
             + c.income_itm_low * income_ind * (itm_low_clamped - 1.0)
     }
 
+    /// Calculate the interest-sensitive component (linear predictor scale)
+    /// R: coef * I(pmax(0, ChangeInTreasury)) - only rising rates increase lapses
+    /// The pre-calculated base assumes ChangeInTreasury = 0, so this is purely additive
+    pub fn interest_sensitive_component(&self) -> f64 {
+        self.coefficients.treasury * self.treasury_change.max(0.0)
+    }
+
+    /// Return a copy of the model with the given treasury change applied
+    pub fn with_treasury_change(mut self, treasury_change: f64) -> Self {
+        self.treasury_change = treasury_change;
+        self
+    }
+
     /// Calculate annual lapse probability using log link with bucket adjustment
-    /// p = exp(base + dynamic)
+    /// p = exp(base + dynamic + interest)
     pub fn annual_lapse_prob_with_bucket(
         &self,
         policy_year: u32,
@@ -364,7 +389,7 @@ impl LapseModel {
     ) -> f64 {
         let base = self.base_component_with_bucket(policy_year, income_activated, bucket, sc_period);
         let dynamic = self.dynamic_component(itm_ness, income_activated);
-        let linear_predictor = base + dynamic;
+        let linear_predictor = base + dynamic + self.interest_sensitive_component();
 
         // Log link: p = exp(eta)
         // Cap at reasonable maximum to avoid overflow
@@ -416,7 +441,7 @@ impl LapseModel {
     /// Month 1: 40%, Month 2: 30%, Month 3: 20%, Months 4-12: 1/120 each
     pub fn monthly_lapse_rate_with_skew(
         &self,
-        projection_month: u32,
+        policy_month: u32,
         policy_year: u32,
         month_in_policy_year: u32,
         income_activated: bool,
@@ -424,8 +449,8 @@ impl LapseModel {
         sc_period: u32,
         bucket: BenefitBaseBucket,
     ) -> f64 {
-        // Month 1 has no lapse (Excel rule)
-        if projection_month == 1 {
+        // Issue month has no lapse (Excel rule)
+        if policy_month == 1 {
             return 0.0;
         }
 
@@ -557,4 +582,20 @@ mod tests {
         assert_eq!(calculate_itm_ness(120_000.0, 100_000.0), 1.2);
         assert_eq!(calculate_itm_ness(80_000.0, 100_000.0), 0.8);
     }
+
+    #[test]
+    fn test_treasury_change_increases_lapse() {
+        let model = LapseModel::default_predictive_model();
+        let base_rate = model.monthly_lapse_rate(24, 2, false, 1.3);
+
+        // Rising rates increase lapses: +100bp adds 0.354 to the linear predictor
+        let shocked = model.clone().with_treasury_change(0.01);
+        assert!((shocked.interest_sensitive_component() - 0.354408097077316).abs() < 1e-12);
+        assert!(shocked.monthly_lapse_rate(24, 2, false, 1.3) > base_rate);
+
+        // Falling rates have no effect (pmax(0, ChangeInTreasury))
+        let down = model.clone().with_treasury_change(-0.01);
+        assert_eq!(down.interest_sensitive_component(), 0.0);
+        assert_eq!(down.monthly_lapse_rate(24, 2, false, 1.3), base_rate);
+    }
 }
@@ -3,26 +3,78 @@
 //! Loads actuarial assumptions from CSV files in data/assumptions/
 
 use std::collections::HashMap;
-use std::error::Error;
+use std::fmt::Display;
 use std::fs::File;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use csv::StringRecord;
+
+use crate::error::ActuarialError;
 
 /// Default path to assumptions directory
 pub const DEFAULT_ASSUMPTIONS_PATH: &str = "data/assumptions";
 
+/// Rows of one assumption file, keeping the file path for error reporting
+struct AssumptionRows {
+    path: PathBuf,
+    rows: Vec<StringRecord>,
+}
+
+impl AssumptionRows {
+    /// Read every data row of `file_name` in the assumptions directory
+    fn read(dir: &Path, file_name: &str) -> Result<Self, ActuarialError> {
+        let path = dir.join(file_name);
+        let file = File::open(&path).map_err(|source| ActuarialError::AssumptionFileMissing {
+            path: path.clone(),
+            source,
+        })?;
+
+        let mut rows = Vec::new();
+        for result in csv::Reader::from_reader(file).into_records() {
+            let record = result.map_err(|e| ActuarialError::AssumptionMalformed {
+                path: path.clone(),
+                line: e.position().map_or(0, |p| p.line()),
+                message: e.to_string(),
+            })?;
+            rows.push(record);
+        }
+
+        Ok(Self { path, rows })
+    }
+
+    /// Parse column `index` of a row
+    fn parse<T>(&self, record: &StringRecord, index: usize) -> Result<T, ActuarialError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let malformed = |message: String| ActuarialError::AssumptionMalformed {
+            path: self.path.clone(),
+            line: record.position().map_or(0, |p| p.line()),
+            message,
+        };
+        let value = record
+            .get(index)
+            .ok_or_else(|| malformed(format!("missing column {}", index + 1)))?;
+        value
+            .trim()
+            .parse()
+            .map_err(|e| malformed(format!("column {}: cannot parse {:?}: {}", index + 1, value, e)))
+    }
+}
+
 /// Load mortality base rates from CSV
 /// Returns Vec<(female_rate, male_rate)> indexed by age
-pub fn load_mortality_base_rates(path: &Path) -> Result<Vec<(f64, f64)>, Box<dyn Error>> {
-    let file = File::open(path.join("mortality_base_rates.csv"))?;
-    let mut reader = csv::Reader::from_reader(file);
+pub fn load_mortality_base_rates(path: &Path) -> Result<Vec<(f64, f64)>, ActuarialError> {
+    let table = AssumptionRows::read(path, "mortality_base_rates.csv")?;
 
     let mut rates = vec![(0.0, 0.0); 121];
 
-    for result in reader.records() {
-        let record = result?;
-        let age: usize = record[0].parse()?;
-        let female: f64 = record[1].parse()?;
-        let male: f64 = record[2].parse()?;
+    for record in &table.rows {
+        let age: usize = table.parse(record, 0)?;
+        let female: f64 = table.parse(record, 1)?;
+        let male: f64 = table.parse(record, 2)?;
 
         if age < rates.len() {
             rates[age] = (female, male);
@@ -34,17 +86,15 @@ pub fn load_mortality_base_rates(path: &Path) -> Result<Vec<(f64, f64)>, Box<dyn
 
 /// Load mortality improvement rates from CSV
 /// Returns Vec<(female_rate, male_rate)> indexed by age
-pub fn load_mortality_improvement(path: &Path) -> Result<Vec<(f64, f64)>, Box<dyn Error>> {
-    let file = File::open(path.join("mortality_improvement.csv"))?;
-    let mut reader = csv::Reader::from_reader(file);
+pub fn load_mortality_improvement(path: &Path) -> Result<Vec<(f64, f64)>, ActuarialError> {
+    let table = AssumptionRows::read(path, "mortality_improvement.csv")?;
 
     let mut rates = vec![(0.0, 0.0); 121];
 
-    for result in reader.records() {
-        let record = result?;
-        let age: usize = record[0].parse()?;
-        let female: f64 = record[1].parse()?;
-        let male: f64 = record[2].parse()?;
+    for record in &table.rows {
+        let age: usize = table.parse(record, 0)?;
+        let female: f64 = table.parse(record, 1)?;
+        let male: f64 = table.parse(record, 2)?;
 
         if age < rates.len() {
             rates[age] = (female, male);
@@ -56,17 +106,15 @@ pub fn load_mortality_improvement(path: &Path) -> Result<Vec<(f64, f64)>, Box<dy
 
 /// Load mortality age factors from CSV
 /// Returns Vec<f64> indexed by age
-pub fn load_mortality_age_factors(path: &Path) -> Result<Vec<f64>, Box<dyn Error>> {
-    let file = File::open(path.join("mortality_age_factors.csv"))?;
-    let mut reader = csv::Reader::from_reader(file);
+pub fn load_mortality_age_factors(path: &Path) -> Result<Vec<f64>, ActuarialError> {
+    let table = AssumptionRows::read(path, "mortality_age_factors.csv")?;
 
     // Default to 1.0 for all ages
     let mut factors = vec![1.0; 121];
 
-    for result in reader.records() {
-        let record = result?;
-        let age: usize = record[0].parse()?;
-        let factor: f64 = record[1].parse()?;
+    for record in &table.rows {
+        let age: usize = table.parse(record, 0)?;
+        let factor: f64 = table.parse(record, 1)?;
 
         if age < factors.len() {
             factors[age] = factor;
@@ -78,16 +126,14 @@ pub fn load_mortality_age_factors(path: &Path) -> Result<Vec<f64>, Box<dyn Error
 
 /// Load surrender charges from CSV
 /// Returns Vec<f64> indexed by policy year (1-indexed in file, 0-indexed in vec)
-pub fn load_surrender_charges(path: &Path) -> Result<Vec<f64>, Box<dyn Error>> {
-    let file = File::open(path.join("surrender_charges.csv"))?;
-    let mut reader = csv::Reader::from_reader(file);
+pub fn load_surrender_charges(path: &Path) -> Result<Vec<f64>, ActuarialError> {
+    let table = AssumptionRows::read(path, "surrender_charges.csv")?;
 
     let mut charges = vec![0.0; 20]; // Support up to 20 years
 
-    for result in reader.records() {
-        let record = result?;
-        let year: usize = record[0].parse()?;
-        let charge: f64 = record[1].parse()?;
+    for record in &table.rows {
+        let year: usize = table.parse(record, 0)?;
+        let charge: f64 = table.parse(record, 1)?;
 
         if year > 0 && year <= charges.len() {
             charges[year - 1] = charge;
@@ -99,16 +145,14 @@ pub fn load_surrender_charges(path: &Path) -> Result<Vec<f64>, Box<dyn Error>> {
 
 /// Load RMD rates from CSV
 /// Returns Vec<(age, rate)> for ages with RMD requirements
-pub fn load_rmd_rates(path: &Path) -> Result<Vec<(u8, f64)>, Box<dyn Error>> {
-    let file = File::open(path.join("rmd_rates.csv"))?;
-    let mut reader = csv::Reader::from_reader(file);
+pub fn load_rmd_rates(path: &Path) -> Result<Vec<(u8, f64)>, ActuarialError> {
+    let table = AssumptionRows::read(path, "rmd_rates.csv")?;
 
     let mut rates = Vec::new();
 
-    for result in reader.records() {
-        let record = result?;
-        let age: u8 = record[0].parse()?;
-        let rate: f64 = record[1].parse()?;
+    for record in &table.rows {
+        let age: u8 = table.parse(record, 0)?;
+        let rate: f64 = table.parse(record, 1)?;
         rates.push((age, rate));
     }
 
@@ -117,16 +161,14 @@ pub fn load_rmd_rates(path: &Path) -> Result<Vec<(u8, f64)>, Box<dyn Error>> {
 
 /// Load free withdrawal utilization from CSV
 /// Returns Vec<f64> indexed by policy year (1-indexed in file)
-pub fn load_free_withdrawal_util(path: &Path) -> Result<Vec<f64>, Box<dyn Error>> {
-    let file = File::open(path.join("free_withdrawal_util.csv"))?;
-    let mut reader = csv::Reader::from_reader(file);
+pub fn load_free_withdrawal_util(path: &Path) -> Result<Vec<f64>, ActuarialError> {
+    let table = AssumptionRows::read(path, "free_withdrawal_util.csv")?;
 
     let mut utils = Vec::new();
 
-    for result in reader.records() {
-        let record = result?;
-        let _year: usize = record[0].parse()?;
-        let util: f64 = record[1].parse()?;
+    for record in &table.rows {
+        let _year: usize = table.parse(record, 0)?;
+        let util: f64 = table.parse(record, 1)?;
         utils.push(util);
     }
 
@@ -135,16 +177,35 @@ pub fn load_free_withdrawal_util(path: &Path) -> Result<Vec<f64>, Box<dyn Error>
 
 /// Load payout factors from CSV
 /// Returns HashMap<age, factor>
-pub fn load_payout_factors(path: &Path) -> Result<HashMap<u8, f64>, Box<dyn Error>> {
-    let file = File::open(path.join("payout_factors.csv"))?;
-    let mut reader = csv::Reader::from_reader(file);
+pub fn load_payout_factors(path: &Path) -> Result<HashMap<u8, f64>, ActuarialError> {
+    let table = AssumptionRows::read(path, "payout_factors.csv")?;
+
+    let mut factors = HashMap::new();
+
+    for record in &table.rows {
+        let age: u8 = table.parse(record, 0)?;
+        let factor: f64 = table.parse(record, 1)?;
+        factors.insert(age, factor);
+    }
+
+    Ok(factors)
+}
+
+/// Load joint life payout factors from CSV (keyed by the younger life's age)
+/// Returns an empty map when the product has no joint_payout_factors.csv
+/// (joint contracts are then rejected by `PayoutFactors::check_policy`)
+pub fn load_joint_payout_factors(path: &Path) -> Result<HashMap<u8, f64>, ActuarialError> {
+    if !path.join("joint_payout_factors.csv").exists() {
+        return Ok(HashMap::new());
+    }
+
+    let table = AssumptionRows::read(path, "joint_payout_factors.csv")?;
 
     let mut factors = HashMap::new();
 
-    for result in reader.records() {
-        let record = result?;
-        let age: u8 = record[0].parse()?;
-        let factor: f64 = record[1].parse()?;
+    for record in &table.rows {
+        let age: u8 = table.parse(record, 0)?;
+        let factor: f64 = table.parse(record, 1)?;
         factors.insert(age, factor);
     }
 
@@ -153,16 +214,14 @@ pub fn load_payout_factors(path: &Path) -> Result<HashMap<u8, f64>, Box<dyn Erro
 
 /// Load surrender predictive model coefficients from CSV
 /// Returns HashMap<term_name, coefficient>
-pub fn load_surrender_model(path: &Path) -> Result<HashMap<String, f64>, Box<dyn Error>> {
-    let file = File::open(path.join("surrender_predictive_model.csv"))?;
-    let mut reader = csv::Reader::from_reader(file);
+pub fn load_surrender_model(path: &Path) -> Result<HashMap<String, f64>, ActuarialError> {
+    let table = AssumptionRows::read(path, "surrender_predictive_model.csv")?;
 
     let mut coefficients = HashMap::new();
 
-    for result in reader.records() {
-        let record = result?;
-        let term = record[0].to_string();
-        let coef: f64 = record[1].parse()?;
+    for record in &table.rows {
+        let term: String = table.parse(record, 0)?;
+        let coef: f64 = table.parse(record, 1)?;
         coefficients.insert(term, coef);
     }
 
@@ -178,17 +237,18 @@ pub struct LoadedAssumptions {
     pub rmd_rates: Vec<(u8, f64)>,
     pub free_withdrawal_util: Vec<f64>,
     pub payout_factors: HashMap<u8, f64>,
+    pub joint_payout_factors: HashMap<u8, f64>,
     pub surrender_model: HashMap<String, f64>,
 }
 
 impl LoadedAssumptions {
     /// Load all assumptions from the default path
-    pub fn load_default() -> Result<Self, Box<dyn Error>> {
+    pub fn load_default() -> Result<Self, ActuarialError> {
         Self::load_from(Path::new(DEFAULT_ASSUMPTIONS_PATH))
     }
 
     /// Load all assumptions from a specific path
-    pub fn load_from(path: &Path) -> Result<Self, Box<dyn Error>> {
+    pub fn load_from(path: &Path) -> Result<Self, ActuarialError> {
         Ok(Self {
             mortality_base_rates: load_mortality_base_rates(path)?,
             mortality_improvement: load_mortality_improvement(path)?,
@@ -197,6 +257,7 @@ impl LoadedAssumptions {
             rmd_rates: load_rmd_rates(path)?,
             free_withdrawal_util: load_free_withdrawal_util(path)?,
             payout_factors: load_payout_factors(path)?,
+            joint_payout_factors: load_joint_payout_factors(path)?,
             surrender_model: load_surrender_model(path)?,
         })
     }
@@ -232,4 +293,26 @@ mod tests {
         // Check payout factors loaded
         assert!(!assumptions.payout_factors.is_empty());
     }
+
+    #[test]
+    fn test_assumption_file_errors() {
+        let dir = std::env::temp_dir().join(format!("assumptions_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        match load_rmd_rates(&dir) {
+            Err(ActuarialError::AssumptionFileMissing { path, .. }) => assert_eq!(path, dir.join("rmd_rates.csv")),
+            other => panic!("expected a missing file error, got {:?}", other),
+        }
+
+        std::fs::write(dir.join("rmd_rates.csv"), "Age,Rate\n73,0.0377\n74,abc\n").unwrap();
+        match load_rmd_rates(&dir) {
+            Err(ActuarialError::AssumptionMalformed { path, line, .. }) => {
+                assert_eq!(path, dir.join("rmd_rates.csv"));
+                assert_eq!(line, 3);
+            }
+            other => panic!("expected a malformed row error, got {:?}", other),
+        }
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
 }
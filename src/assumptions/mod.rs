@@ -4,16 +4,24 @@ mod mortality;
 mod lapse;
 mod product;
 mod pwd;
+mod valuation_rate;
 pub mod loader;
 
-pub use mortality::{MortalityTable, MonthlyConversion};
+pub use mortality::{MortalityTable, MonthlyConversion, JointSurvival, ContractMortality};
 pub use lapse::{LapseModel, calculate_itm_ness};
 pub use product::{SurrenderChargeSchedule, PayoutFactors, ProductFeatures};
 pub use pwd::{PwdAssumptions, RmdTable, FreeWithdrawalUtilization};
+pub use valuation_rate::{
+    PlanType, ReferenceRateHistory, ValuationRateBasis,
+    weighting_factor, dynamic_formula_rate, round_to_quarter_percent,
+};
 pub use loader::LoadedAssumptions;
 
 use std::path::Path;
 
+use crate::error::ActuarialError;
+use crate::policy::Policy;
+
 /// Container for all projection assumptions
 #[derive(Debug, Clone)]
 pub struct Assumptions {
@@ -35,12 +43,12 @@ impl Assumptions {
     }
 
     /// Load assumptions from CSV files in the default location (data/assumptions/)
-    pub fn from_csv() -> Result<Self, Box<dyn std::error::Error>> {
+    pub fn from_csv() -> Result<Self, ActuarialError> {
         Self::from_csv_path(Path::new(loader::DEFAULT_ASSUMPTIONS_PATH))
     }
 
     /// Load assumptions from CSV files in a specific directory
-    pub fn from_csv_path(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+    pub fn from_csv_path(path: &Path) -> Result<Self, ActuarialError> {
         let loaded = LoadedAssumptions::load_from(path)?;
 
         Ok(Self {
@@ -50,4 +58,9 @@ impl Assumptions {
             pwd: PwdAssumptions::from_loaded(&loaded),
         })
     }
+
+    /// Check the assumptions cover a policy (joint contracts need joint payout factors)
+    pub fn check_policy(&self, policy: &Policy) -> Result<(), ActuarialError> {
+        self.product.glwb.payout_factors.check_policy(policy)
+    }
 }
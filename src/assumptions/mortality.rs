@@ -7,7 +7,9 @@
 //!
 //! This allows each component to be adjusted independently for sensitivity testing.
 
-use crate::policy::Gender;
+use serde::{Deserialize, Serialize};
+
+use crate::policy::{Gender, JointLife, Policy};
 
 /// Mortality table with separate base rates and adjustment factors
 #[derive(Debug, Clone)]
@@ -419,6 +421,101 @@ impl MortalityTable {
     }
 }
 
+/// Survival of the two lives on a joint-life contract
+///
+/// Lives are independent. The contract stays in force until the second death,
+/// so its survival is the last-survivor probability.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct JointSurvival {
+    /// Probability the first life is alive
+    pub first: f64,
+
+    /// Probability the second life is alive
+    pub second: f64,
+}
+
+impl Default for JointSurvival {
+    fn default() -> Self {
+        Self { first: 1.0, second: 1.0 }
+    }
+}
+
+impl From<&JointLife> for JointSurvival {
+    /// Survival of each life at the policy's valuation date
+    fn from(joint: &JointLife) -> Self {
+        Self { first: joint.first_alive, second: joint.second_alive }
+    }
+}
+
+impl JointSurvival {
+    /// Probability at least one life is alive (contract in force)
+    pub fn last_survivor(&self) -> f64 {
+        self.first + self.second - self.first * self.second
+    }
+
+    /// Probability both lives are alive
+    pub fn both_alive(&self) -> f64 {
+        self.first * self.second
+    }
+
+    /// Advance one month with each life's monthly mortality
+    ///
+    /// Returns the contract's decrement rate for the month: the probability
+    /// that the second death occurs, given the contract was in force.
+    pub fn advance(&mut self, q_first: f64, q_second: f64) -> f64 {
+        let in_force = self.last_survivor();
+        self.first *= 1.0 - q_first;
+        self.second *= 1.0 - q_second;
+
+        if in_force > 0.0 {
+            1.0 - self.last_survivor() / in_force
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Monthly rates at which a contract terminates by death, month by month
+///
+/// Single life: the annuitant's mortality. Joint life: the last-survivor
+/// rate, starting from the policy's joint survival (see `JointLife`).
+pub struct ContractMortality<'a> {
+    table: &'a MortalityTable,
+    policy: &'a Policy,
+    month: u32,
+    joint: JointSurvival,
+}
+
+impl Iterator for ContractMortality<'_> {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<f64> {
+        let t = self.month;
+        self.month += 1;
+
+        let q = self.table.monthly_rate(self.policy.attained_age(t), self.policy.gender, t);
+        Some(match &self.policy.joint_life {
+            Some(joint) => {
+                let q_second = self.table.monthly_rate(joint.attained_age(self.policy, t), joint.gender, t);
+                self.joint.advance(q, q_second)
+            }
+            None => q,
+        })
+    }
+}
+
+impl MortalityTable {
+    /// Contract mortality for a policy from `start_month` onward
+    pub fn contract_rates<'a>(&'a self, policy: &'a Policy, start_month: u32) -> ContractMortality<'a> {
+        ContractMortality {
+            table: self,
+            policy,
+            month: start_month,
+            joint: policy.joint_life.as_ref().map(JointSurvival::from).unwrap_or_default(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -498,4 +595,44 @@ mod tests {
         assert_eq!(factors[80], 1.0);
         assert_eq!(factors[90], 1.0);
     }
+
+    #[test]
+    fn test_last_survivor_rates() {
+        use crate::policy::{QualStatus, CreditingStrategy, RollupType};
+
+        let table = MortalityTable::iam_2012_with_improvement();
+        let single = Policy::new(
+            1, QualStatus::Q, 70, Gender::Male, 130_000.0, 1.0, 100_000.0,
+            CreditingStrategy::Indexed, 10, 0.0475, 0.01, 0.3, RollupType::Simple,
+        );
+        let joint = single.clone().with_joint_life(68, Gender::Female);
+
+        // Single life follows the annuitant's table
+        let single_rates: Vec<f64> = table.contract_rates(&single, 0).take(24).collect();
+        assert_eq!(single_rates[13], table.monthly_rate(71, Gender::Male, 13));
+
+        // Nobody has died yet, so the first month's second-death probability is tiny
+        let joint_rates: Vec<f64> = table.contract_rates(&joint, 0).take(24).collect();
+        let q_male = table.monthly_rate(70, Gender::Male, 0);
+        let q_female = table.monthly_rate(68, Gender::Female, 0);
+        assert!((joint_rates[0] - q_male * q_female).abs() < 1e-15);
+        assert!(joint_rates.iter().zip(&single_rates).all(|(j, s)| j < s));
+
+        // Contract survival matches the last-survivor probability
+        let mut survival = JointSurvival::default();
+        for t in 0..24 {
+            survival.advance(
+                table.monthly_rate(single.attained_age(t), Gender::Male, t),
+                table.monthly_rate(joint.joint_life.unwrap().attained_age(&joint, t), Gender::Female, t),
+            );
+        }
+        let in_force: f64 = joint_rates.iter().map(|q| 1.0 - q).product();
+        assert!((in_force - survival.last_survivor()).abs() < 1e-12);
+
+        // After the annuitant's death the contract follows the survivor's table
+        let widowed = joint.clone().with_joint_survival(0.0, 1.0);
+        let widowed_rates: Vec<f64> = table.contract_rates(&widowed, 0).take(24).collect();
+        assert!((widowed_rates[0] - q_female).abs() < 1e-15);
+        assert!((widowed_rates[13] - table.monthly_rate(69, Gender::Female, 13)).abs() < 1e-15);
+    }
 }
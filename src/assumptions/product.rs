@@ -1,6 +1,9 @@
 //! Product features including surrender charges, payout factors, and rider terms
 
-use std::collections::HashMap;
+use std::collections::BTreeMap;
+
+use crate::error::ActuarialError;
+use crate::policy::Policy;
 
 /// Surrender charge schedule by policy year
 #[derive(Debug, Clone)]
@@ -60,9 +63,10 @@ impl SurrenderChargeSchedule {
 #[derive(Debug, Clone)]
 pub struct PayoutFactors {
     /// Single life payout factors by age band
-    single_life: HashMap<(u8, u8), f64>,
+    /// (ordered, so the Debug output used for assumption fingerprints is stable)
+    single_life: BTreeMap<(u8, u8), f64>,
     /// Joint life payout factors by age band (optional)
-    joint_life: Option<HashMap<(u8, u8), f64>>,
+    joint_life: Option<BTreeMap<(u8, u8), f64>>,
 }
 
 impl PayoutFactors {
@@ -70,7 +74,7 @@ impl PayoutFactors {
     pub fn from_loaded(factors: &std::collections::HashMap<u8, f64>) -> Self {
         // Convert direct age->factor mapping to age bands
         // For now, store as single-year bands
-        let mut single_life = HashMap::new();
+        let mut single_life = BTreeMap::new();
         for (&age, &factor) in factors {
             single_life.insert((age, age), factor);
         }
@@ -83,7 +87,7 @@ impl PayoutFactors {
     /// Create default payout factors from Product features sheet
     /// Uses per-age factors from Excel (not banded)
     pub fn default() -> Self {
-        let mut single_life = HashMap::new();
+        let mut single_life = BTreeMap::new();
 
         // Per-age payout factors from Excel Product features sheet
         // Ages 50-55 use band rate
@@ -153,6 +157,53 @@ impl PayoutFactors {
             None
         })
     }
+
+    /// Add joint life factors from loaded CSV data (HashMap<younger age, factor>)
+    pub fn with_joint_life(mut self, factors: &std::collections::HashMap<u8, f64>) -> Self {
+        self.joint_life = Some(factors.iter().map(|(&age, &factor)| ((age, age), factor)).collect());
+        self
+    }
+
+    /// Whether joint life factors are loaded
+    pub fn has_joint_life(&self) -> bool {
+        self.joint_life.is_some()
+    }
+
+    /// Payout factor for a policy activating at a projection month
+    ///
+    /// Joint contracts with both lives alive use the joint factor at the younger
+    /// life's age (the nearest joint age band when the age is not in the table).
+    /// Once a life has died the survivor's single life factor applies. Fails
+    /// for a joint payout when no joint factors are loaded.
+    pub fn for_policy(&self, policy: &Policy, projection_month: u32) -> Result<f64, ActuarialError> {
+        let age = policy.payout_age(projection_month);
+        if policy.has_joint_payout() {
+            self.nearest_joint_life(age).ok_or_else(|| {
+                ActuarialError::Config(format!(
+                    "policy {} is a joint contract but no joint payout factors are loaded (joint_payout_factors.csv)",
+                    policy.policy_id
+                ))
+            })
+        } else {
+            Ok(self.get_single_life(age))
+        }
+    }
+
+    /// Check the factors cover a policy: a joint payout needs joint factors
+    pub fn check_policy(&self, policy: &Policy) -> Result<(), ActuarialError> {
+        self.for_policy(policy, 0).map(|_| ())
+    }
+
+    /// Joint factor at an age, or at the nearest band when the age is not covered
+    fn nearest_joint_life(&self, attained_age: u8) -> Option<f64> {
+        let joint_life = self.joint_life.as_ref()?;
+        self.get_joint_life(attained_age).or_else(|| {
+            joint_life
+                .iter()
+                .min_by_key(|((min_age, max_age), _)| min_age.abs_diff(attained_age).min(max_age.abs_diff(attained_age)))
+                .map(|(_, &factor)| factor)
+        })
+    }
 }
 
 /// GLWB rider features
@@ -162,9 +213,11 @@ pub struct GlwbFeatures {
     pub min_activation_age: u8,
 
     /// Bonus percentage applied to initial premium for benefit base
+    /// (product default; the projection uses each policy's bonus)
     pub bonus_rate: f64,
 
     /// Annual rollup rate for benefit base
+    /// (product default; the projection uses each policy's rollup rate)
     pub rollup_rate: f64,
 
     /// Maximum years for rollup
@@ -265,6 +318,13 @@ pub struct BaseProductFeatures {
 
     /// First year commission rate (as decimal, e.g., 0.05 = 5%) - DEPRECATED, use CommissionAssumptions
     pub first_year_commission_rate: f64,
+
+    /// Portion of premium accumulating at the MGIR for the guaranteed
+    /// (nonforfeiture) account value (e.g., 0.875 = 87.5%)
+    pub nonforfeiture_premium_pct: f64,
+
+    /// Floor credited rates on Fixed crediting strategies at the policy MGIR
+    pub mgir_floor_on_fixed: bool,
 }
 
 impl Default for BaseProductFeatures {
@@ -279,6 +339,28 @@ impl Default for BaseProductFeatures {
             annual_expense_per_policy: 0.0,      // $0 - using expense_rate_of_av instead
             expense_rate_of_av: 0.0025,          // 0.25% of EOP AV annually
             first_year_commission_rate: 0.05,   // DEPRECATED - 5% first year commission
+            nonforfeiture_premium_pct: 0.875,    // 87.5% of premium at MGIR
+            mgir_floor_on_fixed: true,           // Fixed strategy guarantees MGIR
+        }
+    }
+}
+
+impl BaseProductFeatures {
+    /// Guaranteed (nonforfeiture) account value after `months` with no withdrawals or charges
+    /// = Premium * NF% * (1 + MGIR)^(months/12)
+    pub fn nonforfeiture_value(&self, premium: f64, mgir: f64, months: u32) -> f64 {
+        premium * self.nonforfeiture_premium_pct * (1.0 + mgir).powf(months as f64 / 12.0)
+    }
+
+    /// Guaranteed account value for a policy at a projection month, assuming
+    /// no withdrawals or rider charges since the snapshot
+    /// Accumulates the policy's current guaranteed AV for in-force snapshots,
+    /// otherwise accumulates premium from issue. The engine and the reserve
+    /// roll-forward start from this and reduce it as the AV is reduced.
+    pub fn policy_nonforfeiture_value(&self, policy: &Policy, month: u32) -> f64 {
+        match policy.current_guaranteed_av {
+            Some(current) => current * (1.0 + policy.mgir).powf(month as f64 / 12.0),
+            None => self.nonforfeiture_value(policy.initial_premium, policy.mgir, policy.duration_months + month),
         }
     }
 }
@@ -356,14 +438,14 @@ impl CommissionAssumptions {
         }
     }
 
-    /// Get chargeback factor based on projection month
+    /// Get chargeback factor based on policy month
     /// 100% for months 1-6, 50% for months 7-12, 0% after
-    pub fn chargeback_factor(&self, projection_month: u32, policy_year: u32) -> f64 {
+    pub fn chargeback_factor(&self, policy_month: u32, policy_year: u32) -> f64 {
         if policy_year > 1 {
             0.0
-        } else if projection_month <= self.chargeback_months_full {
+        } else if policy_month <= self.chargeback_months_full {
             1.0
-        } else if projection_month <= self.chargeback_months_half {
+        } else if policy_month <= self.chargeback_months_half {
             0.5
         } else {
             0.0
@@ -416,6 +498,9 @@ impl ProductFeatures {
         let mut features = Self::default();
         features.base.surrender_charges = SurrenderChargeSchedule::from_loaded(&loaded.surrender_charges);
         features.glwb.payout_factors = PayoutFactors::from_loaded(&loaded.payout_factors);
+        if !loaded.joint_payout_factors.is_empty() {
+            features.glwb.payout_factors = features.glwb.payout_factors.with_joint_life(&loaded.joint_payout_factors);
+        }
         features
     }
 }
@@ -448,6 +533,39 @@ mod tests {
         assert_eq!(pf.get_single_life(90), 0.0895);  // Age 90+
     }
 
+    #[test]
+    fn test_joint_payout_factors() {
+        use crate::policy::{QualStatus, Gender, CreditingStrategy, RollupType};
+
+        let joint_factors = [(65, 0.0555), (67, 0.057)].into_iter().collect();
+        let pf = PayoutFactors::default().with_joint_life(&joint_factors);
+        assert!(pf.has_joint_life());
+
+        let policy = Policy::new(
+            1, QualStatus::Q, 67, Gender::Male, 130_000.0, 1.0, 100_000.0,
+            CreditingStrategy::Indexed, 10, 0.0475, 0.01, 0.3, RollupType::Simple,
+        );
+        assert_eq!(pf.for_policy(&policy, 1).unwrap(), 0.062);
+
+        // Joint factor at the younger life's age
+        let joint = policy.clone().with_joint_life(65, Gender::Female);
+        assert_eq!(pf.for_policy(&joint, 1).unwrap(), 0.0555);
+        assert_eq!(pf.for_policy(&joint, 25).unwrap(), 0.057);
+        assert_eq!(pf.for_policy(&joint, 13).unwrap(), 0.0555);  // Age 66 not in the table
+        assert!(pf.check_policy(&joint).is_ok());
+
+        // After the first death the survivor's single life factor applies
+        assert_eq!(pf.for_policy(&joint.clone().with_joint_survival(1.0, 0.0), 1).unwrap(), 0.062);
+        assert_eq!(pf.for_policy(&joint.clone().with_joint_survival(0.0, 1.0), 1).unwrap(), 0.0605);
+
+        // Without joint factors a joint contract is rejected, not priced single life
+        let single_only = PayoutFactors::default();
+        assert!(matches!(single_only.check_policy(&joint), Err(ActuarialError::Config(_))));
+        assert!(single_only.for_policy(&joint, 1).is_err());
+        assert!(single_only.check_policy(&policy).is_ok());
+        assert!(single_only.check_policy(&joint.with_joint_survival(0.0, 1.0)).is_ok());
+    }
+
     #[test]
     fn test_glwb_rollup() {
         let glwb = GlwbFeatures::default();
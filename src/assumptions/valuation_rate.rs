@@ -0,0 +1,347 @@
+//! Statutory maximum valuation interest rates (Standard Valuation Law)
+//!
+//! Dynamic formula for single-premium deferred annuities with cash settlement
+//! options, valued on an issue-year basis:
+//!
+//!   I = 0.03 + W * (R1 - 0.03) + W/2 * (R2 - 0.09)
+//!
+//! where R1 = min(R, 9%), R2 = max(R, 9%), R is the reference rate and W the
+//! weighting factor for the plan type and guarantee duration. The result is
+//! rounded to the nearest 0.25%.
+//!
+//! The reference rate is the average of the monthly corporate bond yield
+//! (Moody's Monthly Average Corporates) over the 12 months ending June 30 of
+//! the issue year; for guarantee durations over 10 years it is the lesser of
+//! that and the average over the 36 months ending June 30.
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::error::ActuarialError;
+use crate::policy::Policy;
+
+/// Withdrawal plan type for the weighting factors
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlanType {
+    /// No withdrawal, or withdrawal only with a market value adjustment,
+    /// in installments over five years or more, or as a life annuity
+    A,
+
+    /// Withdrawal without adjustment only at the end of the interest guarantee
+    B,
+
+    /// Withdrawal at any time without market value adjustment
+    /// (surrender charges only), e.g. our FIA
+    C,
+}
+
+/// Weighting factor for an issue-year-basis annuity with cash settlement options
+///
+/// Single-premium contracts that do not guarantee interest on considerations
+/// received more than a year after issue add 0.15 / 0.25 / 0.05 for plan
+/// types A / B / C.
+pub fn weighting_factor(plan_type: PlanType, guarantee_duration_years: u32, guarantees_future_considerations: bool) -> f64 {
+    let (a, b, c) = match guarantee_duration_years {
+        0..=5 => (0.80, 0.60, 0.50),
+        6..=10 => (0.75, 0.60, 0.50),
+        11..=20 => (0.65, 0.50, 0.45),
+        _ => (0.45, 0.35, 0.35),
+    };
+    let (base, increase) = match plan_type {
+        PlanType::A => (a, 0.15),
+        PlanType::B => (b, 0.25),
+        PlanType::C => (c, 0.05),
+    };
+
+    if guarantees_future_considerations {
+        base
+    } else {
+        base + increase
+    }
+}
+
+/// SVL dynamic formula rate before rounding
+pub fn dynamic_formula_rate(reference_rate: f64, weighting_factor: f64) -> f64 {
+    let r1 = reference_rate.min(0.09);
+    let r2 = reference_rate.max(0.09);
+    0.03 + weighting_factor * (r1 - 0.03) + weighting_factor / 2.0 * (r2 - 0.09)
+}
+
+/// Round a rate to the nearest 0.25% (halves round up)
+pub fn round_to_quarter_percent(rate: f64) -> f64 {
+    // Nudge so that exact halves survive floating-point error
+    (rate * 400.0 + 1e-9).round() / 400.0
+}
+
+/// Monthly history of the reference corporate bond yield
+#[derive(Debug, Clone, Default)]
+pub struct ReferenceRateHistory {
+    /// (year, month) -> monthly average yield (decimal)
+    rates: BTreeMap<(i32, u32), f64>,
+}
+
+impl ReferenceRateHistory {
+    /// Create from (year, month, rate) observations
+    pub fn new(observations: impl IntoIterator<Item = (i32, u32, f64)>) -> Self {
+        Self {
+            rates: observations
+                .into_iter()
+                .map(|(year, month, rate)| ((year, month), rate))
+                .collect(),
+        }
+    }
+
+    /// Load from a CSV file with `month,rate` columns
+    ///
+    /// Months are `YYYY-MM` (or a `YYYY-MM-DD` date in the month) and rates
+    /// are decimals (0.0525 = 5.25%).
+    pub fn from_csv(path: &Path) -> Result<Self, ActuarialError> {
+        let file = File::open(path).map_err(|source| ActuarialError::AssumptionFileMissing {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::read(file, path)
+    }
+
+    /// Load from any CSV reader with `month,rate` columns
+    pub fn from_reader<R: Read>(reader: R) -> Result<Self, ActuarialError> {
+        Self::read(reader, Path::new("rate history"))
+    }
+
+    /// Parse `month,rate` rows, reporting errors against `path`
+    fn read<R: Read>(reader: R, path: &Path) -> Result<Self, ActuarialError> {
+        let mut reader = csv::Reader::from_reader(reader);
+        let mut rates = BTreeMap::new();
+
+        for result in reader.records() {
+            let malformed = |line: Option<&csv::Position>, message: String| ActuarialError::AssumptionMalformed {
+                path: path.to_path_buf(),
+                line: line.map_or(0, |p| p.line()),
+                message,
+            };
+            let record = result.map_err(|e| malformed(e.position(), e.to_string()))?;
+            let month_str = record.get(0).unwrap_or("").trim();
+            let rate_str = record.get(1).unwrap_or("").trim();
+            let date = NaiveDate::parse_from_str(month_str, "%Y-%m-%d")
+                .or_else(|_| NaiveDate::parse_from_str(&format!("{}-01", month_str), "%Y-%m-%d"))
+                .map_err(|_| malformed(record.position(), format!("Invalid month in rate history: {}", month_str)))?;
+            let rate: f64 = rate_str
+                .parse()
+                .map_err(|_| malformed(record.position(), format!("Invalid rate in rate history: {}", rate_str)))?;
+            rates.insert((date.year(), date.month()), rate);
+        }
+
+        Ok(Self { rates })
+    }
+
+    /// Number of monthly observations
+    pub fn len(&self) -> usize {
+        self.rates.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rates.is_empty()
+    }
+
+    /// Average of the `months` monthly rates ending with (year, month)
+    pub fn average(&self, year: i32, month: u32, months: u32) -> Result<f64, ActuarialError> {
+        let mut total = 0.0;
+        let (mut y, mut m) = (year, month);
+
+        for _ in 0..months {
+            let rate = self
+                .rates
+                .get(&(y, m))
+                .ok_or_else(|| ActuarialError::Config(format!("Rate history has no rate for {}-{:02}", y, m)))?;
+            total += rate;
+
+            if m == 1 {
+                y -= 1;
+                m = 12;
+            } else {
+                m -= 1;
+            }
+        }
+
+        Ok(total / months.max(1) as f64)
+    }
+
+    /// Reference rate for an issue year: average over the 12 months ending
+    /// June 30 of that year (for guarantee durations over 10 years, the lesser
+    /// of the 36- and 12-month averages)
+    pub fn reference_rate(&self, issue_year: i32, guarantee_duration_years: u32) -> Result<f64, ActuarialError> {
+        let avg12 = self.average(issue_year, 6, 12)?;
+        if guarantee_duration_years > 10 {
+            Ok(self.average(issue_year, 6, 36)?.min(avg12))
+        } else {
+            Ok(avg12)
+        }
+    }
+}
+
+/// Basis for assigning statutory valuation rates to policies
+#[derive(Debug, Clone)]
+pub struct ValuationRateBasis {
+    /// Reference rate history
+    pub history: ReferenceRateHistory,
+
+    /// Withdrawal plan type
+    pub plan_type: PlanType,
+
+    /// Interest guarantee duration in years
+    /// None = each policy's surrender charge period
+    pub guarantee_duration_years: Option<u32>,
+
+    /// Whether the contract guarantees interest on considerations received
+    /// more than a year after issue (false for single-premium contracts)
+    pub guarantees_future_considerations: bool,
+}
+
+impl ValuationRateBasis {
+    /// Single-premium deferred annuity basis for a plan type
+    pub fn spda(history: ReferenceRateHistory, plan_type: PlanType) -> Self {
+        Self {
+            history,
+            plan_type,
+            guarantee_duration_years: None,
+            guarantees_future_considerations: false,
+        }
+    }
+
+    /// Statutory maximum valuation rate for an issue date and guarantee duration
+    pub fn valuation_rate(&self, issue_date: NaiveDate, guarantee_duration_years: u32) -> Result<f64, ActuarialError> {
+        let reference = self.history.reference_rate(issue_date.year(), guarantee_duration_years)?;
+        let weight = weighting_factor(
+            self.plan_type,
+            guarantee_duration_years,
+            self.guarantees_future_considerations,
+        );
+        Ok(round_to_quarter_percent(dynamic_formula_rate(reference, weight)))
+    }
+
+    /// Statutory valuation rate for a policy (None if it has no issue date)
+    pub fn policy_valuation_rate(&self, policy: &Policy) -> Result<Option<f64>, ActuarialError> {
+        let Some(issue_date) = policy.issue_date else {
+            return Ok(None);
+        };
+        let duration = self.guarantee_duration_years.unwrap_or(policy.sc_period as u32);
+        self.valuation_rate(issue_date, duration)
+            .map(Some)
+            .map_err(|e| match e {
+                ActuarialError::Config(message) => ActuarialError::Config(format!("Policy {}: {}", policy.policy_id, message)),
+                other => other,
+            })
+    }
+
+    /// Set `val_rate` on every dated policy; undated policies keep their rate
+    pub fn assign(&self, policies: &mut [Policy]) -> Result<(), ActuarialError> {
+        for policy in policies.iter_mut() {
+            if let Some(rate) = self.policy_valuation_rate(policy)? {
+                policy.val_rate = rate;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn level_history(rate: f64) -> ReferenceRateHistory {
+        ReferenceRateHistory::new(
+            (2018..=2024).flat_map(|year| (1..=12).map(move |month| (year, month, rate))),
+        )
+    }
+
+    #[test]
+    fn test_dynamic_formula() {
+        // Reference 5.5%, Plan C <= 5 years, single premium: W = 0.55
+        let rate = dynamic_formula_rate(0.055, weighting_factor(PlanType::C, 5, false));
+        assert!((rate - (0.03 + 0.55 * 0.025)).abs() < 1e-12);
+        assert!((round_to_quarter_percent(rate) - 0.0450).abs() < 1e-12);
+
+        // Above 9% only half the weight applies to the excess
+        let high = dynamic_formula_rate(0.11, 0.50);
+        assert!((high - (0.03 + 0.50 * 0.06 + 0.25 * 0.02)).abs() < 1e-12);
+
+        assert!((round_to_quarter_percent(0.04374) - 0.0425).abs() < 1e-12);
+        assert!((round_to_quarter_percent(0.04376) - 0.0450).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_weighting_factors() {
+        assert_eq!(weighting_factor(PlanType::A, 3, true), 0.80);
+        assert_eq!(weighting_factor(PlanType::B, 10, true), 0.60);
+        assert_eq!(weighting_factor(PlanType::C, 15, true), 0.45);
+        assert_eq!(weighting_factor(PlanType::A, 25, true), 0.45);
+        assert!((weighting_factor(PlanType::B, 7, false) - 0.85).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_reference_rate_window() {
+        // 12-month window ending June 2023: Jul 2022 - Jun 2023
+        let history = ReferenceRateHistory::new(
+            (1..=12).map(|m| (2022, m, 0.04)).chain((1..=12).map(|m| (2023, m, 0.06))),
+        );
+        assert!((history.reference_rate(2023, 10).unwrap() - 0.05).abs() < 1e-12);
+
+        // Longer guarantees need 36 months of history
+        assert!(history.reference_rate(2023, 15).is_err());
+
+        // Longer guarantees take the lesser of the 36- and 12-month averages
+        let yearly = |rates: [f64; 4]| {
+            ReferenceRateHistory::new((2020..=2023).zip(rates).flat_map(|(y, r)| (1..=12).map(move |m| (y, m, r))))
+        };
+
+        // Rising rates: the 36-month average (18 x 3%, 12 x 5%, 6 x 6%) is lower
+        let rising = yearly([0.03, 0.03, 0.05, 0.06]);
+        assert!((rising.reference_rate(2023, 10).unwrap() - 0.055).abs() < 1e-12);
+        assert!((rising.reference_rate(2023, 15).unwrap() - 1.5 / 36.0).abs() < 1e-12);
+
+        // Falling rates: the 12-month average (6 x 5%, 6 x 3%) is lower
+        let falling = yearly([0.06, 0.06, 0.05, 0.03]);
+        assert!((falling.reference_rate(2023, 15).unwrap() - 0.04).abs() < 1e-12);
+        assert!((falling.reference_rate(2023, 15).unwrap() - falling.reference_rate(2023, 10).unwrap()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_history_from_csv() {
+        let data = "month,rate\n2023-05,0.055\n2023-06-30,0.057\n";
+        let history = ReferenceRateHistory::from_reader(data.as_bytes()).unwrap();
+        assert_eq!(history.len(), 2);
+        assert!((history.average(2023, 6, 2).unwrap() - 0.056).abs() < 1e-12);
+
+        assert!(ReferenceRateHistory::from_reader("month,rate\nJune,0.05\n".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_assign_valuation_rates() {
+        use crate::policy::{QualStatus, Gender, CreditingStrategy, RollupType};
+
+        let mut policies = vec![
+            Policy::new(
+                1, QualStatus::Q, 65, Gender::Male, 130_000.0, 1.0, 100_000.0,
+                CreditingStrategy::Indexed, 10, 0.0475, 0.01, 0.3, RollupType::Simple,
+            ),
+            Policy::new(
+                2, QualStatus::N, 70, Gender::Female, 130_000.0, 1.0, 100_000.0,
+                CreditingStrategy::Fixed, 10, 0.0475, 0.01, 0.3, RollupType::Simple,
+            ),
+        ];
+        policies[0].issue_date = NaiveDate::from_ymd_opt(2023, 3, 1);
+
+        // Reference 5.5%, 10-year guarantee, Plan C single premium: 3% + 0.55 * 2.5% = 4.375% -> 4.50%
+        let basis = ValuationRateBasis::spda(level_history(0.055), PlanType::C);
+        basis.assign(&mut policies).unwrap();
+        assert!((policies[0].val_rate - 0.045).abs() < 1e-12);
+        assert_eq!(policies[1].val_rate, 0.0475);
+
+        policies[1].issue_date = NaiveDate::from_ymd_opt(2010, 1, 1);
+        assert!(basis.assign(&mut policies).is_err());
+    }
+}
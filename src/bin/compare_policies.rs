@@ -3,7 +3,7 @@
 //! Usage: cargo run --bin compare_policies
 
 use actuarial_system::{
-    Assumptions,
+    ActuarialError, Assumptions,
     projection::{
         ProjectionEngine, ProjectionConfig, CashflowRow, CreditingApproach, HedgeParams,
         DEFAULT_FIXED_ANNUAL_RATE, DEFAULT_INDEXED_ANNUAL_RATE,
@@ -13,11 +13,11 @@ use actuarial_system::policy::load_default_inforce;
 use std::fs::File;
 use std::io::{BufRead, BufReader, Write};
 
-fn main() {
+fn main() -> Result<(), ActuarialError> {
     let policy_ids = vec![4, 1404];
 
     println!("Loading policies from pricing_inforce.csv...");
-    let all_policies = load_default_inforce().expect("Failed to load policies");
+    let all_policies = load_default_inforce()?;
 
     let assumptions = Assumptions::default_pricing();
     let config = ProjectionConfig {
@@ -31,6 +31,7 @@ fn main() {
         fixed_lapse_rate: None,
         hedge_params: Some(HedgeParams::default()),
         reserve_config: None,
+        reinsurance: None,
     };
 
     for policy_id in policy_ids {
@@ -41,28 +42,30 @@ fn main() {
         // Find the policy
         let policy = all_policies.iter()
             .find(|p| p.policy_id == policy_id)
-            .expect(&format!("Policy {} not found", policy_id));
+            .ok_or_else(|| ActuarialError::Config(format!("policy {} not found in pricing_inforce.csv", policy_id)))?;
 
         println!("  Issue Age: {}, GLWB Start Year: {}, Crediting: {:?}",
                  policy.issue_age, policy.glwb_start_year, policy.crediting_strategy);
 
         // Run projection
         let engine = ProjectionEngine::new(assumptions.clone(), config.clone());
-        let result = engine.project_policy(policy);
+        let result = engine.project_policy(policy)?;
 
         // Write Rust output
         let rust_output_path = format!("cashflow_examples/rust_output_{}.csv", policy_id);
-        write_rust_output(&rust_output_path, &result.cashflows);
+        write_rust_output(&rust_output_path, &result.cashflows)?;
         println!("  Rust output written to: {}", rust_output_path);
 
         // Load Excel output and compare
         let excel_path = format!("cashflow_examples/output_{}.csv", policy_id);
-        compare_outputs(&excel_path, &result.cashflows, policy_id);
+        compare_outputs(&excel_path, &result.cashflows, policy_id)?;
     }
+
+    Ok(())
 }
 
-fn write_rust_output(path: &str, cashflows: &[CashflowRow]) {
-    let mut file = File::create(path).expect("Failed to create output file");
+fn write_rust_output(path: &str, cashflows: &[CashflowRow]) -> Result<(), ActuarialError> {
+    let mut file = File::create(path)?;
 
     // Header matching Excel format
     writeln!(file, "Projection month,Policy year,Month in policy year,Attained age,\
@@ -72,7 +75,7 @@ fn write_rust_output(path: &str, cashflows: &[CashflowRow]) {
         Systematic withdrawal,Rollup rate,AV persistency,BB persistency,Lives persistency,\
         Lives,Pre-decrement AV,Mortality,Lapse,PWD,Rider charges,Surrender charges,\
         Interest credits,EOP AV,Expenses,Agent Commission,IMO Override,Wholesaler Override,\
-        Chargebacks,Bonus comp,Total net cashflow,Net index credit reimbursement,Hedge gains").unwrap();
+        Chargebacks,Bonus comp,Total net cashflow,Net index credit reimbursement,Hedge gains")?;
 
     for row in cashflows {
         writeln!(file, "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
@@ -119,16 +122,18 @@ fn write_rust_output(path: &str, cashflows: &[CashflowRow]) {
             row.total_net_cashflow,
             row.net_index_credit_reimbursement,
             row.hedge_gains,
-        ).unwrap();
+        )?;
     }
+
+    Ok(())
 }
 
-fn compare_outputs(excel_path: &str, rust_cashflows: &[CashflowRow], _policy_id: u32) {
+fn compare_outputs(excel_path: &str, rust_cashflows: &[CashflowRow], _policy_id: u32) -> Result<(), ActuarialError> {
     let file = match File::open(excel_path) {
         Ok(f) => f,
         Err(_) => {
             println!("  Excel file not found: {}", excel_path);
-            return;
+            return Ok(());
         }
     };
 
@@ -150,7 +155,7 @@ fn compare_outputs(excel_path: &str, rust_cashflows: &[CashflowRow], _policy_id:
             break;
         }
 
-        let line = line_result.expect("Failed to read line");
+        let line = line_result?;
         let fields: Vec<&str> = line.split(',').collect();
 
         // Column indices from Excel format:
@@ -219,4 +224,6 @@ fn compare_outputs(excel_path: &str, rust_cashflows: &[CashflowRow], _policy_id:
     if !first_divergence_found {
         println!("\n  ✓ No significant divergence found in first {} months!", rust_cashflows.len());
     }
+
+    Ok(())
 }
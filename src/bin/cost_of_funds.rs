@@ -9,10 +9,10 @@
 //! Set USE_DYNAMIC_INFORCE=1 to generate policies dynamically instead of loading CSV
 
 use actuarial_system::{
-    Assumptions,
+    ActuarialError, Assumptions,
     projection::{
         ProjectionEngine, ProjectionConfig, CreditingApproach, HedgeParams,
-        calculate_cost_of_funds, DEFAULT_FIXED_ANNUAL_RATE, DEFAULT_INDEXED_ANNUAL_RATE,
+        solve_cost_of_funds, DEFAULT_FIXED_ANNUAL_RATE, DEFAULT_INDEXED_ANNUAL_RATE,
     },
 };
 use actuarial_system::policy::{load_default_inforce, AdjustmentParams, load_adjusted_inforce};
@@ -104,7 +104,7 @@ fn calculate_ceding_commission(cashflows: &[f64], bbb_rate: f64, spread: f64) ->
     npv * monthly_factor
 }
 
-fn main() {
+fn main() -> Result<(), ActuarialError> {
     env_logger::init();
 
     let json_output = env::args().any(|arg| arg == "--json");
@@ -144,6 +144,11 @@ fn main() {
     // Inforce adjustment parameters
     let use_adjusted = env::var("USE_DYNAMIC_INFORCE").is_ok();
 
+    // BB bonus override (default: 30% for the adjusted inforce, each policy's own bonus otherwise)
+    let bb_bonus: Option<f64> = env::var("INFORCE_BB_BONUS")
+        .ok()
+        .and_then(|s| s.parse().ok());
+
     let adjustment_params = AdjustmentParams {
         fixed_pct: env::var("INFORCE_FIXED_PCT")
             .ok()
@@ -165,18 +170,14 @@ fn main() {
             .ok()
             .and_then(|s| s.parse().ok())
             .unwrap_or(1.0),
-        bb_bonus: env::var("INFORCE_BB_BONUS")
-            .ok()
-            .and_then(|s| s.parse().ok())
-            .unwrap_or(0.30),
+        bb_bonus: bb_bonus.unwrap_or(0.30),
         target_premium: 100_000_000.0,
     };
 
-    // Rollup rate override (default 10%)
-    let rollup_rate: f64 = env::var("ROLLUP_RATE")
+    // Rollup rate override (default: each policy's own rate)
+    let rollup_rate: Option<f64> = env::var("ROLLUP_RATE")
         .ok()
-        .and_then(|s| s.parse().ok())
-        .unwrap_or(0.10);
+        .and_then(|s| s.parse().ok());
 
     // Policy filters
     let min_glwb_start_year: Option<u32> = env::var("MIN_GLWB_START_YEAR")
@@ -204,17 +205,17 @@ fn main() {
     // Load policies (with optional adjustments)
     let mut policies = if use_adjusted {
         if !json_output {
-            println!("Loading adjusted inforce (fixed_pct={:.0}%, bb_bonus={:.0}%, rollup={:.0}%)...",
+            println!("Loading adjusted inforce (fixed_pct={:.0}%, bb_bonus={:.0}%, rollup={})...",
                      adjustment_params.fixed_pct * 100.0,
                      adjustment_params.bb_bonus * 100.0,
-                     rollup_rate * 100.0);
+                     rollup_rate.map_or("per policy".to_string(), |r| format!("{:.0}%", r * 100.0)));
         }
-        load_adjusted_inforce(&adjustment_params).expect("Failed to load adjusted policies")
+        load_adjusted_inforce(&adjustment_params)?
     } else {
         if !json_output {
             println!("Loading policies from pricing_inforce.csv...");
         }
-        load_default_inforce().expect("Failed to load policies")
+        load_default_inforce()?
     };
 
     // Apply policy filters
@@ -285,10 +286,17 @@ fn main() {
 
     let policy_count = policies.len();
 
-    // Load assumptions and apply rollup rate override
-    let mut assumptions = Assumptions::default_pricing();
-    assumptions.product.glwb.rollup_rate = rollup_rate;
-    assumptions.product.glwb.bonus_rate = adjustment_params.bb_bonus;
+    // Apply rollup overrides where supplied (rollup is driven by each policy's terms)
+    for policy in policies.iter_mut() {
+        if let Some(rollup_rate) = rollup_rate {
+            policy.rollup_rate = rollup_rate;
+        }
+        if let Some(bonus) = bb_bonus {
+            policy.bonus = bonus;
+        }
+    }
+
+    let assumptions = Assumptions::default_pricing();
 
     // Projection config from environment
     let config = ProjectionConfig {
@@ -302,6 +310,7 @@ fn main() {
         fixed_lapse_rate: None,
         hedge_params: Some(HedgeParams::default()),
         reserve_config: None, // Reserves off for cost of funds calculation
+        reinsurance: None,
     };
 
     if !json_output {
@@ -317,7 +326,7 @@ fn main() {
             let engine = ProjectionEngine::new(assumptions.clone(), config.clone());
             engine.project_policy(policy)
         })
-        .collect();
+        .collect::<Result<_, ActuarialError>>()?;
 
     if !json_output {
         println!("Projections complete in {:?}", proj_start.elapsed());
@@ -380,8 +389,8 @@ fn main() {
     let month_1_cashflow = aggregated_cashflows.first().copied().unwrap_or(0.0);
 
     // Calculate Cost of Funds (IRR)
-    let cost_of_funds = calculate_cost_of_funds(&aggregated_cashflows);
-    let cost_of_funds_pct = cost_of_funds.map(|r| r * 100.0);
+    let cost_of_funds = solve_cost_of_funds(&aggregated_cashflows);
+    let cost_of_funds_pct = cost_of_funds.as_ref().ok().map(|r| r * 100.0);
 
     // Calculate ceding commission if BBB rate is provided
     let ceding_commission = bbb_rate.map(|bbb| {
@@ -430,7 +439,7 @@ fn main() {
             cashflows: detailed_cashflows.clone(),
             execution_time_ms,
         };
-        println!("{}", serde_json::to_string(&response).unwrap());
+        println!("{}", serde_json::to_string(&response)?);
     } else {
         // Human-readable output
         println!("\nNet Cashflow Summary:");
@@ -455,14 +464,14 @@ fn main() {
 
         println!("\nCalculating Cost of Funds (IRR)...");
 
-        match cost_of_funds_pct {
-            Some(pct) => {
+        match &cost_of_funds {
+            Ok(rate) => {
                 println!("\n========================================");
-                println!("  COST OF FUNDS: {:.4}%", pct);
+                println!("  COST OF FUNDS: {:.4}%", rate * 100.0);
                 println!("========================================");
             }
-            None => {
-                println!("\n  Could not calculate IRR (no solution found)");
+            Err(e) => {
+                println!("\n  Could not calculate IRR: {}", e);
             }
         }
 
@@ -478,4 +487,6 @@ fn main() {
 
         println!("\nTotal time: {:?}", start.elapsed());
     }
+
+    Ok(())
 }
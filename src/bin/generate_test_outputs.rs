@@ -4,7 +4,7 @@
 //! against the Excel reference outputs.
 
 use actuarial_system::{
-    Policy, Assumptions,
+    ActuarialError, Policy, Assumptions,
     projection::{ProjectionEngine, ProjectionConfig, CreditingApproach, HedgeParams, DEFAULT_INDEXED_ANNUAL_RATE},
 };
 use actuarial_system::policy::{QualStatus, Gender, CreditingStrategy, RollupType};
@@ -23,7 +23,7 @@ struct TestPolicy {
     glwb_start_year: u32,
 }
 
-fn main() {
+fn main() -> Result<(), ActuarialError> {
     env_logger::init();
 
     println!("Generating test policy outputs...\n");
@@ -168,6 +168,7 @@ fn main() {
         fixed_lapse_rate: None, // Use predictive lapse model
         hedge_params: Some(HedgeParams::default()),
         reserve_config: None,
+        reinsurance: None,
     };
 
     for tp in &test_policies {
@@ -195,14 +196,14 @@ fn main() {
 
         // Run projection
         let engine = ProjectionEngine::new(assumptions.clone(), config.clone());
-        let result = engine.project_policy(&policy);
+        let result = engine.project_policy(&policy)?;
 
         // Write output to cashflow_examples/
         let csv_path = format!("cashflow_examples/rust_output_{}.csv", tp.policy_id);
-        let mut file = File::create(&csv_path).expect("Unable to create CSV file");
+        let mut file = File::create(&csv_path)?;
 
         // Write header matching the Rust output format
-        writeln!(file, "Month,PolicyYear,MonthInPY,Age,BOP_AV,BOP_BB,FinalMortality,FinalLapse,PWD_Rate,RiderChargeRate,CreditedRate,SurrChgPct,FPW_Pct,Lives,Mortality,Lapse,PWD,SurrChg,RiderChg,Interest,EOP_AV,BaseLapse,DynamicLapse,LapseSkew,NetIndexCreditReimb,HedgeGains").unwrap();
+        writeln!(file, "Month,PolicyYear,MonthInPY,Age,BOP_AV,BOP_BB,FinalMortality,FinalLapse,PWD_Rate,RiderChargeRate,CreditedRate,SurrChgPct,FPW_Pct,Lives,Mortality,Lapse,PWD,SurrChg,RiderChg,Interest,EOP_AV,BaseLapse,DynamicLapse,LapseSkew,NetIndexCreditReimb,HedgeGains")?;
 
         // Write all rows
         for row in &result.cashflows {
@@ -233,7 +234,7 @@ fn main() {
                 row.lapse_skew,
                 row.net_index_credit_reimbursement,
                 row.hedge_gains,
-            ).unwrap();
+            )?;
         }
 
         println!("  -> Written to {}", csv_path);
@@ -244,4 +245,5 @@ fn main() {
     }
 
     println!("\nDone! Generated {} output files.", test_policies.len());
+    Ok(())
 }
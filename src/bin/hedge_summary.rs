@@ -1,7 +1,7 @@
 //! Output total hedge gains by policy ID
 
 use actuarial_system::{
-    Assumptions,
+    ActuarialError, Assumptions,
     projection::{ProjectionEngine, ProjectionConfig, CreditingApproach, HedgeParams, DEFAULT_FIXED_ANNUAL_RATE, DEFAULT_INDEXED_ANNUAL_RATE},
 };
 use actuarial_system::policy::load_default_inforce;
@@ -9,8 +9,8 @@ use rayon::prelude::*;
 use std::fs::File;
 use std::io::Write;
 
-fn main() {
-    let policies = load_default_inforce().expect("Failed to load policies");
+fn main() -> Result<(), ActuarialError> {
+    let policies = load_default_inforce()?;
     let assumptions = Assumptions::default_pricing();
     let config = ProjectionConfig {
         projection_months: 768, // Run to terminal age 121
@@ -23,6 +23,7 @@ fn main() {
         fixed_lapse_rate: None,
         hedge_params: Some(HedgeParams::default()),
         reserve_config: None,
+        reinsurance: None,
     };
 
     // Run projections in parallel and collect (policy_id, total_hedge_gains)
@@ -30,21 +31,22 @@ fn main() {
         .par_iter()
         .map(|policy| {
             let engine = ProjectionEngine::new(assumptions.clone(), config.clone());
-            let result = engine.project_policy(policy);
+            let result = engine.project_policy(policy)?;
             let total_hedge: f64 = result.cashflows.iter().map(|r| r.hedge_gains).sum();
-            (policy.policy_id, total_hedge)
+            Ok((policy.policy_id, total_hedge))
         })
-        .collect();
+        .collect::<Result<_, ActuarialError>>()?;
 
-    let mut file = File::create("hedge_gains_by_policy.csv").unwrap();
-    writeln!(file, "PolicyID,TotalHedgeGains").unwrap();
+    let mut file = File::create("hedge_gains_by_policy.csv")?;
+    writeln!(file, "PolicyID,TotalHedgeGains")?;
     
     let mut sorted_results = results;
     sorted_results.sort_by_key(|(id, _)| *id);
     
     for (policy_id, total_hedge) in &sorted_results {
-        writeln!(file, "{},{:.6}", policy_id, total_hedge).unwrap();
+        writeln!(file, "{},{:.6}", policy_id, total_hedge)?;
     }
     
     println!("Written {} policies to hedge_gains_by_policy.csv", sorted_results.len());
+    Ok(())
 }
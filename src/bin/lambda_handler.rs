@@ -6,12 +6,13 @@
 //! Supports Lambda Function URLs for direct HTTP access.
 
 use actuarial_system::{
-    Assumptions,
+    ActuarialError, Assumptions,
     projection::{
         ProjectionEngine, ProjectionConfig, CreditingApproach, HedgeParams,
-        calculate_cost_of_funds, DEFAULT_FIXED_ANNUAL_RATE, DEFAULT_INDEXED_ANNUAL_RATE,
+        calculate_cost_of_funds, solve_cost_of_funds, DEFAULT_FIXED_ANNUAL_RATE, DEFAULT_INDEXED_ANNUAL_RATE,
     },
     policy::{load_default_inforce, AdjustmentParams, load_adjusted_inforce, Gender, QualStatus, CreditingStrategy, BenefitBaseBucket},
+    reinsurance::ReinsuranceTreaty,
 };
 use lambda_http::{run, service_fn, Body, Error, Request, Response};
 use rayon::prelude::*;
@@ -76,13 +77,14 @@ pub struct ProjectionRequest {
     #[serde(default = "default_one")]
     pub inforce_nonqual_mult: f64,
 
-    /// Benefit base bonus (0-1, e.g., 0.30 for 30%)
-    #[serde(default = "default_bb_bonus")]
-    pub inforce_bb_bonus: f64,
+    /// Benefit base bonus (0-1, e.g., 0.30 for 30%); when omitted the dynamic
+    /// inforce uses 30% and each policy keeps its own bonus
+    #[serde(default)]
+    pub inforce_bb_bonus: Option<f64>,
 
-    /// Annual rollup rate (default: 10%)
-    #[serde(default = "default_rollup_rate")]
-    pub rollup_rate: f64,
+    /// Annual rollup rate override (default: each policy's own rate)
+    #[serde(default)]
+    pub rollup_rate: Option<f64>,
 
     // PWD assumptions
     /// Free withdrawal percentage (default: 10%)
@@ -130,6 +132,10 @@ pub struct ProjectionRequest {
     /// Filter by benefit base bucket
     #[serde(default)]
     pub bb_buckets: Option<Vec<String>>,
+
+    /// Reinsurance treaty applied to every policy (None = gross only)
+    #[serde(default)]
+    pub reinsurance: Option<ReinsuranceTreaty>,
 }
 
 fn default_projection_months() -> u32 { 768 }
@@ -140,7 +146,6 @@ fn default_equity_kicker() -> f64 { 0.20 }    // 20%
 fn default_fixed_pct() -> f64 { 0.25 }
 fn default_one() -> f64 { 1.0 }
 fn default_bb_bonus() -> f64 { 0.30 }
-fn default_rollup_rate() -> f64 { 0.10 }
 fn default_free_withdrawal_pct() -> f64 { 0.10 }    // 10%
 fn default_pwd_util_year1() -> f64 { 0.065 }        // 6.5%
 fn default_pwd_util_year2() -> f64 { 0.13 }         // 13%
@@ -154,14 +159,66 @@ pub struct ProjectionResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ceding_commission: Option<CedingCommission>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub reinsurance: Option<ReinsuranceSummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub inforce_params: Option<InforceParamsOutput>,
     pub policy_count: usize,
     pub projection_months: u32,
     pub summary: ProjectionSummary,
     pub cashflows: Vec<DetailedCashflowRow>,
     pub execution_time_ms: u64,
+    /// Why cost_of_funds_pct is missing, when the IRR solver found no rate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ApiError>,
+}
+
+/// Structured error returned by the API
+#[derive(Debug, Serialize)]
+pub struct ApiError {
+    /// Error category (see `ActuarialError::kind`)
+    pub kind: &'static str,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error: Option<String>,
+    pub row: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy_id: Option<u32>,
+}
+
+impl From<&ActuarialError> for ApiError {
+    fn from(error: &ActuarialError) -> Self {
+        let mut api_error = ApiError {
+            kind: error.kind(),
+            message: error.to_string(),
+            file: None,
+            line: None,
+            row: None,
+            policy_id: None,
+        };
+        match error {
+            ActuarialError::AssumptionFileMissing { path, .. } => {
+                api_error.file = Some(path.display().to_string());
+            }
+            ActuarialError::AssumptionMalformed { path, line, .. } => {
+                api_error.file = Some(path.display().to_string());
+                api_error.line = Some(*line);
+            }
+            ActuarialError::InforceRow { row, policy_id, .. } => {
+                api_error.row = Some(*row);
+                api_error.policy_id = *policy_id;
+            }
+            _ => {}
+        }
+        api_error
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct ErrorBody {
+    error: ApiError,
 }
 
 #[derive(Debug, Serialize)]
@@ -172,6 +229,22 @@ pub struct CedingCommission {
     pub total_rate_pct: f64,
 }
 
+/// Block totals after reinsurance
+#[derive(Debug, Serialize, Default)]
+pub struct ReinsuranceSummary {
+    pub net_cost_of_funds_pct: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub net_ceding_commission_npv: Option<f64>,
+    pub total_ceded_premium: f64,
+    pub total_ceded_benefits: f64,
+    pub total_allowances: f64,
+    pub total_reserve_adjustment: f64,
+    pub total_withheld_interest: f64,
+    pub total_experience_refund: f64,
+    pub total_net_settlement: f64,
+    pub net_cashflows: Vec<f64>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct InforceParamsOutput {
     pub fixed_pct: f64,
@@ -231,24 +304,28 @@ fn calculate_ceding_commission(cashflows: &[f64], bbb_rate: f64, spread: f64) ->
     npv * monthly_factor
 }
 
-fn error_response(status: u16, message: &str) -> Response<Body> {
-    Response::builder()
+fn error_response(status: u16, error: &ActuarialError) -> Result<Response<Body>, Error> {
+    let body = ErrorBody { error: ApiError::from(error) };
+    Ok(Response::builder()
         .status(status)
         .header("Content-Type", "application/json")
         .header("Access-Control-Allow-Origin", "*")
-        .body(Body::Text(format!(r#"{{"error":"{}"}}"#, message)))
-        .unwrap()
+        .body(Body::Text(serde_json::to_string(&body)?))?)
 }
 
-fn json_response(body: &ProjectionResponse) -> Response<Body> {
-    Response::builder()
+/// Successful response, or a typed 500 if the body cannot be serialized
+fn json_response(body: &ProjectionResponse) -> Result<Response<Body>, Error> {
+    let text = match serde_json::to_string(body) {
+        Ok(text) => text,
+        Err(e) => return error_response(500, &ActuarialError::from(e)),
+    };
+    Ok(Response::builder()
         .status(200)
         .header("Content-Type", "application/json")
         .header("Access-Control-Allow-Origin", "*")
         .header("Access-Control-Allow-Methods", "POST, OPTIONS")
         .header("Access-Control-Allow-Headers", "Content-Type")
-        .body(Body::Text(serde_json::to_string(body).unwrap()))
-        .unwrap()
+        .body(Body::Text(text))?)
 }
 
 /// Lambda handler function
@@ -262,8 +339,7 @@ async fn handler(event: Request) -> Result<Response<Body>, Error> {
             .header("Access-Control-Allow-Origin", "*")
             .header("Access-Control-Allow-Methods", "POST, OPTIONS")
             .header("Access-Control-Allow-Headers", "Content-Type")
-            .body(Body::Empty)
-            .unwrap());
+            .body(Body::Empty)?);
     }
 
     // Parse request body
@@ -277,10 +353,14 @@ async fn handler(event: Request) -> Result<Response<Body>, Error> {
     let request: ProjectionRequest = match serde_json::from_str(&body_str) {
         Ok(r) => r,
         Err(e) => {
-            return Ok(error_response(400, &format!("Invalid JSON: {}", e)));
+            return error_response(400, &ActuarialError::from(e));
         }
     };
 
+    if let Some(Err(e)) = request.reinsurance.as_ref().map(|t| t.validate()) {
+        return error_response(400, &e);
+    }
+
     // Set up adjustment params for dynamic inforce
     let adjustment_params = AdjustmentParams {
         fixed_pct: request.inforce_fixed_pct,
@@ -288,7 +368,7 @@ async fn handler(event: Request) -> Result<Response<Body>, Error> {
         female_mult: request.inforce_female_mult,
         qual_mult: request.inforce_qual_mult,
         nonqual_mult: request.inforce_nonqual_mult,
-        bb_bonus: request.inforce_bb_bonus,
+        bb_bonus: request.inforce_bb_bonus.unwrap_or_else(default_bb_bonus),
         target_premium: 100_000_000.0,
     };
 
@@ -297,14 +377,14 @@ async fn handler(event: Request) -> Result<Response<Body>, Error> {
         match load_adjusted_inforce(&adjustment_params) {
             Ok(p) => p,
             Err(e) => {
-                return Ok(error_response(500, &format!("Failed to generate dynamic inforce: {}", e)));
+                return error_response(500, &e);
             }
         }
     } else {
         match load_default_inforce() {
             Ok(p) => p,
             Err(e) => {
-                return Ok(error_response(500, &format!("Failed to load default inforce: {}", e)));
+                return error_response(500, &e);
             }
         }
     };
@@ -373,12 +453,20 @@ async fn handler(event: Request) -> Result<Response<Body>, Error> {
         }
     }
 
+    // Apply rollup overrides where supplied (rollup is driven by each policy's terms)
+    for policy in policies.iter_mut() {
+        if let Some(rollup_rate) = request.rollup_rate {
+            policy.rollup_rate = rollup_rate;
+        }
+        if let Some(bonus) = request.inforce_bb_bonus {
+            policy.bonus = bonus;
+        }
+    }
+
     let policy_count = policies.len();
 
     // Load assumptions and apply overrides
     let mut assumptions = Assumptions::default_pricing();
-    assumptions.product.glwb.rollup_rate = request.rollup_rate;
-    assumptions.product.glwb.bonus_rate = request.inforce_bb_bonus;
 
     // Override PWD assumptions
     assumptions.product.base.free_withdrawal_pct = request.free_withdrawal_pct;
@@ -405,16 +493,28 @@ async fn handler(event: Request) -> Result<Response<Body>, Error> {
             financing_fee: 0.05,  // Hardcoded at 5%
         }),
         reserve_config: None, // Reserves off for API endpoint
+        reinsurance: request.reinsurance.clone(),
     };
 
+    if let Err(e) = config.validate() {
+        return error_response(400, &e);
+    }
+    if let Some(Err(e)) = policies.iter().map(|policy| assumptions.check_policy(policy)).find(Result::is_err) {
+        return error_response(400, &e);
+    }
+
     // Run projections in parallel
-    let results: Vec<_> = policies
+    let results: Result<Vec<_>, ActuarialError> = policies
         .par_iter()
         .map(|policy| {
             let engine = ProjectionEngine::new(assumptions.clone(), config.clone());
             engine.project_policy(policy)
         })
         .collect();
+    let results = match results {
+        Ok(results) => results,
+        Err(e) => return error_response(400, &e),
+    };
 
     // Aggregate results
     let num_months = request.projection_months as usize;
@@ -428,6 +528,10 @@ async fn handler(event: Request) -> Result<Response<Body>, Error> {
     let mut total_premium = 0.0;
     let mut final_lives = 0.0;
     let mut final_av = 0.0;
+    let mut reinsurance_summary = request.reinsurance.as_ref().map(|_| ReinsuranceSummary {
+        net_cashflows: vec![0.0; num_months],
+        ..Default::default()
+    });
 
     for result in &results {
         for row in &result.cashflows {
@@ -455,6 +559,22 @@ async fn handler(event: Request) -> Result<Response<Body>, Error> {
             }
         }
 
+        if let (Some(summary), Some(ceded)) = (reinsurance_summary.as_mut(), result.reinsurance.as_ref()) {
+            for (row, settlement) in ceded.net.iter().zip(&ceded.settlements) {
+                let idx = (row.projection_month - 1) as usize;
+                if idx < num_months {
+                    summary.net_cashflows[idx] += row.total_net_cashflow;
+                }
+                summary.total_ceded_premium += settlement.ceded_premium;
+                summary.total_ceded_benefits += settlement.ceded_benefits;
+                summary.total_allowances += settlement.allowances;
+                summary.total_reserve_adjustment += settlement.reserve_adjustment;
+                summary.total_withheld_interest += settlement.withheld_interest;
+                summary.total_experience_refund += settlement.experience_refund;
+                summary.total_net_settlement += settlement.net_settlement;
+            }
+        }
+
         if let Some(first) = result.cashflows.first() {
             total_initial_av += first.bop_av;
             total_initial_bb += first.bop_benefit_base;
@@ -474,8 +594,8 @@ async fn handler(event: Request) -> Result<Response<Body>, Error> {
     let month_1_cashflow = aggregated_cashflows.first().copied().unwrap_or(0.0);
 
     // Calculate Cost of Funds (IRR)
-    let cost_of_funds = calculate_cost_of_funds(&aggregated_cashflows);
-    let cost_of_funds_pct = cost_of_funds.map(|r| r * 100.0);
+    let cost_of_funds = solve_cost_of_funds(&aggregated_cashflows);
+    let cost_of_funds_pct = cost_of_funds.as_ref().ok().map(|r| r * 100.0);
 
     // Calculate ceding commission if BBB rate is provided
     let ceding_commission = request.bbb_rate.map(|bbb| {
@@ -489,6 +609,14 @@ async fn handler(event: Request) -> Result<Response<Body>, Error> {
         }
     });
 
+    // Net of reinsurance cost of funds and ceding commission
+    if let Some(summary) = reinsurance_summary.as_mut() {
+        summary.net_cost_of_funds_pct = calculate_cost_of_funds(&summary.net_cashflows).map(|r| r * 100.0);
+        summary.net_ceding_commission_npv = request.bbb_rate.map(|bbb| {
+            calculate_ceding_commission(&summary.net_cashflows, bbb, request.spread.unwrap_or(0.0))
+        });
+    }
+
     // Build inforce params output
     let inforce_params = if request.use_dynamic_inforce {
         Some(InforceParamsOutput {
@@ -508,6 +636,7 @@ async fn handler(event: Request) -> Result<Response<Body>, Error> {
     let response = ProjectionResponse {
         cost_of_funds_pct,
         ceding_commission,
+        reinsurance: reinsurance_summary,
         inforce_params,
         policy_count,
         projection_months: request.projection_months,
@@ -523,10 +652,10 @@ async fn handler(event: Request) -> Result<Response<Body>, Error> {
         },
         cashflows: detailed_cashflows,
         execution_time_ms,
-        error: None,
+        error: cost_of_funds.as_ref().err().map(ApiError::from),
     };
 
-    Ok(json_response(&response))
+    json_response(&response)
 }
 
 #[tokio::main]
@@ -3,7 +3,7 @@
 //! Outputs monthly aggregated cashflows for comparison with Excel
 
 use actuarial_system::{
-    Assumptions,
+    ActuarialError, Assumptions,
     projection::{
         ProjectionEngine, ProjectionConfig, CashflowRow, CreditingApproach, HedgeParams,
         DEFAULT_FIXED_ANNUAL_RATE, DEFAULT_INDEXED_ANNUAL_RATE,
@@ -40,13 +40,13 @@ struct AggregatedRow {
     total_net_cashflow: f64,
 }
 
-fn main() {
+fn main() -> Result<(), ActuarialError> {
     env_logger::init();
 
     let start = Instant::now();
     println!("Loading policies from pricing_inforce.csv...");
 
-    let policies = load_default_inforce().expect("Failed to load policies");
+    let policies = load_default_inforce()?;
     println!("Loaded {} policies in {:?}", policies.len(), start.elapsed());
 
     // Load assumptions
@@ -64,6 +64,7 @@ fn main() {
         fixed_lapse_rate: None,
         hedge_params: Some(HedgeParams::default()),
         reserve_config: None,
+        reinsurance: None,
     };
 
     println!("Running projections...");
@@ -74,10 +75,9 @@ fn main() {
         .par_iter()
         .map(|policy| {
             let engine = ProjectionEngine::new(assumptions.clone(), config.clone());
-            let result = engine.project_policy(policy);
-            result.cashflows
+            Ok(engine.project_policy(policy)?.cashflows)
         })
-        .collect();
+        .collect::<Result<_, ActuarialError>>()?;
 
     println!("Projections complete in {:?}", proj_start.elapsed());
 
@@ -117,9 +117,9 @@ fn main() {
 
     // Write output
     let output_path = "block_projection_output.csv";
-    let mut file = File::create(output_path).expect("Failed to create output file");
+    let mut file = File::create(output_path)?;
 
-    writeln!(file, "Month,BOP_AV,BOP_BB,Lives,Mortality,Lapse,PWD,RiderCharges,SurrCharges,Interest,EOP_AV,Expenses,AgentComm,IMOOverride,WholesalerOverride,BonusComp,Chargebacks,HedgeGains,NetCashflow").unwrap();
+    writeln!(file, "Month,BOP_AV,BOP_BB,Lives,Mortality,Lapse,PWD,RiderCharges,SurrCharges,Interest,EOP_AV,Expenses,AgentComm,IMOOverride,WholesalerOverride,BonusComp,Chargebacks,HedgeGains,NetCashflow")?;
 
     for row in &aggregated {
         writeln!(
@@ -144,7 +144,7 @@ fn main() {
             row.total_chargebacks,
             row.total_hedge_gains,
             row.total_net_cashflow,
-        ).unwrap();
+        )?;
     }
 
     println!("Output written to {}", output_path);
@@ -169,4 +169,5 @@ fn main() {
              aggregated[767].total_bop_av);
 
     println!("\nTotal time: {:?}", start.elapsed());
+    Ok(())
 }
@@ -0,0 +1,94 @@
+//! Crate error type
+//!
+//! Loaders, the scenario runner and the solvers return `ActuarialError` so
+//! callers (and the Lambda API) can tell a missing assumption file from a bad
+//! inforce row or an invalid configuration without parsing message strings.
+
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+/// Errors raised by the actuarial library
+#[derive(Debug, Error)]
+pub enum ActuarialError {
+    /// Assumption file could not be opened
+    #[error("cannot open assumption file {}: {source}", path.display())]
+    AssumptionFileMissing {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// Assumption file has a row that cannot be read
+    #[error("{}, line {line}: {message}", path.display())]
+    AssumptionMalformed {
+        path: PathBuf,
+        line: u64,
+        message: String,
+    },
+
+    /// Inforce row that cannot be turned into a policy (row is 1-based, header excluded)
+    #[error("inforce row {row}{}: {message}", policy_id.map_or_else(String::new, |id| format!(" (policy {})", id)))]
+    InforceRow {
+        row: usize,
+        policy_id: Option<u32>,
+        message: String,
+    },
+
+    /// Configuration, schema or treaty that fails validation
+    #[error("invalid configuration: {0}")]
+    Config(String),
+
+    /// Numerical solver that found no solution
+    #[error("{solver} did not converge: {message}")]
+    SolverNonConvergence {
+        solver: &'static str,
+        message: String,
+    },
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error(transparent)]
+    Csv(#[from] csv::Error),
+
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+}
+
+impl ActuarialError {
+    /// Short machine-readable error category (used by the Lambda API)
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ActuarialError::AssumptionFileMissing { .. } => "assumption_file_missing",
+            ActuarialError::AssumptionMalformed { .. } => "assumption_malformed",
+            ActuarialError::InforceRow { .. } => "inforce_row",
+            ActuarialError::Config(_) => "config",
+            ActuarialError::SolverNonConvergence { .. } => "solver_non_convergence",
+            ActuarialError::Io(_) => "io",
+            ActuarialError::Csv(_) => "csv",
+            ActuarialError::Json(_) => "json",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_messages() {
+        let e = ActuarialError::AssumptionMalformed {
+            path: PathBuf::from("data/assumptions/rmd_rates.csv"),
+            line: 4,
+            message: "Rate: invalid float literal".to_string(),
+        };
+        assert_eq!(e.to_string(), "data/assumptions/rmd_rates.csv, line 4: Rate: invalid float literal");
+        assert_eq!(e.kind(), "assumption_malformed");
+
+        let e = ActuarialError::InforceRow { row: 2, policy_id: Some(17), message: "Unknown Gender: X".to_string() };
+        assert_eq!(e.to_string(), "inforce row 2 (policy 17): Unknown Gender: X");
+        let e = ActuarialError::InforceRow { row: 2, policy_id: None, message: "missing field".to_string() };
+        assert_eq!(e.to_string(), "inforce row 2: missing field");
+    }
+}
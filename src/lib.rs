@@ -4,6 +4,7 @@
 //! - Single and multi-policy liability projections
 //! - Decrement modeling (mortality, lapse, partial withdrawals)
 //! - Reserve calculations (CARVM, AG33, AG35, VM-22)
+//! - Reinsurance (coinsurance, modco, YRT) with gross / ceded / net splits
 //! - Asset modeling and portfolio analytics
 //! - Multi-scenario simulation framework
 
@@ -12,12 +13,15 @@ pub mod assumptions;
 pub mod projection;
 pub mod scenario;
 pub mod reserves;
+pub mod reinsurance;
+pub mod error;
 
 // Re-export commonly used types
 pub use policy::Policy;
 pub use assumptions::{Assumptions, MortalityTable, SurrenderChargeSchedule, LapseModel};
 pub use projection::{ProjectionEngine, ProjectionResult, CashflowRow};
 pub use scenario::ScenarioRunner;
+pub use error::ActuarialError;
 
 // Re-export reserve types
 pub use reserves::{
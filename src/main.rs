@@ -3,14 +3,14 @@
 //! Command-line interface for running actuarial projections
 
 use actuarial_system::{
-    Policy, Assumptions,
+    ActuarialError, Policy, Assumptions,
     projection::{ProjectionEngine, ProjectionConfig, CreditingApproach, HedgeParams, DEFAULT_INDEXED_ANNUAL_RATE},
 };
 use actuarial_system::policy::{QualStatus, Gender, CreditingStrategy, RollupType};
 use std::fs::File;
 use std::io::Write;
 
-fn main() {
+fn main() -> Result<(), ActuarialError> {
     env_logger::init();
 
     println!("Actuarial System v0.1.0");
@@ -57,11 +57,12 @@ fn main() {
         fixed_lapse_rate: None, // Use predictive lapse model
         hedge_params: Some(HedgeParams::default()),
         reserve_config: None,
+        reinsurance: None,
     };
 
     // Run projection
     let engine = ProjectionEngine::new(assumptions, config);
-    let result = engine.project_policy(&policy);
+    let result = engine.project_policy(&policy)?;
 
     // Print header
     println!("Projection Results ({} months):", result.cashflows.len());
@@ -90,12 +91,12 @@ fn main() {
 
     // Write full results to CSV
     let csv_path = "projection_output.csv";
-    let mut file = File::create(csv_path).expect("Unable to create CSV file");
+    let mut file = File::create(csv_path)?;
 
     // Write header - includes per-policy decrement amounts for AV roll-forward
     // Lapse shown as net-of-SC (matching Excel), SurrChg shown separately
     // FPW_Pct added to show the free partial withdrawal % (incorporates RMD for qualified)
-    writeln!(file, "Month,PolicyYear,MonthInPY,Age,BOP_AV,BOP_BB,FinalMortality,FinalLapse,PWD_Rate,RiderChargeRate,CreditedRate,SurrChgPct,FPW_Pct,Lives,Mortality,Lapse,PWD,SurrChg,RiderChg,Interest,EOP_AV,BaseLapse,DynamicLapse,LapseSkew").unwrap();
+    writeln!(file, "Month,PolicyYear,MonthInPY,Age,BOP_AV,BOP_BB,FinalMortality,FinalLapse,PWD_Rate,RiderChargeRate,CreditedRate,SurrChgPct,FPW_Pct,Lives,Mortality,Lapse,PWD,SurrChg,RiderChg,Interest,EOP_AV,BaseLapse,DynamicLapse,LapseSkew")?;
 
     // Write all rows with per-policy decrement amounts
     // Engine now calculates these using Excel's proportional allocation approach
@@ -125,7 +126,7 @@ fn main() {
             row.base_lapse_component,
             row.dynamic_lapse_component,
             row.lapse_skew,
-        ).unwrap();
+        )?;
     }
 
     println!("\nFull results written to: {}", csv_path);
@@ -149,4 +150,6 @@ fn main() {
                 m, row.final_mortality, row.final_lapse_rate, row.lives, row.bop_av);
         }
     }
+
+    Ok(())
 }
@@ -4,8 +4,8 @@
 //! and apply parameter adjustments (fixed %, gender weights, etc.)
 
 use super::{Policy, QualStatus, Gender, CreditingStrategy, load_default_inforce};
+use crate::error::ActuarialError;
 use serde::{Deserialize, Serialize};
-use std::error::Error;
 
 /// Parameters for adjusting the inforce
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -63,7 +63,7 @@ impl Default for AdjustmentParams {
 }
 
 /// Load policies from CSV and apply parameter adjustments
-pub fn load_adjusted_inforce(params: &AdjustmentParams) -> Result<Vec<Policy>, Box<dyn Error>> {
+pub fn load_adjusted_inforce(params: &AdjustmentParams) -> Result<Vec<Policy>, ActuarialError> {
     // Load base policies
     let base_policies = load_default_inforce()?;
 
@@ -1,5 +1,6 @@
 //! Policy data structures matching the pricing inforce format
 
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
 /// Default GLWB start year (99 = never activates)
@@ -7,6 +8,26 @@ fn default_glwb_start_year() -> u32 {
     99
 }
 
+/// Default annual rollup rate (10%)
+fn default_rollup_rate() -> f64 {
+    0.10
+}
+
+/// Default rollup duration in policy years
+fn default_rollup_duration() -> u32 {
+    10
+}
+
+/// Default wait period in policy years (98 = GLWB never activates)
+fn default_wait_period() -> u32 {
+    98
+}
+
+/// Default survival probability of a life on a joint contract (alive)
+fn default_alive() -> f64 {
+    1.0
+}
+
 /// Qualified status of the policy
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum QualStatus {
@@ -29,6 +50,50 @@ pub enum Gender {
     Female,
 }
 
+/// Second life on a joint (spousal) contract
+///
+/// Income continues to the last survivor and the death benefit is paid on the
+/// second death; the first death has no cashflow.
+///
+/// `first_alive` and `second_alive` are the survival of the annuitant and the
+/// second life at the valuation date: both 1.0 at issue and 0.0 for a life known
+/// to have died. A seasoned cohort carries each life's projected survival since
+/// issue, so the last-survivor decrements continue where they left off.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct JointLife {
+    /// Second life's age at issue
+    pub issue_age: u8,
+
+    /// Second life's gender
+    pub gender: Gender,
+
+    /// Probability the annuitant is alive at the valuation date
+    #[serde(default = "default_alive")]
+    pub first_alive: f64,
+
+    /// Probability the second life is alive at the valuation date
+    #[serde(default = "default_alive")]
+    pub second_alive: f64,
+}
+
+impl JointLife {
+    /// Second life on a new joint contract (both lives alive)
+    pub fn new(issue_age: u8, gender: Gender) -> Self {
+        Self { issue_age, gender, first_alive: 1.0, second_alive: 1.0 }
+    }
+
+    /// Whether both lives may still be alive (joint payout factors apply)
+    pub fn both_may_be_alive(&self) -> bool {
+        self.first_alive > 0.0 && self.second_alive > 0.0
+    }
+
+    /// Second life's attained age at a projection month (ages with the policy year)
+    pub fn attained_age(&self, policy: &Policy, projection_month: u32) -> u8 {
+        let policy_year = policy.policy_year(projection_month);
+        self.issue_age.saturating_add((policy_year - 1) as u8)
+    }
+}
+
 /// Crediting strategy for the annuity
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CreditingStrategy {
@@ -138,6 +203,18 @@ pub struct Policy {
     /// Rollup type (Simple or Compound)
     pub rollup_type: RollupType,
 
+    /// Annual benefit base rollup rate
+    #[serde(default = "default_rollup_rate")]
+    pub rollup_rate: f64,
+
+    /// Number of policy years the benefit base rolls up
+    #[serde(default = "default_rollup_duration")]
+    pub rollup_duration: u32,
+
+    /// Policy years before GLWB income may start (rollup stops after this)
+    #[serde(default = "default_wait_period")]
+    pub wait_period: u32,
+
     /// Current policy duration in months (for seasoned policies)
     #[serde(default)]
     pub duration_months: u32,
@@ -157,6 +234,38 @@ pub struct Policy {
     /// Current benefit base (for mid-projection starts)
     #[serde(default)]
     pub current_benefit_base: Option<f64>,
+
+    /// Current guaranteed (nonforfeiture) account value (for mid-projection starts)
+    #[serde(default)]
+    pub current_guaranteed_av: Option<f64>,
+
+    /// Payout rate locked when income activated (for mid-projection starts)
+    #[serde(default)]
+    pub locked_payout_rate: Option<f64>,
+
+    /// Prior month's account value, for the lagged ITM (for mid-projection starts)
+    #[serde(default)]
+    pub prior_av: Option<f64>,
+
+    /// Prior month's benefit base, for the lagged ITM (for mid-projection starts)
+    #[serde(default)]
+    pub prior_benefit_base: Option<f64>,
+
+    /// Systematic withdrawals taken so far this policy year (for mid-projection starts)
+    #[serde(default)]
+    pub ytd_systematic_wd: f64,
+
+    /// Non-systematic withdrawals taken so far this policy year (for mid-projection starts)
+    #[serde(default)]
+    pub ytd_non_systematic_wd: f64,
+
+    /// Issue date (drives the statutory valuation rate)
+    #[serde(default)]
+    pub issue_date: Option<NaiveDate>,
+
+    /// Second life on a joint contract (None = single life)
+    #[serde(default)]
+    pub joint_life: Option<JointLife>,
 }
 
 impl Policy {
@@ -224,14 +333,72 @@ impl Policy {
             mgir,
             bonus,
             rollup_type,
+            rollup_rate: default_rollup_rate(),
+            rollup_duration: default_rollup_duration(),
+            wait_period: glwb_start_year.saturating_sub(1),
             duration_months: 0,
             income_activated: false,
             glwb_start_year,
             current_av: None,
             current_benefit_base: None,
+            current_guaranteed_av: None,
+            locked_payout_rate: None,
+            prior_av: None,
+            prior_benefit_base: None,
+            ytd_systematic_wd: 0.0,
+            ytd_non_systematic_wd: 0.0,
+            issue_date: None,
+            joint_life: None,
+        }
+    }
+
+    /// Make this a joint-life contract with a second life
+    pub fn with_joint_life(mut self, issue_age: u8, gender: Gender) -> Self {
+        self.joint_life = Some(JointLife::new(issue_age, gender));
+        self
+    }
+
+    /// Record which lives on a joint contract are alive at the valuation date
+    /// (see `JointLife`); no effect on a single-life contract
+    pub fn with_joint_survival(mut self, first_alive: f64, second_alive: f64) -> Self {
+        if let Some(joint) = self.joint_life.as_mut() {
+            joint.first_alive = first_alive;
+            joint.second_alive = second_alive;
+        }
+        self
+    }
+
+    /// Whether income continues to a second life
+    pub fn is_joint(&self) -> bool {
+        self.joint_life.is_some()
+    }
+
+    /// Whether the GLWB payout uses joint factors: a joint contract where both
+    /// lives may still be alive
+    pub fn has_joint_payout(&self) -> bool {
+        self.joint_life.is_some_and(|joint| joint.both_may_be_alive())
+    }
+
+    /// Age used for the GLWB payout factor: the younger life on a joint
+    /// contract, or the survivor once a life is known to have died
+    pub fn payout_age(&self, projection_month: u32) -> u8 {
+        let age = self.attained_age(projection_month);
+        match &self.joint_life {
+            Some(joint) if joint.first_alive <= 0.0 => joint.attained_age(self, projection_month),
+            Some(joint) if joint.second_alive <= 0.0 => age,
+            Some(joint) => age.min(joint.attained_age(self, projection_month)),
+            None => age,
         }
     }
 
+    /// Set the benefit base rollup terms (annual rate, duration and wait period in years)
+    pub fn with_rollup_terms(mut self, rollup_rate: f64, rollup_duration: u32, wait_period: u32) -> Self {
+        self.rollup_rate = rollup_rate;
+        self.rollup_duration = rollup_duration;
+        self.wait_period = wait_period;
+        self
+    }
+
     /// Get the starting account value for projection
     pub fn starting_av(&self) -> f64 {
         self.current_av.unwrap_or(self.initial_premium)
@@ -242,6 +409,17 @@ impl Policy {
         self.current_benefit_base.unwrap_or(self.initial_benefit_base)
     }
 
+    /// Whether the policy starts the projection after issue
+    pub fn is_seasoned(&self) -> bool {
+        self.duration_months > 0
+    }
+
+    /// Policy month (1-indexed from issue) at a given projection month
+    /// Month 1 is the issue month; seasoned policies start past it
+    pub fn policy_month(&self, projection_month: u32) -> u32 {
+        self.duration_months + projection_month
+    }
+
     /// Calculate attained age at a given projection month
     /// Excel formula: =IssueAge + PolicyYear - 1
     /// Age increments at the START of each policy year (month 13, 25, etc.)
@@ -277,6 +455,33 @@ impl Policy {
         }
         self.policy_year(projection_month) >= self.glwb_start_year
     }
+
+    /// Check if the benefit base is still rolling up at a given projection month
+    /// R: PolicyYear <= pmin(RollupDuration, WaitPeriod)
+    pub fn in_rollup_period(&self, projection_month: u32) -> bool {
+        self.policy_year(projection_month) <= self.rollup_duration.min(self.wait_period)
+    }
+
+    /// Monthly benefit base rollup factor at a given projection month
+    /// (1.0 outside the rollup period; income activation is checked by the caller)
+    ///
+    /// Simple:   (1+bonus+rollup/12*PM)/(1+bonus+rollup/12*(PM-1)), PM = policy month
+    /// Compound: (1+rollup)^(1/12)
+    pub fn monthly_rollup_factor(&self, projection_month: u32) -> f64 {
+        if !self.in_rollup_period(projection_month) {
+            return 1.0;
+        }
+
+        match self.rollup_type {
+            RollupType::Simple => {
+                let base = 1.0 + self.bonus;
+                let monthly_rollup = self.rollup_rate / 12.0;
+                let pm = self.policy_month(projection_month) as f64;
+                (base + monthly_rollup * pm) / (base + monthly_rollup * (pm - 1.0))
+            }
+            RollupType::Compound => (1.0 + self.rollup_rate).powf(1.0 / 12.0),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -325,4 +530,28 @@ mod tests {
         assert_eq!(policy.month_in_policy_year(13), 1);
         assert_eq!(policy.attained_age(13), 78);
     }
+
+    #[test]
+    fn test_rollup_factors() {
+        let simple = Policy::with_glwb_start(
+            1, QualStatus::Q, 65, Gender::Male, 130_000.0, 1.0, 100_000.0,
+            CreditingStrategy::Indexed, 10, 0.0475, 0.01, 0.30, RollupType::Simple, 6,
+        );
+        assert_eq!(simple.wait_period, 5);
+
+        // Twelve monthly simple factors telescope to the annual formula
+        let year_1: f64 = (1..=12).map(|m| simple.monthly_rollup_factor(m)).product();
+        assert!((year_1 - 1.4 / 1.3).abs() < 1e-12);
+
+        // Rollup stops after the wait period (year 6 = GLWB start)
+        assert!(simple.in_rollup_period(60));
+        assert_eq!(simple.monthly_rollup_factor(61), 1.0);
+
+        let mut compound = simple.clone().with_rollup_terms(0.07, 10, 98);
+        compound.rollup_type = RollupType::Compound;
+        let year_1: f64 = (1..=12).map(|m| compound.monthly_rollup_factor(m)).product();
+        assert!((year_1 - 1.07).abs() < 1e-12);
+        assert!(compound.in_rollup_period(120));
+        assert_eq!(compound.monthly_rollup_factor(121), 1.0);
+    }
 }
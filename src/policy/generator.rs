@@ -7,7 +7,12 @@
 //! - Benefit base bonus
 
 use super::{Policy, QualStatus, Gender, CreditingStrategy, RollupType, BenefitBaseBucket};
+use super::loader::{load_fia_inforce, FiaInforceRecord};
+use crate::assumptions::ValuationRateBasis;
+use crate::error::ActuarialError;
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
+use std::path::Path;
 
 /// Parameters for generating the inforce
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -41,6 +46,10 @@ pub struct InforceParams {
     /// Target total premium (default: $100M)
     #[serde(default = "default_target_premium")]
     pub target_premium: f64,
+
+    /// Issue date of the generated policies (None = undated)
+    #[serde(default)]
+    pub issue_date: Option<NaiveDate>,
 }
 
 fn default_fixed_pct() -> f64 { 0.25 }
@@ -57,6 +66,7 @@ impl Default for InforceParams {
             nonqual_mult: 1.0,
             bonus: 0.0,
             target_premium: 100_000_000.0,
+            issue_date: None,
         }
     }
 }
@@ -93,12 +103,26 @@ pub struct InforceTemplate {
 impl InforceTemplate {
     /// Create the template from hardcoded base data
     /// This represents the aggregated FIA_inforce demographics and GLWB utilization
+    /// (use `from_fia_inforce_file` to rebuild the demographics from a newer file)
     pub fn new() -> Self {
         let cells = build_demographic_cells();
         let utilization = build_utilization_rates();
         Self { cells, utilization }
     }
 
+    /// Rebuild the template demographics from FIA_inforce records
+    /// GLWB utilization still comes from the activation study rates
+    pub fn from_fia_inforce(records: &[FiaInforceRecord]) -> Self {
+        let cells = demographic_cells_from_fia(records);
+        let utilization = build_utilization_rates();
+        Self { cells, utilization }
+    }
+
+    /// Rebuild the template demographics from an FIA_inforce.csv file
+    pub fn from_fia_inforce_file<P: AsRef<Path>>(path: P) -> Result<Self, ActuarialError> {
+        Ok(Self::from_fia_inforce(&load_fia_inforce(path)?))
+    }
+
     /// Generate policies based on parameters
     pub fn generate(&self, params: &InforceParams) -> Vec<Policy> {
         let indexed_pct = 1.0 - params.fixed_pct;
@@ -169,11 +193,22 @@ impl InforceTemplate {
                         mgir: 0.01,
                         bonus: params.bonus,
                         rollup_type: RollupType::Simple,
+                        rollup_rate: 0.10,
+                        rollup_duration: 10,
+                        wait_period: util.glwb_start_year.saturating_sub(1),
                         duration_months: 0,
                         income_activated: false,
                         glwb_start_year: util.glwb_start_year,
                         current_av: None,
                         current_benefit_base: None,
+                        current_guaranteed_av: None,
+                        locked_payout_rate: None,
+                        prior_av: None,
+                        prior_benefit_base: None,
+                        ytd_systematic_wd: 0.0,
+                        ytd_non_systematic_wd: 0.0,
+                        issue_date: params.issue_date,
+                        joint_life: None,
                     });
                     policy_id += 1;
                 }
@@ -201,11 +236,22 @@ impl InforceTemplate {
                         mgir: 0.01,
                         bonus: params.bonus,
                         rollup_type: RollupType::Simple,
+                        rollup_rate: 0.10,
+                        rollup_duration: 10,
+                        wait_period: util.glwb_start_year.saturating_sub(1),
                         duration_months: 0,
                         income_activated: false,
                         glwb_start_year: util.glwb_start_year,
                         current_av: None,
                         current_benefit_base: None,
+                        current_guaranteed_av: None,
+                        locked_payout_rate: None,
+                        prior_av: None,
+                        prior_benefit_base: None,
+                        ytd_systematic_wd: 0.0,
+                        ytd_non_systematic_wd: 0.0,
+                        issue_date: params.issue_date,
+                        joint_life: None,
                     });
                     policy_id += 1;
                 }
@@ -214,6 +260,17 @@ impl InforceTemplate {
 
         policies
     }
+
+    /// Generate policies and assign the statutory valuation rate for the issue date
+    pub fn generate_with_valuation_rates(
+        &self,
+        params: &InforceParams,
+        basis: &ValuationRateBasis,
+    ) -> Result<Vec<Policy>, ActuarialError> {
+        let mut policies = self.generate(params);
+        basis.assign(&mut policies)?;
+        Ok(policies)
+    }
 }
 
 impl Default for InforceTemplate {
@@ -384,6 +441,71 @@ fn build_demographic_cells() -> Vec<DemographicCell> {
     cells
 }
 
+/// Aggregate FIA_inforce records into demographic cells
+/// R: filter(Benefit_Base>=10000, AttainedAge between 55 and 79),
+///    IssueAge=round((AttainedAge-2)/5)*5+2, InitialPremium=sum(Benefit_Base*n)/1.3
+fn demographic_cells_from_fia(records: &[FiaInforceRecord]) -> Vec<DemographicCell> {
+    // (cell, total policies, total premium)
+    let mut totals: Vec<(DemographicCell, f64, f64)> = Vec::new();
+
+    for record in records {
+        if record.benefit_base < 10_000.0 || !(55..=79).contains(&record.attained_age) {
+            continue;
+        }
+
+        let issue_age = ((record.attained_age as f64 - 2.0) / 5.0).round() as u8 * 5 + 2;
+        let benefit_base_bucket = BenefitBaseBucket::from_amount(record.benefit_base);
+        let premium = record.total_benefit_base() / 1.3;
+
+        let existing = totals.iter_mut().find(|(cell, _, _)| {
+            cell.qual_status == record.qual_status
+                && cell.issue_age == issue_age
+                && cell.gender == record.gender
+                && cell.benefit_base_bucket == benefit_base_bucket
+        });
+        match existing {
+            Some((_, pols, total_premium)) => {
+                *pols += record.policy_count;
+                *total_premium += premium;
+            }
+            None => totals.push((
+                DemographicCell {
+                    qual_status: record.qual_status,
+                    issue_age,
+                    gender: record.gender,
+                    benefit_base_bucket,
+                    base_weight: 0.0,
+                    bb_to_premium_ratio: 1.3,
+                    pols_per_premium: 0.0,
+                },
+                record.policy_count,
+                premium,
+            )),
+        }
+    }
+
+    let block_premium: f64 = totals.iter().map(|(_, _, premium)| premium).sum();
+
+    // Same order as the hardcoded cells: qual status, issue age, gender, bucket
+    totals.sort_by_key(|(cell, _, _)| {
+        (
+            cell.qual_status == QualStatus::Q,
+            cell.issue_age,
+            cell.gender == Gender::Male,
+            cell.benefit_base_bucket as u8,
+        )
+    });
+
+    totals
+        .into_iter()
+        .map(|(mut cell, pols, premium)| {
+            cell.base_weight = premium / block_premium;
+            cell.pols_per_premium = pols / premium;
+            cell
+        })
+        .collect()
+}
+
 /// Build utilization rates from GLWB activation study
 /// These are the incremental utilization percentages by GLWB start year
 /// Extracted from pricing_inforce.csv (sum of Fixed + Indexed for each year within a cell)
@@ -452,4 +574,25 @@ mod tests {
         // Check that all policies have bonus set
         assert!(policies.iter().all(|p| (p.bonus - 0.10).abs() < 0.001));
     }
+
+    #[test]
+    fn test_template_from_fia_inforce() {
+        let from_file = InforceTemplate::from_fia_inforce_file("FIA_inforce.csv").expect("Failed to load FIA_inforce.csv");
+        let hardcoded = InforceTemplate::new();
+
+        assert_eq!(from_file.cells.len(), hardcoded.cells.len());
+        for (file_cell, cell) in from_file.cells.iter().zip(&hardcoded.cells) {
+            assert_eq!(file_cell.qual_status, cell.qual_status);
+            assert_eq!(file_cell.issue_age, cell.issue_age);
+            assert_eq!(file_cell.gender, cell.gender);
+            assert_eq!(file_cell.benefit_base_bucket, cell.benefit_base_bucket);
+            assert!((file_cell.base_weight - cell.base_weight).abs() < 1e-9);
+            assert!((file_cell.pols_per_premium - cell.pols_per_premium).abs() < 1e-10);
+        }
+
+        let policies = from_file.generate(&InforceParams::default());
+        assert_eq!(policies.len(), 2800);
+        let total_premium: f64 = policies.iter().map(|p| p.initial_premium).sum();
+        assert!((total_premium - 100_000_000.0).abs() < 1.0);
+    }
 }
@@ -1,17 +1,40 @@
-//! Load policies from pricing_inforce.csv
+//! Load policies from pricing_inforce.csv and the FIA_inforce.csv block summary
 //!
 //! For Lambda deployment, the CSV data is embedded at compile time using include_str!
 //! This avoids needing to package the CSV file separately.
+//!
+//! Seasoned inforce files carry the same columns plus the policy's state at the
+//! valuation date, all optional (blank or absent = a new issue):
+//! - `DurationMonths`: months since issue
+//! - `CurrentAV`, `CurrentBB`, `CurrentGuaranteedAV`: values at valuation
+//! - `IncomeActivated` (true/false, 1/0 or Y/N), `LockedPayoutRate`
+//! - `PriorAV`, `PriorBB`: prior month's values for the lagged ITM
+//! - `YTDSystematicWD`, `YTDNonSystematicWD`: withdrawals so far this policy year
+//! - `JointStatus` (joint contracts): `BothAlive`, `PrimaryDeceased` or `JointDeceased`
 
-use super::{Policy, QualStatus, Gender, CreditingStrategy, RollupType, BenefitBaseBucket};
+use super::{Policy, JointLife, QualStatus, Gender, CreditingStrategy, RollupType, BenefitBaseBucket};
+use super::schema::InforceSchema;
+use super::validation::{InforceValidation, InforceValidator};
+use crate::assumptions::ValuationRateBasis;
+use chrono::NaiveDate;
 use csv::Reader;
-use std::error::Error;
+use crate::error::ActuarialError;
 use std::path::Path;
 use std::io::Cursor;
 
 /// Embedded pricing_inforce.csv data for Lambda/serverless deployments
 const EMBEDDED_INFORCE_CSV: &str = include_str!("../../pricing_inforce.csv");
 
+/// Every pricing_inforce.csv column the loader reads (required and optional)
+pub const INFORCE_COLUMNS: &[&str] = &[
+    "QualStatus", "IssueAge", "Gender", "InitialBB", "InitialPols", "InitialPremium",
+    "Benefit_Base_Bucket", "Percentage", "CreditingStrategy", "PolicyID", "SCPeriod", "valRate",
+    "MGIR", "Bonus", "RollupType", "Rollup", "RollupDuration", "GLWBStartYear", "WaitPeriod",
+    "IssueDate", "JointIssueAge", "JointGender", "DurationMonths", "CurrentAV", "CurrentBB",
+    "CurrentGuaranteedAV", "IncomeActivated", "LockedPayoutRate", "PriorAV", "PriorBB",
+    "YTDSystematicWD", "YTDNonSystematicWD", "JointStatus",
+];
+
 /// Raw CSV row matching pricing_inforce.csv columns
 #[derive(Debug, serde::Deserialize)]
 struct CsvRow {
@@ -46,39 +69,86 @@ struct CsvRow {
     #[serde(rename = "RollupType")]
     rollup_type: String,
     #[serde(rename = "Rollup")]
-    _rollup: f64,
+    rollup: f64,
     #[serde(rename = "RollupDuration")]
-    _rollup_duration: u32,
+    rollup_duration: u32,
     #[serde(rename = "GLWBStartYear")]
     glwb_start_year: u32,
     #[serde(rename = "WaitPeriod")]
-    _wait_period: u32,
+    wait_period: u32,
+    #[serde(rename = "IssueDate", default)]
+    issue_date: Option<NaiveDate>,
+    #[serde(rename = "JointIssueAge", default)]
+    joint_issue_age: Option<u8>,
+    #[serde(rename = "JointGender", default)]
+    joint_gender: Option<String>,
+    #[serde(rename = "DurationMonths", default)]
+    duration_months: Option<u32>,
+    #[serde(rename = "CurrentAV", default)]
+    current_av: Option<f64>,
+    #[serde(rename = "CurrentBB", default)]
+    current_bb: Option<f64>,
+    #[serde(rename = "CurrentGuaranteedAV", default)]
+    current_guaranteed_av: Option<f64>,
+    #[serde(rename = "IncomeActivated", default)]
+    income_activated: Option<String>,
+    #[serde(rename = "LockedPayoutRate", default)]
+    locked_payout_rate: Option<f64>,
+    #[serde(rename = "PriorAV", default)]
+    prior_av: Option<f64>,
+    #[serde(rename = "PriorBB", default)]
+    prior_bb: Option<f64>,
+    #[serde(rename = "YTDSystematicWD", default)]
+    ytd_systematic_wd: Option<f64>,
+    #[serde(rename = "YTDNonSystematicWD", default)]
+    ytd_non_systematic_wd: Option<f64>,
+    #[serde(rename = "JointStatus", default)]
+    joint_status: Option<String>,
 }
 
 impl CsvRow {
-    fn to_policy(self) -> Result<Policy, Box<dyn Error>> {
+    /// Build the policy for data row `row` (1-based, header excluded)
+    fn to_policy(self, row: usize) -> Result<Policy, ActuarialError> {
+        let policy_id = self.policy_id;
+        self.build_policy()
+            .map_err(|message| ActuarialError::InforceRow { row, policy_id: Some(policy_id), message })
+    }
+
+    fn build_policy(self) -> Result<Policy, String> {
         let qual_status = match self.qual_status.as_str() {
             "Q" => QualStatus::Q,
             "N" => QualStatus::N,
-            other => return Err(format!("Unknown QualStatus: {}", other).into()),
+            other => return Err(format!("Unknown QualStatus: {}", other)),
         };
 
-        let gender = match self.gender.as_str() {
-            "Male" => Gender::Male,
-            "Female" => Gender::Female,
-            other => return Err(format!("Unknown Gender: {}", other).into()),
+        let gender = parse_gender(&self.gender)?;
+
+        let joint_life = match (self.joint_issue_age, self.joint_gender.as_deref()) {
+            (Some(issue_age), Some(joint_gender)) => {
+                let (first_alive, second_alive) = parse_joint_status(self.joint_status.as_deref().unwrap_or(""))?;
+                Some(JointLife { first_alive, second_alive, ..JointLife::new(issue_age, parse_gender(joint_gender)?) })
+            }
+            (None, None) => {
+                if self.joint_status.as_deref().is_some_and(|status| !status.trim().is_empty()) {
+                    return Err("JointStatus given on a single-life contract".to_string());
+                }
+                None
+            }
+            _ => {
+                return Err("JointIssueAge and JointGender must be given together".to_string())
+            }
         };
 
         let crediting_strategy = match self.crediting_strategy.as_str() {
             "Indexed" => CreditingStrategy::Indexed,
             "Fixed" => CreditingStrategy::Fixed,
-            other => return Err(format!("Unknown CreditingStrategy: {}", other).into()),
+            other => return Err(format!("Unknown CreditingStrategy: {}", other)),
         };
 
         let rollup_type = match self.rollup_type.as_str() {
             "Simple" => RollupType::Simple,
             "Compound" => RollupType::Compound,
-            other => return Err(format!("Unknown RollupType: {}", other).into()),
+            other => return Err(format!("Unknown RollupType: {}", other)),
         };
 
         let benefit_base_bucket = match self.benefit_base_bucket.as_str() {
@@ -87,8 +157,24 @@ impl CsvRow {
             "[100000, 200000)" => BenefitBaseBucket::From100kTo200k,
             "[200000, 500000)" => BenefitBaseBucket::From200kTo500k,
             "[500000, Inf)" => BenefitBaseBucket::Over500k,
-            other => return Err(format!("Unknown Benefit_Base_Bucket: {}", other).into()),
+            other => return Err(format!("Unknown Benefit_Base_Bucket: {}", other)),
+        };
+
+        // pricing_inforce.csv carries Bonus = 0 with the bonus already baked into
+        // InitialBB, so fill in the bonus implied by InitialBB/InitialPremium
+        let bonus = if self.bonus == 0.0 && self.initial_premium > 0.0 {
+            self.initial_bb / self.initial_premium - 1.0
+        } else {
+            self.bonus
+        };
+
+        let income_activated = match self.income_activated.as_deref() {
+            Some(flag) => parse_flag(flag)?,
+            None => false,
         };
+        if self.locked_payout_rate.is_some() && !income_activated {
+            return Err("LockedPayoutRate given but income is not activated".to_string());
+        }
 
         Ok(Policy {
             policy_id: self.policy_id,
@@ -104,25 +190,342 @@ impl CsvRow {
             sc_period: self.sc_period,
             val_rate: self.val_rate,
             mgir: self.mgir,
-            bonus: self.bonus,
+            bonus,
             rollup_type,
-            duration_months: 0,
-            income_activated: false,
+            rollup_rate: self.rollup,
+            rollup_duration: self.rollup_duration,
+            wait_period: self.wait_period,
+            duration_months: self.duration_months.unwrap_or(0),
+            income_activated,
             glwb_start_year: self.glwb_start_year,
-            current_av: None,
-            current_benefit_base: None,
+            current_av: self.current_av,
+            current_benefit_base: self.current_bb,
+            current_guaranteed_av: self.current_guaranteed_av,
+            locked_payout_rate: self.locked_payout_rate,
+            prior_av: self.prior_av,
+            prior_benefit_base: self.prior_bb,
+            ytd_systematic_wd: self.ytd_systematic_wd.unwrap_or(0.0),
+            ytd_non_systematic_wd: self.ytd_non_systematic_wd.unwrap_or(0.0),
+            issue_date: self.issue_date,
+            joint_life,
         })
     }
 }
 
+fn parse_gender(gender: &str) -> Result<Gender, String> {
+    match gender {
+        "Male" => Ok(Gender::Male),
+        "Female" => Ok(Gender::Female),
+        other => Err(format!("Unknown Gender: {}", other)),
+    }
+}
+
+/// Raw CSV row matching FIA_inforce.csv columns
+#[derive(Debug, serde::Deserialize)]
+struct FiaCsvRow {
+    #[serde(rename = "QualifiedStatus")]
+    qual_status: String,
+    #[serde(rename = "AttainedAge")]
+    attained_age: u8,
+    #[serde(rename = "Gender")]
+    gender: String,
+    #[serde(rename = "Benefit_Base", default)]
+    benefit_base: Option<f64>,
+    #[serde(rename = "Utilized_AV")]
+    utilized_av: f64,
+    #[serde(rename = "Utilized")]
+    utilized: f64,
+    #[serde(rename = "Election_exp_AV")]
+    election_exp_av: f64,
+    #[serde(rename = "Election_exp")]
+    election_exp: f64,
+    #[serde(rename = "n")]
+    n: f64,
+}
+
+impl FiaCsvRow {
+    fn into_record(self) -> Result<FiaInforceRecord, String> {
+        let qual_status = match self.qual_status.as_str() {
+            "Q" => QualStatus::Q,
+            "N" => QualStatus::N,
+            other => return Err(format!("Unknown QualifiedStatus: {}", other)),
+        };
+
+        let gender = match self.gender.as_str() {
+            "M" => Gender::Male,
+            "F" => Gender::Female,
+            other => return Err(format!("Unknown Gender: {}", other)),
+        };
+
+        Ok(FiaInforceRecord {
+            qual_status,
+            attained_age: self.attained_age,
+            gender,
+            benefit_base: self.benefit_base.unwrap_or(0.0),
+            utilized_av: self.utilized_av,
+            utilized: self.utilized,
+            election_exp_av: self.election_exp_av,
+            election_exp: self.election_exp,
+            policy_count: self.n,
+        })
+    }
+}
+
+/// One attained-age cell of the FIA_inforce.csv in-force summary
+///
+/// Benefit base is the average per policy; the AV and count columns are cell
+/// totals. Utilized policies are already taking GLWB income; expected
+/// elections count the utilized policies plus those expected to start income
+/// over the next year, so the election columns include the utilized ones.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FiaInforceRecord {
+    pub qual_status: QualStatus,
+    pub attained_age: u8,
+    pub gender: Gender,
+
+    /// Average benefit base per policy (0 when not reported)
+    pub benefit_base: f64,
+
+    /// Account value of the utilized policies
+    pub utilized_av: f64,
+
+    /// Policies taking GLWB income
+    pub utilized: f64,
+
+    /// Account value of the expected elections (including the utilized AV)
+    pub election_exp_av: f64,
+
+    /// Policies expected to have elected income by the end of the next year
+    /// (including the utilized policies)
+    pub election_exp: f64,
+
+    /// Policies in the cell
+    pub policy_count: f64,
+}
+
+impl FiaInforceRecord {
+    /// Total benefit base of the cell
+    pub fn total_benefit_base(&self) -> f64 {
+        self.benefit_base * self.policy_count
+    }
+
+    /// Reported account value of the cell (expected elections, which include
+    /// the utilized policies)
+    pub fn reported_av(&self) -> f64 {
+        self.election_exp_av.max(self.utilized_av)
+    }
+
+    /// Benefit base per policy used for the cell's records
+    ///
+    /// The reported benefit base where there is one; otherwise the average AV
+    /// of the policies reporting an AV, grossed up by `bb_to_av_ratio`. None
+    /// if the cell reports neither.
+    pub fn benefit_base_per_policy(&self, terms: &FiaInforceTerms) -> Option<f64> {
+        if self.benefit_base > 0.0 {
+            return Some(self.benefit_base);
+        }
+
+        let (av, lives) = if self.election_exp_av > 0.0 && self.election_exp > 0.0 {
+            (self.election_exp_av, self.election_exp)
+        } else {
+            (self.utilized_av, self.utilized)
+        };
+        (av > 0.0 && lives > 0.0).then(|| av / lives * terms.bb_to_av_ratio)
+    }
+
+    /// Convert the cell into policy records
+    ///
+    /// Each cell splits into up to three records: utilized (income active),
+    /// expected elections (income from the next policy year) and the
+    /// remaining deferred policies. Groups reporting an AV keep it; the rest
+    /// take the benefit base over `bb_to_av_ratio`. Fails for a cell with
+    /// policies but neither a benefit base nor an account value.
+    pub fn to_policies(&self, terms: &FiaInforceTerms, first_policy_id: u32) -> Result<Vec<Policy>, String> {
+        if self.policy_count <= 0.0 {
+            return Ok(Vec::new());
+        }
+        let bb_per_policy = self.benefit_base_per_policy(terms).ok_or_else(|| {
+            format!("No Benefit_Base or account value reported for {} policies", self.policy_count)
+        })?;
+
+        let utilized = self.utilized.clamp(0.0, self.policy_count);
+        let electing = self.election_exp.clamp(utilized, self.policy_count) - utilized;
+        let deferred = self.policy_count - utilized - electing;
+
+        let years_since_issue = (terms.duration_months.saturating_sub(1) / 12) as u8;
+        let current_policy_year = terms.duration_months.saturating_sub(1) / 12 + 1;
+
+        let groups = [
+            (utilized, self.utilized_av, true, current_policy_year),
+            (electing, (self.election_exp_av - self.utilized_av).max(0.0), false, current_policy_year + 1),
+            (deferred, 0.0, false, terms.deferred_glwb_start_year),
+        ];
+
+        let mut policies = Vec::new();
+        for (lives, reported_av, income_activated, glwb_start_year) in groups {
+            if lives <= 0.0 {
+                continue;
+            }
+
+            let benefit_base = bb_per_policy * lives;
+            let account_value = if reported_av > 0.0 {
+                reported_av
+            } else {
+                benefit_base / terms.bb_to_av_ratio
+            };
+
+            let mut policy = Policy::with_glwb_start(
+                first_policy_id + policies.len() as u32,
+                self.qual_status,
+                self.attained_age.saturating_sub(years_since_issue),
+                self.gender,
+                benefit_base,
+                lives,
+                benefit_base / terms.bb_to_av_ratio,
+                terms.crediting_strategy,
+                terms.sc_period,
+                terms.val_rate,
+                terms.mgir,
+                terms.bb_to_av_ratio - 1.0,
+                terms.rollup_type,
+                glwb_start_year,
+            )
+            .with_rollup_terms(terms.rollup_rate, terms.rollup_duration, glwb_start_year.saturating_sub(1));
+            policy.duration_months = terms.duration_months;
+            policy.current_av = Some(account_value);
+            policy.current_benefit_base = Some(benefit_base);
+            policy.income_activated = income_activated;
+            policies.push(policy);
+        }
+
+        Ok(policies)
+    }
+}
+
+/// Product terms applied when mapping FIA_inforce.csv cells to policies
+///
+/// The summary carries attained age and balances only, so the seasoning and
+/// contract terms are assumptions.
+#[derive(Debug, Clone)]
+pub struct FiaInforceTerms {
+    /// Months since issue assumed for every cell
+    pub duration_months: u32,
+
+    /// Benefit base per unit of account value where no AV is reported
+    /// (also sets the issue premium and the benefit base bonus)
+    pub bb_to_av_ratio: f64,
+
+    /// Policy year deferred policies start income (99 = never)
+    pub deferred_glwb_start_year: u32,
+
+    pub crediting_strategy: CreditingStrategy,
+    pub sc_period: u8,
+    pub val_rate: f64,
+    pub mgir: f64,
+    pub rollup_type: RollupType,
+    pub rollup_rate: f64,
+    pub rollup_duration: u32,
+}
+
+impl Default for FiaInforceTerms {
+    fn default() -> Self {
+        Self {
+            duration_months: 12,
+            bb_to_av_ratio: 1.3,
+            deferred_glwb_start_year: 99,
+            crediting_strategy: CreditingStrategy::Indexed,
+            sc_period: 10,
+            val_rate: 0.0475,
+            mgir: 0.01,
+            rollup_type: RollupType::Simple,
+            rollup_rate: 0.10,
+            rollup_duration: 10,
+        }
+    }
+}
+
+/// Load the FIA_inforce.csv block summary
+pub fn load_fia_inforce<P: AsRef<Path>>(path: P) -> Result<Vec<FiaInforceRecord>, ActuarialError> {
+    load_fia_inforce_from_reader(std::fs::File::open(path)?)
+}
+
+/// Load the FIA_inforce.csv block summary from any reader
+pub fn load_fia_inforce_from_reader<R: std::io::Read>(reader: R) -> Result<Vec<FiaInforceRecord>, ActuarialError> {
+    let mut csv_reader = Reader::from_reader(reader);
+    let mut records = Vec::new();
+
+    for (i, result) in csv_reader.deserialize().enumerate() {
+        let row: FiaCsvRow = result.map_err(|e| row_error(i + 1, e))?;
+        let record = row
+            .into_record()
+            .map_err(|message| ActuarialError::InforceRow { row: i + 1, policy_id: None, message })?;
+        records.push(record);
+    }
+
+    Ok(records)
+}
+
+/// Load FIA_inforce.csv, map every cell to seasoned policy records and validate them
+///
+/// Cells that cannot be mapped are reported as unreadable rows (row = data row
+/// of the cell) instead of being dropped.
+pub fn load_fia_inforce_validated<P: AsRef<Path>>(
+    path: P,
+    terms: &FiaInforceTerms,
+    validator: &InforceValidator,
+) -> Result<InforceValidation, ActuarialError> {
+    let mut validation = InforceValidation::default();
+    let mut next_policy_id = 1;
+
+    for (i, record) in load_fia_inforce(path)?.into_iter().enumerate() {
+        match record.to_policies(terms, next_policy_id) {
+            Ok(policies) => {
+                next_policy_id += policies.len() as u32;
+                for policy in policies {
+                    validator.add_row(&mut validation, i + 1, Ok(policy));
+                }
+            }
+            Err(message) => {
+                let error = ActuarialError::InforceRow { row: i + 1, policy_id: None, message };
+                validator.add_row(&mut validation, i + 1, Err(error));
+            }
+        }
+    }
+
+    Ok(validation)
+}
+
+/// Error for a data row (1-based, header excluded) the CSV reader rejected
+fn row_error(row: usize, error: csv::Error) -> ActuarialError {
+    ActuarialError::InforceRow { row, policy_id: None, message: error.to_string() }
+}
+
+/// Survival of (annuitant, second life) for a joint status (blank = both alive)
+fn parse_joint_status(status: &str) -> Result<(f64, f64), String> {
+    match status.trim() {
+        "BothAlive" | "" => Ok((1.0, 1.0)),
+        "PrimaryDeceased" => Ok((0.0, 1.0)),
+        "JointDeceased" => Ok((1.0, 0.0)),
+        other => Err(format!("Unknown JointStatus: {}", other)),
+    }
+}
+
+fn parse_flag(flag: &str) -> Result<bool, String> {
+    match flag.trim().to_ascii_uppercase().as_str() {
+        "TRUE" | "1" | "Y" => Ok(true),
+        "FALSE" | "0" | "N" | "" => Ok(false),
+        other => Err(format!("Unknown IncomeActivated flag: {}", other)),
+    }
+}
+
 /// Load all policies from a CSV file
-pub fn load_policies<P: AsRef<Path>>(path: P) -> Result<Vec<Policy>, Box<dyn Error>> {
+pub fn load_policies<P: AsRef<Path>>(path: P) -> Result<Vec<Policy>, ActuarialError> {
     let mut reader = Reader::from_path(path)?;
     let mut policies = Vec::new();
 
-    for result in reader.deserialize() {
-        let row: CsvRow = result?;
-        let policy = row.to_policy()?;
+    for (i, result) in reader.deserialize().enumerate() {
+        let row: CsvRow = result.map_err(|e| row_error(i + 1, e))?;
+        let policy = row.to_policy(i + 1)?;
         policies.push(policy);
     }
 
@@ -130,25 +533,93 @@ pub fn load_policies<P: AsRef<Path>>(path: P) -> Result<Vec<Policy>, Box<dyn Err
 }
 
 /// Load policies from any reader (e.g., string buffer, network stream)
-pub fn load_policies_from_reader<R: std::io::Read>(reader: R) -> Result<Vec<Policy>, Box<dyn Error>> {
+pub fn load_policies_from_reader<R: std::io::Read>(reader: R) -> Result<Vec<Policy>, ActuarialError> {
     let mut csv_reader = Reader::from_reader(reader);
     let mut policies = Vec::new();
 
-    for result in csv_reader.deserialize() {
-        let row: CsvRow = result?;
-        let policy = row.to_policy()?;
+    for (i, result) in csv_reader.deserialize().enumerate() {
+        let row: CsvRow = result.map_err(|e| row_error(i + 1, e))?;
+        let policy = row.to_policy(i + 1)?;
         policies.push(policy);
     }
 
     Ok(policies)
 }
 
+/// Load policies from a CSV file, validating every row
+///
+/// Bad rows are reported in the exception report rather than stopping the
+/// load; only a file that cannot be opened or has no header row fails.
+pub fn load_policies_validated<P: AsRef<Path>>(
+    path: P,
+    validator: &InforceValidator,
+) -> Result<InforceValidation, ActuarialError> {
+    load_policies_validated_from_reader(std::fs::File::open(path)?, validator)
+}
+
+/// Load policies from any reader, validating every row
+pub fn load_policies_validated_from_reader<R: std::io::Read>(
+    reader: R,
+    validator: &InforceValidator,
+) -> Result<InforceValidation, ActuarialError> {
+    let mut csv_reader = Reader::from_reader(reader);
+    csv_reader.headers()?;
+
+    let mut validation = InforceValidation::default();
+    for (i, result) in csv_reader.deserialize::<CsvRow>().enumerate() {
+        let parsed = result.map_err(|e| row_error(i + 1, e)).and_then(|row| row.to_policy(i + 1));
+        validator.add_row(&mut validation, i + 1, parsed);
+    }
+
+    Ok(validation)
+}
+
+/// Load policies from an admin extract through a schema mapping
+pub fn load_policies_with_schema<P: AsRef<Path>>(path: P, schema: &InforceSchema) -> Result<Vec<Policy>, ActuarialError> {
+    load_policies_with_schema_from_reader(std::fs::File::open(path)?, schema)
+}
+
+/// Load policies from any reader through a schema mapping
+pub fn load_policies_with_schema_from_reader<R: std::io::Read>(
+    reader: R,
+    schema: &InforceSchema,
+) -> Result<Vec<Policy>, ActuarialError> {
+    let mut csv_reader = Reader::from_reader(reader);
+    let headers = csv_reader.headers()?.clone();
+    schema.check_headers(&headers)?;
+
+    let mut policies = Vec::new();
+    for (i, result) in csv_reader.records().enumerate() {
+        let record = result.map_err(|e| row_error(i + 1, e))?;
+        let (fields, values) = schema
+            .translate(&headers, &record)
+            .map_err(|message| ActuarialError::InforceRow { row: i + 1, policy_id: None, message })?;
+        let row: CsvRow = values.deserialize(Some(&fields)).map_err(|e| row_error(i + 1, e))?;
+        policies.push(row.to_policy(i + 1)?);
+    }
+
+    Ok(policies)
+}
+
+/// Load policies from a CSV file and assign statutory valuation rates
+///
+/// Policies with an IssueDate get the SVL maximum valuation rate for their
+/// issue year; undated policies keep the ValRate column.
+pub fn load_policies_with_valuation_rates<P: AsRef<Path>>(
+    path: P,
+    basis: &ValuationRateBasis,
+) -> Result<Vec<Policy>, ActuarialError> {
+    let mut policies = load_policies(path)?;
+    basis.assign(&mut policies)?;
+    Ok(policies)
+}
+
 /// Load policies from embedded data (for Lambda) or fall back to file
 ///
 /// This function first tries to load from the embedded CSV data compiled into the binary,
 /// which is the primary method for Lambda deployments. If that fails (shouldn't happen),
 /// it falls back to loading from the file system for local development.
-pub fn load_default_inforce() -> Result<Vec<Policy>, Box<dyn Error>> {
+pub fn load_default_inforce() -> Result<Vec<Policy>, ActuarialError> {
     // Use embedded data - works in Lambda and locally
     load_policies_from_reader(Cursor::new(EMBEDDED_INFORCE_CSV))
 }
@@ -156,6 +627,8 @@ pub fn load_default_inforce() -> Result<Vec<Policy>, Box<dyn Error>> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::assumptions::ProductFeatures;
+    use crate::policy::validation::{ExceptionAction, ValidationOptions};
 
     #[test]
     fn test_load_policies() {
@@ -171,5 +644,177 @@ mod tests {
         assert_eq!(p10.policy_id, 10);
         assert_eq!(p10.issue_age, 57);
         assert_eq!(p10.glwb_start_year, 5);
+
+        // Rollup terms come from the Rollup/RollupDuration/WaitPeriod columns
+        assert!((p10.rollup_rate - 0.10).abs() < 1e-12);
+        assert_eq!(p10.rollup_duration, 10);
+        assert_eq!(p10.wait_period, 4);
+        assert!(p10.issue_date.is_none());
+
+        // Bonus = 0 in the file: the bonus is the one baked into InitialBB
+        assert!((p10.bonus - (p10.initial_benefit_base / p10.initial_premium - 1.0)).abs() < 1e-12);
+        assert!(p10.bonus > 0.0);
+    }
+
+    #[test]
+    fn test_issue_date_assigns_valuation_rate() {
+        use crate::assumptions::{PlanType, ReferenceRateHistory};
+
+        let mut lines: Vec<String> = EMBEDDED_INFORCE_CSV.lines().take(3).map(String::from).collect();
+        lines[0].push_str(",IssueDate");
+        lines[1].push_str(",2023-03-15");
+        lines[2].push(',');
+
+        let mut policies = load_policies_from_reader(lines.join("\n").as_bytes()).unwrap();
+        assert_eq!(policies[0].issue_date, NaiveDate::from_ymd_opt(2023, 3, 15));
+        assert!(policies[1].issue_date.is_none());
+
+        // Reference 5.5% for the 12 months to June 2023: 3% + 0.55 * 2.5% -> 4.50%
+        let history = ReferenceRateHistory::new((1..=12).flat_map(|m| [(2022, m, 0.055), (2023, m, 0.055)]));
+        let basis = ValuationRateBasis::spda(history, PlanType::C);
+        let csv_rate = policies[1].val_rate;
+        basis.assign(&mut policies).unwrap();
+        assert!((policies[0].val_rate - 0.045).abs() < 1e-12);
+        assert_eq!(policies[1].val_rate, csv_rate);
+    }
+
+    #[test]
+    fn test_seasoned_inforce_columns() {
+        let mut lines: Vec<String> = EMBEDDED_INFORCE_CSV.lines().take(3).map(String::from).collect();
+        lines[0].push_str(",DurationMonths,CurrentAV,CurrentBB,IncomeActivated,LockedPayoutRate,PriorAV,PriorBB,YTDSystematicWD");
+        lines[1].push_str(",30,95000,140000,Y,0.06,95500,140000,4200");
+        lines[2].push_str(",,,,,,,,");
+
+        let policies = load_policies_from_reader(lines.join("\n").as_bytes()).unwrap();
+        let seasoned = &policies[0];
+        assert!(seasoned.is_seasoned());
+        assert_eq!(seasoned.policy_month(1), 31);
+        assert_eq!(seasoned.starting_av(), 95_000.0);
+        assert_eq!(seasoned.starting_benefit_base(), 140_000.0);
+        assert!(seasoned.income_activated);
+        assert_eq!(seasoned.locked_payout_rate, Some(0.06));
+        assert_eq!(seasoned.prior_av, Some(95_500.0));
+        assert_eq!(seasoned.ytd_systematic_wd, 4200.0);
+
+        // Blank state columns load as a new issue
+        assert!(!policies[1].is_seasoned());
+        assert!(!policies[1].income_activated);
+        assert_eq!(policies[1].current_av, None);
+
+        // A locked rate needs activated income; the error names the row and policy
+        lines[1] = lines[1].replace(",Y,", ",N,");
+        match load_policies_from_reader(lines.join("\n").as_bytes()) {
+            Err(ActuarialError::InforceRow { row, policy_id, .. }) => {
+                assert_eq!(row, 1);
+                assert_eq!(policy_id, Some(1));
+            }
+            other => panic!("expected an inforce row error, got {:?}", other.map(|p| p.len())),
+        }
+    }
+
+    #[test]
+    fn test_joint_status_column() {
+        let mut lines: Vec<String> = EMBEDDED_INFORCE_CSV.lines().take(4).map(String::from).collect();
+        lines[0].push_str(",JointIssueAge,JointGender,JointStatus");
+        lines[1].push_str(",68,Female,");
+        lines[2].push_str(",68,Female,PrimaryDeceased");
+        lines[3].push_str(",,,");
+
+        let policies = load_policies_from_reader(lines.join("\n").as_bytes()).unwrap();
+        assert!(policies[0].has_joint_payout());
+        let survivor = policies[1].joint_life.unwrap();
+        assert_eq!((survivor.first_alive, survivor.second_alive), (0.0, 1.0));
+        assert!(policies[1].is_joint() && !policies[1].has_joint_payout());
+        assert!(!policies[2].is_joint());
+
+        lines[3].push_str("JointDeceased");
+        assert!(matches!(
+            load_policies_from_reader(lines.join("\n").as_bytes()),
+            Err(ActuarialError::InforceRow { row: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn test_fia_inforce_policies() {
+        let csv = "QualifiedStatus,AttainedAge,Gender,Benefit_Base,Utilized_AV,Utilized,Election_exp_AV,Election_exp,n\n\
+                   Q,70,M,130000,200000,2,350000,3,10\n\
+                   N,40,F,,0,0,0,0,3\n\
+                   N,75,F,,200000,2,300000,3,5\n";
+        let records = load_fia_inforce_from_reader(csv.as_bytes()).unwrap();
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[1].benefit_base, 0.0);
+
+        let terms = FiaInforceTerms { duration_months: 30, ..Default::default() };
+        let policies = records[0].to_policies(&terms, 1).unwrap();
+        assert_eq!(policies.len(), 3);
+        assert!(records[1].to_policies(&terms, 4).is_err());
+
+        // No benefit base: 100,000 average reported AV grossed up by the ratio
+        let derived = records[2].to_policies(&terms, 4).unwrap();
+        assert_eq!(derived.iter().map(|p| p.initial_pols).sum::<f64>(), 5.0);
+        assert_eq!(derived[0].starting_av(), 200_000.0);
+        assert!((derived[0].starting_benefit_base() - 260_000.0).abs() < 1e-6);
+        assert_eq!(derived[1].starting_av(), 100_000.0);
+        assert!((derived[2].starting_av() - 200_000.0).abs() < 1e-6);
+
+        // Utilized: income active from the reported AV
+        let utilized = &policies[0];
+        assert!(utilized.income_activated);
+        assert_eq!(utilized.initial_pols, 2.0);
+        assert_eq!(utilized.starting_av(), 200_000.0);
+        assert_eq!(utilized.starting_benefit_base(), 260_000.0);
+        assert_eq!(utilized.attained_age(0), 70);
+        assert_eq!(utilized.issue_age, 68);
+
+        // Expected elections start income next policy year
+        let electing = &policies[1];
+        assert!(!electing.income_activated);
+        assert!(!electing.should_activate_income(1));
+        assert!(electing.should_activate_income(7));
+
+        // Deferred: AV from the benefit base, never activating by default
+        let deferred = &policies[2];
+        assert_eq!(deferred.initial_pols, 7.0);
+        assert!((deferred.starting_av() - 7.0 * 100_000.0).abs() < 1e-6);
+        assert_eq!(deferred.glwb_start_year, 99);
+        assert_eq!(policies.iter().map(|p| p.policy_id).collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_fia_inforce_reconciles_to_file() {
+        let terms = FiaInforceTerms::default();
+        let records = load_fia_inforce("FIA_inforce.csv").unwrap();
+        let validator = InforceValidator::new(
+            &ProductFeatures::default(),
+            ValidationOptions::default().with_action(ExceptionAction::Quarantine),
+        );
+        let validation = load_fia_inforce_validated("FIA_inforce.csv", &terms, &validator).unwrap();
+        assert!(validation.summary.is_reconciled());
+
+        let file_lives: f64 = records.iter().map(|r| r.policy_count).sum();
+        let file_av: f64 = records.iter().map(|r| r.reported_av()).sum();
+        assert!((file_lives - 166_455.0).abs() < 1e-6);
+
+        // Every policy in the file is either read or reported as unmappable
+        let unmapped: Vec<_> = records.iter().filter(|r| r.to_policies(&terms, 1).is_err()).collect();
+        let unmapped_lives: f64 = unmapped.iter().map(|r| r.policy_count).sum();
+        assert_eq!(validation.summary.unreadable_rows, unmapped.len());
+        assert!((validation.summary.read.lives + unmapped_lives - file_lives).abs() < 1e-6);
+
+        // Every reported AV lands on a loaded policy; unmappable cells report none
+        assert!(unmapped.iter().all(|r| r.reported_av() == 0.0));
+        let loaded = validation.policies.iter().chain(&validation.quarantined);
+        let loaded_av: f64 = loaded.map(|p| p.starting_av()).sum();
+        let derived_av: f64 = records
+            .iter()
+            .filter_map(|r| {
+                let groups = [(r.utilized_av, r.utilized), (r.election_exp_av - r.utilized_av, r.election_exp - r.utilized)];
+                let reporting: f64 = groups.iter().filter(|(av, _)| *av > 0.0).map(|(_, lives)| lives).sum();
+                r.benefit_base_per_policy(&terms)
+                    .map(|bb| (r.policy_count - reporting) * bb / terms.bb_to_av_ratio)
+            })
+            .sum();
+        assert!(loaded_av >= file_av);
+        assert!((loaded_av - derived_av - file_av).abs() < 1e-6 * file_av);
     }
 }
@@ -4,8 +4,20 @@ mod data;
 pub mod loader;
 pub mod generator;
 pub mod adjuster;
+pub mod schema;
+pub mod validation;
 
-pub use data::{Policy, QualStatus, Gender, CreditingStrategy, RollupType, BenefitBaseBucket};
-pub use loader::{load_policies, load_policies_from_reader, load_default_inforce};
+pub use data::{Policy, JointLife, QualStatus, Gender, CreditingStrategy, RollupType, BenefitBaseBucket};
+pub use loader::{
+    load_policies, load_policies_from_reader, load_policies_with_valuation_rates, load_default_inforce,
+    load_policies_with_schema, load_policies_with_schema_from_reader,
+    load_policies_validated, load_policies_validated_from_reader,
+    load_fia_inforce, load_fia_inforce_from_reader, load_fia_inforce_validated, FiaInforceRecord, FiaInforceTerms,
+};
 pub use generator::{InforceParams, InforceTemplate};
+pub use schema::{InforceSchema, DerivedField};
+pub use validation::{
+    InforceValidator, InforceValidation, ValidationOptions, ValidationException, ValidationRule,
+    ValidationSummary, RowTotals, Severity, ExceptionAction, write_exception_csv,
+};
 pub use adjuster::{AdjustmentParams, load_adjusted_inforce};
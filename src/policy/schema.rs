@@ -0,0 +1,249 @@
+//! Declarative inforce schema mapping
+//!
+//! Admin-system extracts use their own headers and codes. An `InforceSchema`
+//! maps them onto the pricing_inforce.csv columns the loader understands:
+//!
+//! ```json
+//! {
+//!   "columns":  { "PolicyID": "POL_NO", "IssueAge": "ISS_AGE", "Gender": "SEX" },
+//!   "values":   { "Gender": { "M": "Male", "F": "Female" }, "QualStatus": { "Y": "Q" } },
+//!   "defaults": { "SCPeriod": "10", "valRate": "0.0475" },
+//!   "derived":  { "Benefit_Base_Bucket": "BenefitBaseBucket" }
+//! }
+//! ```
+//!
+//! Columns not listed are read under their pricing_inforce.csv name. Value
+//! translations apply to the raw extract value; unlisted values pass through.
+//! Defaults fill blank or absent columns, and derived fields are computed
+//! from the translated row.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use csv::StringRecord;
+use serde::{Deserialize, Serialize};
+
+use super::loader::INFORCE_COLUMNS;
+use super::BenefitBaseBucket;
+use crate::error::ActuarialError;
+
+/// Field computed from other columns of the translated row
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DerivedField {
+    /// Bucket of benefit base per life (InitialBB / InitialPols)
+    BenefitBaseBucket,
+
+    /// GLWBStartYear - 1
+    WaitPeriod,
+}
+
+/// Mapping from an admin extract onto the pricing_inforce.csv columns
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct InforceSchema {
+    /// Pricing column -> extract column
+    #[serde(default)]
+    pub columns: HashMap<String, String>,
+
+    /// Pricing column -> (extract value -> pricing value)
+    #[serde(default)]
+    pub values: HashMap<String, HashMap<String, String>>,
+
+    /// Pricing column -> value used when the extract has none
+    #[serde(default)]
+    pub defaults: HashMap<String, String>,
+
+    /// Pricing column -> how to compute it
+    #[serde(default)]
+    pub derived: HashMap<String, DerivedField>,
+}
+
+impl InforceSchema {
+    /// Load a schema from a JSON mapping file
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, ActuarialError> {
+        let json = std::fs::read_to_string(path)?;
+        Self::from_json(&json)
+    }
+
+    /// Parse and check a schema from JSON
+    pub fn from_json(json: &str) -> Result<Self, ActuarialError> {
+        let schema: Self = serde_json::from_str(json)?;
+        schema.validate()?;
+        Ok(schema)
+    }
+
+    /// Check every mapped field is a pricing_inforce.csv column
+    pub fn validate(&self) -> Result<(), ActuarialError> {
+        let fields = self.columns.keys()
+            .chain(self.values.keys())
+            .chain(self.defaults.keys())
+            .chain(self.derived.keys());
+        for field in fields {
+            if !INFORCE_COLUMNS.contains(&field.as_str()) {
+                return Err(ActuarialError::Config(format!("Unknown inforce field in schema: {}", field)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Extract column holding a pricing column
+    pub fn source_column<'a>(&'a self, field: &'a str) -> &'a str {
+        self.columns.get(field).map_or(field, String::as_str)
+    }
+
+    /// Check the extract has every explicitly mapped column
+    pub fn check_headers(&self, headers: &StringRecord) -> Result<(), ActuarialError> {
+        for (field, column) in &self.columns {
+            if !headers.iter().any(|h| h == column) {
+                return Err(ActuarialError::Config(format!("Extract has no column {} (mapped to {})", column, field)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Translate one extract row into pricing_inforce.csv headers and values
+    ///
+    /// Only columns with a value are returned, so required columns that are
+    /// still missing fail when the row is deserialized. Errors are row-level
+    /// messages for the caller to tag with the row number.
+    pub fn translate(
+        &self,
+        headers: &StringRecord,
+        record: &StringRecord,
+    ) -> Result<(StringRecord, StringRecord), String> {
+        let mut row: Vec<(&str, String)> = Vec::new();
+
+        for &field in INFORCE_COLUMNS {
+            if self.derived.contains_key(field) {
+                continue;
+            }
+
+            let source = self.source_column(field);
+            let raw = headers
+                .iter()
+                .position(|h| h == source)
+                .and_then(|i| record.get(i))
+                .map(str::trim)
+                .unwrap_or("");
+
+            let value = match self.values.get(field).and_then(|codes| codes.get(raw)) {
+                Some(translated) => translated.clone(),
+                None if raw.is_empty() => match self.defaults.get(field) {
+                    Some(default) => default.clone(),
+                    None => continue,
+                },
+                None => raw.to_string(),
+            };
+            row.push((field, value));
+        }
+
+        // Derived fields, in column order so they can build on each other
+        for &field in INFORCE_COLUMNS {
+            let Some(derived) = self.derived.get(field) else {
+                continue;
+            };
+            let value = derived.compute(&row)?;
+            row.push((field, value));
+        }
+
+        Ok((
+            row.iter().map(|(field, _)| *field).collect(),
+            row.iter().map(|(_, value)| value.as_str()).collect(),
+        ))
+    }
+}
+
+impl DerivedField {
+    fn compute(&self, row: &[(&str, String)]) -> Result<String, String> {
+        let number = |field: &str| -> Result<f64, String> {
+            let value = row
+                .iter()
+                .find(|(f, _)| *f == field)
+                .map(|(_, v)| v)
+                .ok_or_else(|| format!("{:?} needs {}", self, field))?;
+            value
+                .parse::<f64>()
+                .map_err(|_| format!("{:?}: {} is not a number: {}", self, field, value))
+        };
+
+        match self {
+            DerivedField::BenefitBaseBucket => {
+                let bb = number("InitialBB")?;
+                let pols = number("InitialPols")?;
+                let bb_per_life = if pols > 0.0 { bb / pols } else { bb };
+                Ok(BenefitBaseBucket::from_amount(bb_per_life).as_str().to_string())
+            }
+            DerivedField::WaitPeriod => {
+                let start_year = number("GLWBStartYear")?;
+                Ok((start_year as u32).saturating_sub(1).to_string())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::{load_policies_with_schema_from_reader, Gender, QualStatus};
+
+    const ADMIN_EXTRACT: &str = "\
+POL_NO,QUAL,ISS_AGE,SEX,BB,POLS,PREM,FUND,SC_YRS,INC_YR
+101,Y,65,M,130000,1,100000,IDX,10,5
+102,N,70,F,40000,1,30769.23,FIX,7,
+";
+
+    fn admin_schema() -> InforceSchema {
+        InforceSchema::from_file("data/admin_extract_schema.json").expect("Failed to load schema")
+    }
+
+    #[test]
+    fn test_admin_extract_mapping() {
+        let policies = load_policies_with_schema_from_reader(ADMIN_EXTRACT.as_bytes(), &admin_schema()).unwrap();
+        assert_eq!(policies.len(), 2);
+
+        let p = &policies[0];
+        assert_eq!(p.policy_id, 101);
+        assert_eq!(p.qual_status, QualStatus::Q);
+        assert_eq!(p.gender, Gender::Male);
+        assert_eq!(p.benefit_base_bucket, BenefitBaseBucket::From100kTo200k);
+        assert_eq!(p.glwb_start_year, 5);
+        assert_eq!(p.wait_period, 4);
+
+        // Blank income year falls back to the default
+        assert_eq!(policies[1].glwb_start_year, 99);
+        assert_eq!(policies[1].wait_period, 98);
+        assert_eq!(policies[1].benefit_base_bucket, BenefitBaseBucket::Under50k);
+    }
+
+    #[test]
+    fn test_schema_errors() {
+        // Typo in a pricing field name
+        assert!(InforceSchema::from_json(r#"{ "columns": { "IssueAg": "ISS_AGE" } }"#).is_err());
+
+        // Mapped column missing from the extract
+        let mut schema = admin_schema();
+        schema.columns.insert("MGIR".to_string(), "MIN_RATE".to_string());
+        assert!(load_policies_with_schema_from_reader(ADMIN_EXTRACT.as_bytes(), &schema).is_err());
+
+        // Required column with no mapping, value or default
+        let mut schema = admin_schema();
+        schema.defaults.remove("valRate");
+        assert!(load_policies_with_schema_from_reader(ADMIN_EXTRACT.as_bytes(), &schema).is_err());
+
+        // A missing schema file is an I/O error, not a configuration error
+        assert!(matches!(
+            InforceSchema::from_file("data/no_such_schema.json"),
+            Err(ActuarialError::Io(e)) if e.kind() == std::io::ErrorKind::NotFound
+        ));
+    }
+
+    #[test]
+    fn test_default_schema_reads_pricing_inforce() {
+        let csv = std::fs::read_to_string("pricing_inforce.csv").unwrap();
+        let mapped = load_policies_with_schema_from_reader(csv.as_bytes(), &InforceSchema::default()).unwrap();
+        let direct = crate::policy::load_policies_from_reader(csv.as_bytes()).unwrap();
+        assert_eq!(mapped.len(), direct.len());
+        assert!(mapped.iter().zip(&direct).all(|(a, b)| a.policy_id == b.policy_id
+            && a.initial_benefit_base == b.initial_benefit_base
+            && a.benefit_base_bucket == b.benefit_base_bucket));
+    }
+}
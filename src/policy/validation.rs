@@ -0,0 +1,526 @@
+//! Inforce data validation and exception reporting
+//!
+//! Checks loaded policies against the product's issue limits and internal
+//! consistency, recording every exception (row, field, rule, severity)
+//! instead of stopping at the first bad row. Error rows are rejected, fixed
+//! where a fix exists, or quarantined for review, per `ExceptionAction`.
+
+use std::collections::HashMap;
+use std::io::Write;
+
+use serde::{Deserialize, Serialize};
+
+use super::{BenefitBaseBucket, Policy};
+use crate::assumptions::ProductFeatures;
+use crate::error::ActuarialError;
+
+/// Severity of a validation exception
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Severity {
+    /// Reported; the row is still loaded
+    Warning,
+    /// The row is rejected, fixed or quarantined
+    Error,
+}
+
+/// Validation rule that raised an exception
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ValidationRule {
+    /// Row could not be parsed into a policy
+    Unreadable,
+    /// Premium, benefit base or account value below zero
+    NegativeAmount,
+    /// No lives on the record
+    NonPositiveLives,
+    /// Issue age outside the product's issue ages
+    IssueAgeRange,
+    /// Premium per life outside the product's premium limits
+    PremiumRange,
+    /// Benefit base bucket inconsistent with benefit base per life
+    BucketMismatch,
+    /// Income starts before the minimum activation age
+    GlwbStartBeforeMinAge,
+}
+
+impl ValidationRule {
+    /// Severity unless overridden in `ValidationOptions`
+    pub fn default_severity(&self) -> Severity {
+        match self {
+            ValidationRule::PremiumRange => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+}
+
+/// What to do with rows that have errors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExceptionAction {
+    /// Drop the row
+    Reject,
+    /// Apply the rule's fix where one exists; reject rows that still have errors
+    Fix,
+    /// Set the row aside for review
+    Quarantine,
+}
+
+/// Validation settings
+#[derive(Debug, Clone)]
+pub struct ValidationOptions {
+    pub action: ExceptionAction,
+
+    /// Per-rule severities replacing `ValidationRule::default_severity`
+    pub severity_overrides: HashMap<ValidationRule, Severity>,
+}
+
+impl Default for ValidationOptions {
+    fn default() -> Self {
+        Self {
+            action: ExceptionAction::Reject,
+            severity_overrides: HashMap::new(),
+        }
+    }
+}
+
+impl ValidationOptions {
+    /// Set the action for rows with errors
+    pub fn with_action(mut self, action: ExceptionAction) -> Self {
+        self.action = action;
+        self
+    }
+
+    /// Override the severity of a rule
+    pub fn with_severity(mut self, rule: ValidationRule, severity: Severity) -> Self {
+        self.severity_overrides.insert(rule, severity);
+        self
+    }
+
+    fn severity(&self, rule: ValidationRule) -> Severity {
+        self.severity_overrides.get(&rule).copied().unwrap_or_else(|| rule.default_severity())
+    }
+}
+
+/// One exception in the report
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidationException {
+    /// Data row in the file (1 = first row after the header)
+    pub row: usize,
+
+    /// Policy on the row (None if the row could not be read)
+    pub policy_id: Option<u32>,
+
+    /// Inforce column the rule checks
+    pub field: String,
+
+    pub rule: ValidationRule,
+    pub severity: Severity,
+    pub message: String,
+
+    /// Whether the value was corrected
+    pub fixed: bool,
+}
+
+/// Row count, premium and lives of a set of rows
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct RowTotals {
+    pub rows: usize,
+    pub premium: f64,
+    pub lives: f64,
+}
+
+impl RowTotals {
+    fn add(&mut self, policy: &Policy) {
+        self.rows += 1;
+        self.premium += policy.initial_premium;
+        self.lives += policy.initial_pols;
+    }
+}
+
+/// Totals reconciling the rows read against where they ended up
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ValidationSummary {
+    /// Rows parsed into policies
+    pub read: RowTotals,
+    pub loaded: RowTotals,
+    pub rejected: RowTotals,
+    pub quarantined: RowTotals,
+
+    /// Rows that could not be parsed (always rejected; no premium or lives)
+    pub unreadable_rows: usize,
+
+    /// Loaded rows with at least one fix applied
+    pub fixed_rows: usize,
+
+    pub warnings: usize,
+    pub errors: usize,
+}
+
+impl ValidationSummary {
+    /// Loaded + rejected + quarantined equals what was read
+    pub fn is_reconciled(&self) -> bool {
+        let parts = [self.loaded, self.rejected, self.quarantined];
+        let rows: usize = parts.iter().map(|t| t.rows).sum();
+        let premium: f64 = parts.iter().map(|t| t.premium).sum();
+        let lives: f64 = parts.iter().map(|t| t.lives).sum();
+        rows == self.read.rows
+            && (premium - self.read.premium).abs() <= 1e-6 * self.read.premium.abs().max(1.0)
+            && (lives - self.read.lives).abs() <= 1e-9 * self.read.lives.abs().max(1.0)
+    }
+}
+
+/// Outcome of validating an inforce file
+#[derive(Debug, Clone, Default)]
+pub struct InforceValidation {
+    /// Policies to project (clean, warned or fixed)
+    pub policies: Vec<Policy>,
+
+    /// Policies set aside under `ExceptionAction::Quarantine`
+    pub quarantined: Vec<Policy>,
+
+    pub exceptions: Vec<ValidationException>,
+    pub summary: ValidationSummary,
+}
+
+impl InforceValidation {
+    /// Exceptions at a given severity
+    pub fn exceptions_at(&self, severity: Severity) -> impl Iterator<Item = &ValidationException> {
+        self.exceptions.iter().filter(move |e| e.severity == severity)
+    }
+}
+
+/// Validates policies against product limits
+pub struct InforceValidator {
+    min_issue_age: u8,
+    max_issue_age: u8,
+    min_premium: f64,
+    max_premium: f64,
+    min_activation_age: u8,
+    options: ValidationOptions,
+}
+
+impl InforceValidator {
+    /// Validator using the product's issue ages, premium limits and minimum activation age
+    pub fn new(product: &ProductFeatures, options: ValidationOptions) -> Self {
+        Self {
+            min_issue_age: product.base.min_issue_age,
+            max_issue_age: product.base.max_issue_age,
+            min_premium: product.base.min_premium,
+            max_premium: product.base.max_premium,
+            min_activation_age: product.glwb.min_activation_age,
+            options,
+        }
+    }
+
+    /// Validate already-loaded policies (row = position in the slice, 1-indexed)
+    pub fn validate(&self, policies: Vec<Policy>) -> InforceValidation {
+        let mut validation = InforceValidation::default();
+        for (i, policy) in policies.into_iter().enumerate() {
+            self.add_row(&mut validation, i + 1, Ok(policy));
+        }
+        validation
+    }
+
+    /// Validate one row and file it as loaded, rejected or quarantined
+    pub fn add_row(&self, validation: &mut InforceValidation, row: usize, parsed: Result<Policy, ActuarialError>) {
+        let mut policy = match parsed {
+            Ok(policy) => policy,
+            Err(e) => {
+                let (policy_id, message) = match e {
+                    ActuarialError::InforceRow { policy_id, message, .. } => (policy_id, message),
+                    other => (None, other.to_string()),
+                };
+                validation.summary.unreadable_rows += 1;
+                validation.summary.errors += 1;
+                validation.exceptions.push(ValidationException {
+                    row,
+                    policy_id,
+                    field: String::new(),
+                    rule: ValidationRule::Unreadable,
+                    severity: Severity::Error,
+                    message,
+                    fixed: false,
+                });
+                return;
+            }
+        };
+        validation.summary.read.add(&policy);
+
+        let fix = self.options.action == ExceptionAction::Fix;
+        let mut has_error = false;
+        let mut fixed_any = false;
+
+        for (field, rule, message) in self.check(&policy) {
+            let severity = self.options.severity(rule);
+            let fixed = fix && self.apply_fix(&mut policy, rule);
+            match severity {
+                Severity::Warning => validation.summary.warnings += 1,
+                Severity::Error => {
+                    validation.summary.errors += 1;
+                    has_error |= !fixed;
+                }
+            }
+            fixed_any |= fixed;
+
+            validation.exceptions.push(ValidationException {
+                row,
+                policy_id: Some(policy.policy_id),
+                field: field.to_string(),
+                rule,
+                severity,
+                message,
+                fixed,
+            });
+        }
+
+        if !has_error {
+            if fixed_any {
+                validation.summary.fixed_rows += 1;
+            }
+            validation.summary.loaded.add(&policy);
+            validation.policies.push(policy);
+        } else if self.options.action == ExceptionAction::Quarantine {
+            validation.summary.quarantined.add(&policy);
+            validation.quarantined.push(policy);
+        } else {
+            validation.summary.rejected.add(&policy);
+        }
+    }
+
+    /// Every rule the policy breaks: (field, rule, message)
+    pub fn check(&self, policy: &Policy) -> Vec<(&'static str, ValidationRule, String)> {
+        let mut found = Vec::new();
+
+        let amounts = [
+            ("InitialPremium", Some(policy.initial_premium)),
+            ("InitialBB", Some(policy.initial_benefit_base)),
+            ("CurrentAV", policy.current_av),
+            ("CurrentBB", policy.current_benefit_base),
+        ];
+        for (field, amount) in amounts {
+            if let Some(amount) = amount.filter(|a| *a < 0.0) {
+                found.push((field, ValidationRule::NegativeAmount, format!("{} is negative: {}", field, amount)));
+            }
+        }
+
+        if policy.initial_pols <= 0.0 {
+            found.push((
+                "InitialPols",
+                ValidationRule::NonPositiveLives,
+                format!("InitialPols must be positive: {}", policy.initial_pols),
+            ));
+        }
+
+        if !(self.min_issue_age..=self.max_issue_age).contains(&policy.issue_age) {
+            found.push((
+                "IssueAge",
+                ValidationRule::IssueAgeRange,
+                format!(
+                    "IssueAge {} outside {}-{}",
+                    policy.issue_age, self.min_issue_age, self.max_issue_age
+                ),
+            ));
+        }
+
+        if policy.initial_pols > 0.0 {
+            let premium_per_life = policy.initial_premium / policy.initial_pols;
+            if premium_per_life < self.min_premium || premium_per_life > self.max_premium {
+                found.push((
+                    "InitialPremium",
+                    ValidationRule::PremiumRange,
+                    format!(
+                        "Premium per life {:.2} outside {:.0}-{:.0}",
+                        premium_per_life, self.min_premium, self.max_premium
+                    ),
+                ));
+            }
+        }
+
+        let expected_bucket = expected_bucket(policy);
+        if policy.benefit_base_bucket != expected_bucket {
+            found.push((
+                "Benefit_Base_Bucket",
+                ValidationRule::BucketMismatch,
+                format!(
+                    "Bucket {} but benefit base per life is in {}",
+                    policy.benefit_base_bucket.as_str(),
+                    expected_bucket.as_str()
+                ),
+            ));
+        }
+
+        if let Some(earliest) = self.earliest_start_year(policy) {
+            if policy.glwb_start_year < earliest {
+                found.push((
+                    "GLWBStartYear",
+                    ValidationRule::GlwbStartBeforeMinAge,
+                    format!(
+                        "GLWBStartYear {} is before age {} (year {})",
+                        policy.glwb_start_year, self.min_activation_age, earliest
+                    ),
+                ));
+            }
+        }
+
+        found
+    }
+
+    /// Correct the value a rule checks, if the rule has a fix
+    fn apply_fix(&self, policy: &mut Policy, rule: ValidationRule) -> bool {
+        match rule {
+            ValidationRule::BucketMismatch => {
+                policy.benefit_base_bucket = expected_bucket(policy);
+                true
+            }
+            ValidationRule::GlwbStartBeforeMinAge => match self.earliest_start_year(policy) {
+                Some(earliest) => {
+                    policy.glwb_start_year = earliest;
+                    policy.wait_period = policy.wait_period.max(earliest.saturating_sub(1));
+                    true
+                }
+                None => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// First policy year income may start (None if income is already on or never starts)
+    fn earliest_start_year(&self, policy: &Policy) -> Option<u32> {
+        if policy.income_activated || policy.glwb_start_year >= 99 {
+            return None;
+        }
+        Some(self.min_activation_age.saturating_sub(policy.issue_age) as u32 + 1)
+    }
+}
+
+fn expected_bucket(policy: &Policy) -> BenefitBaseBucket {
+    let bb_per_life = if policy.initial_pols > 0.0 {
+        policy.initial_benefit_base / policy.initial_pols
+    } else {
+        policy.initial_benefit_base
+    };
+    BenefitBaseBucket::from_amount(bb_per_life)
+}
+
+/// Write the exception report as CSV
+pub fn write_exception_csv<W: Write>(exceptions: &[ValidationException], writer: W) -> Result<(), ActuarialError> {
+    let mut csv_writer = csv::Writer::from_writer(writer);
+    csv_writer.write_record(["Row", "PolicyID", "Field", "Rule", "Severity", "Fixed", "Message"])?;
+
+    for e in exceptions {
+        csv_writer.write_record([
+            e.row.to_string(),
+            e.policy_id.map_or_else(String::new, |id| id.to_string()),
+            e.field.clone(),
+            format!("{:?}", e.rule),
+            format!("{:?}", e.severity),
+            e.fixed.to_string(),
+            e.message.clone(),
+        ])?;
+    }
+
+    csv_writer.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::{load_policies_validated_from_reader, CreditingStrategy, Gender, QualStatus, RollupType};
+
+    fn policy(policy_id: u32, issue_age: u8, premium: f64) -> Policy {
+        Policy::with_glwb_start(
+            policy_id,
+            QualStatus::Q,
+            issue_age,
+            Gender::Male,
+            premium * 1.3,
+            1.0,
+            premium,
+            CreditingStrategy::Indexed,
+            10,
+            0.0475,
+            0.01,
+            0.0,
+            RollupType::Simple,
+            5,
+        )
+    }
+
+    fn bad_block() -> Vec<Policy> {
+        let mut wrong_bucket = policy(3, 65, 100_000.0);
+        wrong_bucket.benefit_base_bucket = BenefitBaseBucket::Over500k;
+        vec![
+            policy(1, 65, 100_000.0),
+            policy(2, 90, 100_000.0),  // issue age over 80
+            wrong_bucket,
+            policy(4, 40, 100_000.0),  // income in year 5 at age 44
+            policy(5, 65, 10_000.0),   // small premium: warning only
+            policy(6, 65, -100.0),
+        ]
+    }
+
+    #[test]
+    fn test_reject_and_reconcile() {
+        let validator = InforceValidator::new(&ProductFeatures::default(), ValidationOptions::default());
+        let result = validator.validate(bad_block());
+
+        assert_eq!(result.policies.iter().map(|p| p.policy_id).collect::<Vec<_>>(), vec![1, 5]);
+        assert_eq!(result.summary.rejected.rows, 4);
+        assert!(result.summary.is_reconciled());
+        assert!((result.summary.loaded.premium - 110_000.0).abs() < 1e-9);
+
+        let rules: Vec<_> = result.exceptions.iter().map(|e| (e.row, e.rule)).collect();
+        assert!(rules.contains(&(2, ValidationRule::IssueAgeRange)));
+        assert!(rules.contains(&(3, ValidationRule::BucketMismatch)));
+        assert!(rules.contains(&(4, ValidationRule::GlwbStartBeforeMinAge)));
+        assert!(rules.contains(&(5, ValidationRule::PremiumRange)));
+        assert!(rules.contains(&(6, ValidationRule::NegativeAmount)));
+        assert_eq!(result.exceptions_at(Severity::Warning).count(), result.summary.warnings);
+
+        let mut report = Vec::new();
+        write_exception_csv(&result.exceptions, &mut report).unwrap();
+        assert_eq!(String::from_utf8(report).unwrap().lines().count(), result.exceptions.len() + 1);
+    }
+
+    #[test]
+    fn test_fix_and_quarantine() {
+        let fix = InforceValidator::new(
+            &ProductFeatures::default(),
+            ValidationOptions::default().with_action(ExceptionAction::Fix),
+        );
+        let fixed = fix.validate(bad_block());
+        assert_eq!(fixed.policies.iter().map(|p| p.policy_id).collect::<Vec<_>>(), vec![1, 3, 4, 5]);
+        assert_eq!(fixed.policies[1].benefit_base_bucket, BenefitBaseBucket::From100kTo200k);
+        assert_eq!(fixed.policies[2].glwb_start_year, 11);
+        assert_eq!(fixed.summary.fixed_rows, 2);
+        assert!(fixed.summary.is_reconciled());
+
+        let quarantine = InforceValidator::new(
+            &ProductFeatures::default(),
+            ValidationOptions::default()
+                .with_action(ExceptionAction::Quarantine)
+                .with_severity(ValidationRule::PremiumRange, Severity::Error),
+        );
+        let held = quarantine.validate(bad_block());
+        assert_eq!(held.policies.len(), 1);
+        assert_eq!(held.quarantined.len(), 5);
+        assert_eq!(held.summary.rejected.rows, 0);
+        assert!(held.summary.is_reconciled());
+    }
+
+    #[test]
+    fn test_unreadable_rows_do_not_stop_loading() {
+        let mut lines: Vec<String> = std::fs::read_to_string("pricing_inforce.csv")
+            .unwrap()
+            .lines()
+            .take(4)
+            .map(String::from)
+            .collect();
+        lines[2] = lines[2].replacen("Male", "Unknown", 1).replacen("Female", "Unknown", 1);
+
+        let validator = InforceValidator::new(&ProductFeatures::default(), ValidationOptions::default());
+        let result = load_policies_validated_from_reader(lines.join("\n").as_bytes(), &validator).unwrap();
+        assert_eq!(result.summary.unreadable_rows, 1);
+        assert_eq!(result.summary.read.rows, 2);
+        assert_eq!(result.exceptions.iter().find(|e| e.rule == ValidationRule::Unreadable).unwrap().row, 2);
+    }
+}
@@ -22,6 +22,7 @@ pub struct CashflowRow {
     pub lapse_skew: f64,
     pub base_lapse_component: f64,
     pub dynamic_lapse_component: f64,
+    pub interest_lapse_component: f64,
     pub final_lapse_rate: f64,
 
     // Account values and benefit base
@@ -59,6 +60,10 @@ pub struct CashflowRow {
     pub interest_credits_cf: f64,
     pub eop_av: f64,
 
+    // Guaranteed values (EOP)
+    pub guaranteed_av: f64,              // Nonforfeiture AV accumulating at MGIR
+    pub cash_surrender_value: f64,       // AV net of SC, floored at guaranteed AV
+
     // Expenses
     pub expenses: f64,
 
@@ -71,6 +76,12 @@ pub struct CashflowRow {
     pub bonus_comp: f64,                 // Month 13 bonus on BOP AV
     pub chargebacks: f64,                // Clawback of commissions for early terminations
 
+    // Reserves (when ProjectionConfig::reserve_config is set); None for months
+    // outside the calculated reserve points, written as blanks in CSV output
+    pub bop_reserve: Option<f64>,
+    pub eop_reserve: Option<f64>,
+    pub change_in_reserve: Option<f64>,
+
     // Summary
     pub total_net_cashflow: f64,
     pub net_index_credit_reimbursement: f64,
@@ -95,6 +106,7 @@ impl CashflowRow {
             lapse_skew: 0.0,
             base_lapse_component: 0.0,
             dynamic_lapse_component: 0.0,
+            interest_lapse_component: 0.0,
             final_lapse_rate: 0.0,
             premium: 0.0,
             bop_av: 0.0,
@@ -121,6 +133,8 @@ impl CashflowRow {
             surrender_charges_cf: 0.0,
             interest_credits_cf: 0.0,
             eop_av: 0.0,
+            guaranteed_av: 0.0,
+            cash_surrender_value: 0.0,
             expenses: 0.0,
             agent_commission: 0.0,
             imo_override: 0.0,
@@ -129,6 +143,9 @@ impl CashflowRow {
             wholesaler_conversion_owed: 0.0,
             bonus_comp: 0.0,
             chargebacks: 0.0,
+            bop_reserve: None,
+            eop_reserve: None,
+            change_in_reserve: None,
             total_net_cashflow: 0.0,
             net_index_credit_reimbursement: 0.0,
             hedge_gains: 0.0,
@@ -155,6 +172,10 @@ pub struct ProjectionResult {
     /// None when reserves are not calculated (default, fast path)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reserve_result: Option<crate::reserves::ReserveResult>,
+
+    /// Ceded and net cashflows (if a reinsurance treaty was set)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reinsurance: Option<crate::reinsurance::ReinsuranceResult>,
 }
 
 impl ProjectionResult {
@@ -165,6 +186,7 @@ impl ProjectionResult {
             pv_liabilities: 0.0,
             pv_premiums: 0.0,
             reserve_result: None,
+            reinsurance: None,
         }
     }
 
@@ -4,6 +4,9 @@ use crate::assumptions::Assumptions;
 use crate::policy::{Policy, CreditingStrategy};
 use super::state::ProjectionState;
 use super::cashflows::{CashflowRow, ProjectionResult};
+use crate::reserves::{ReserveCalcConfig, InforceSnapshot, GuaranteedBenefitCalculator};
+use crate::reinsurance::ReinsuranceTreaty;
+use crate::error::ActuarialError;
 
 /// Hedge/derivative parameters for indexed products
 #[derive(Debug, Clone)]
@@ -40,7 +43,7 @@ pub struct ProjectionConfig {
     /// Whether to track detailed cashflows
     pub detailed_output: bool,
 
-    /// Treasury rate change assumption (for lapse model)
+    /// Treasury rate change assumption (decimal, for the interest-sensitive lapse term)
     pub treasury_change: f64,
 
     /// Override lapse with fixed annual rate (for testing)
@@ -54,7 +57,11 @@ pub struct ProjectionConfig {
     /// Reserve calculation configuration
     /// Set to None to skip reserve calculations (default, fast)
     /// Set to Some(config) to calculate reserves alongside projection
-    pub reserve_config: Option<crate::reserves::ReserveCalcConfig>,
+    pub reserve_config: Option<ReserveCalcConfig>,
+
+    /// Reinsurance treaty
+    /// Set to Some(treaty) to split the cashflows and reserves into gross, ceded and net
+    pub reinsurance: Option<ReinsuranceTreaty>,
 }
 
 /// Approach for crediting interest to account value
@@ -96,6 +103,22 @@ pub enum CreditingApproach {
         /// Annual rate for Indexed crediting strategy (e.g., 0.0378 for 3.78%)
         indexed_annual_rate: f64,
     },
+    /// Scenario path of annual index returns (stochastic valuation)
+    /// - Fixed policies: monthly compounding of fixed_annual_rate
+    /// - Indexed policies: year N's return, after participation, floor and cap,
+    ///   credited at month 1 of year N+1; the last return repeats past the path
+    IndexPath {
+        /// Floor on the annual index credit
+        floor: f64,
+        /// Cap on the annual index credit
+        cap: f64,
+        /// Participation rate
+        participation: f64,
+        /// Annual index returns by projection year
+        annual_returns: Vec<f64>,
+        /// Annual rate for Fixed crediting strategy
+        fixed_annual_rate: f64,
+    },
 }
 
 impl Default for ProjectionConfig {
@@ -111,7 +134,42 @@ impl Default for ProjectionConfig {
             fixed_lapse_rate: None,
             hedge_params: Some(HedgeParams::default()),
             reserve_config: None, // Reserves off by default (fast path)
+            reinsurance: None,
+        }
+    }
+}
+
+impl ProjectionConfig {
+    /// Check the settings are usable before projecting
+    pub fn validate(&self) -> Result<(), ActuarialError> {
+        let invalid = |message: String| Err(ActuarialError::Config(message));
+
+        if self.projection_months == 0 {
+            return invalid("Projection months must be positive".to_string());
+        }
+        if !self.treasury_change.is_finite() {
+            return invalid(format!("Treasury change must be finite, got {}", self.treasury_change));
+        }
+        if let Some(rate) = self.fixed_lapse_rate {
+            if !(0.0..=1.0).contains(&rate) {
+                return invalid(format!("Fixed lapse rate must be between 0 and 1, got {}", rate));
+            }
+        }
+        match &self.crediting {
+            CreditingApproach::ScenarioBased { floor, cap, .. } | CreditingApproach::IndexPath { floor, cap, .. }
+                if floor > cap =>
+            {
+                return invalid(format!("Index floor {} is above the cap {}", floor, cap));
+            }
+            CreditingApproach::IndexPath { annual_returns, .. } if annual_returns.is_empty() => {
+                return invalid("Index path has no annual returns".to_string());
+            }
+            _ => {}
         }
+        if let Some(treaty) = &self.reinsurance {
+            treaty.validate()?;
+        }
+        Ok(())
     }
 }
 
@@ -123,41 +181,152 @@ pub struct ProjectionEngine {
 
 impl ProjectionEngine {
     /// Create a new projection engine with given assumptions and config
-    pub fn new(assumptions: Assumptions, config: ProjectionConfig) -> Self {
+    /// The config's treasury change drives the interest-sensitive lapse term
+    pub fn new(mut assumptions: Assumptions, config: ProjectionConfig) -> Self {
+        assumptions.lapse.treasury_change = config.treasury_change;
         Self { assumptions, config }
     }
 
     /// Run projection for a single policy
-    pub fn project_policy(&self, policy: &Policy) -> ProjectionResult {
+    ///
+    /// Fails if the assumptions cannot value the policy (a joint contract
+    /// without joint payout factors).
+    pub fn project_policy(&self, policy: &Policy) -> Result<ProjectionResult, ActuarialError> {
         let mut result = ProjectionResult::new(policy.policy_id);
-        let mut state = ProjectionState::from_policy(policy);
+        let mut state = self.initial_state(policy);
 
-        for _month in 1..=self.config.projection_months {
-            // Advance state to next month
-            state.advance_month(policy);
-
-            // Lock in payout rate when income first activates
-            if state.income_activated && state.locked_payout_rate.is_none() {
-                state.locked_payout_rate = Some(
-                    self.assumptions.product.glwb.payout_factors.get_single_life(state.attained_age)
-                );
-            }
+        // In-force state at the end of each month (index 0 = projection start), kept for reserves
+        let mut inforce_states = Vec::new();
+        if self.config.reserve_config.is_some() {
+            inforce_states.push(state.clone());
+        }
 
+        for _month in 1..=self.config.projection_months {
             // Calculate and record cashflows
-            let row = self.calculate_month(policy, &mut state);
+            let row = self.step_month(policy, &mut state)?;
             result.add_row(row);
 
+            if self.config.reserve_config.is_some() {
+                inforce_states.push(state.clone());
+            }
+
             // Stop if no lives remaining
             if state.lives <= 1e-10 {
                 break;
             }
         }
 
-        result
+        if let Some(ref reserve_config) = self.config.reserve_config {
+            self.calculate_reserves(policy, reserve_config, &inforce_states, &mut result)?;
+        }
+
+        if let Some(ref treaty) = self.config.reinsurance {
+            if let Some(reserve) = result.reserve_result.as_mut() {
+                treaty.apply_to_reserve(reserve);
+            }
+            result.reinsurance = Some(treaty.cede(&result.cashflows));
+        }
+
+        Ok(result)
+    }
+
+    /// In-force state at the end of projection month `month` (0 = projection start)
+    ///
+    /// Stops early once no lives remain, so the state may be from an earlier month.
+    pub fn state_at_month(&self, policy: &Policy, month: u32) -> Result<ProjectionState, ActuarialError> {
+        let mut state = self.initial_state(policy);
+        while state.projection_month < month && state.lives > 1e-10 {
+            self.step_month(policy, &mut state)?;
+        }
+        Ok(state)
+    }
+
+    /// State at projection start, before any month is calculated
+    fn initial_state(&self, policy: &Policy) -> ProjectionState {
+        let mut state = ProjectionState::from_policy(policy);
+        state.guaranteed_av = self.assumptions.product.base.policy_nonforfeiture_value(policy, 0);
+
+        // Seasoned policies were paid commission at issue; chargebacks still recover it
+        if policy.is_seasoned() {
+            let (agent, imo_net, _, ws_net, _) = self.assumptions.product.commissions
+                .calculate_commissions(policy.initial_premium, policy.issue_age);
+            state.first_month_total_commission = agent + imo_net + ws_net;
+        }
+        state
+    }
+
+    /// Run the configured reserve calculator over the projected in-force
+    /// Reserves are calculated at the valuation month and, if configured, at the
+    /// start, every policy anniversary and the end of the projection; months in
+    /// between are interpolated for the row columns. Rows whose BOP or EOP month
+    /// falls outside the calculated points keep unpopulated (None) reserve columns
+    fn calculate_reserves(
+        &self,
+        policy: &Policy,
+        reserve_config: &ReserveCalcConfig,
+        inforce_states: &[ProjectionState],
+        result: &mut ProjectionResult,
+    ) -> Result<(), ActuarialError> {
+        let last_month = (inforce_states.len() as u32).saturating_sub(1);
+
+        let mut reserve_months: Vec<u32> = Vec::new();
+        if reserve_config.anniversary_reserves {
+            reserve_months.push(0);
+            reserve_months.extend((1..last_month).filter(|t| (policy.duration_months + t).is_multiple_of(12)));
+            reserve_months.push(last_month);
+        }
+        if reserve_config.valuation_month <= last_month {
+            reserve_months.push(reserve_config.valuation_month);
+        }
+        reserve_months.sort_unstable();
+        reserve_months.dedup();
+
+        let mut calculator = reserve_config.build_calculator(self.assumptions.clone());
+        let mut reserves: Vec<(u32, f64)> = Vec::with_capacity(reserve_months.len());
+
+        for &month in &reserve_months {
+            let inforce = InforceSnapshot::from_projection_state(&inforce_states[month as usize]);
+            let mut reserve = calculator.calculate_reserve_from_state(policy, month, &inforce)?;
+            reserves.push((month, reserve.gross_reserve));
+
+            if month == reserve_config.valuation_month {
+                if let Some(ref gb_config) = reserve_config.guaranteed_benefit {
+                    let gb_calculator = GuaranteedBenefitCalculator::new(self.assumptions.clone(), gb_config.clone());
+                    reserve.guaranteed_benefit = Some(gb_calculator.calculate(policy, month, &inforce)?);
+                }
+                result.reserve_result = Some(reserve);
+            }
+        }
+
+        // BOP reserve for month m is the reserve at the end of month m-1
+        for row in result.cashflows.iter_mut() {
+            let bop = interpolate_reserve(&reserves, row.projection_month - 1);
+            let eop = interpolate_reserve(&reserves, row.projection_month);
+            if let (Some(bop), Some(eop)) = (bop, eop) {
+                row.bop_reserve = Some(bop);
+                row.eop_reserve = Some(eop);
+                row.change_in_reserve = Some(eop - bop);
+            }
+        }
+        Ok(())
+    }
+
+    /// Advance the state one month and calculate its cashflows
+    fn step_month(&self, policy: &Policy, state: &mut ProjectionState) -> Result<CashflowRow, ActuarialError> {
+        state.advance_month(policy);
+
+        // Lock in payout rate when income first activates
+        if state.income_activated && state.locked_payout_rate.is_none() {
+            state.locked_payout_rate = Some(
+                self.assumptions.product.glwb.payout_factors.for_policy(policy, state.projection_month)?
+            );
+        }
+
+        self.calculate_month(policy, state)
     }
 
     /// Calculate cashflows for a single month
-    fn calculate_month(&self, policy: &Policy, state: &mut ProjectionState) -> CashflowRow {
+    fn calculate_month(&self, policy: &Policy, state: &mut ProjectionState) -> Result<CashflowRow, ActuarialError> {
         let mut row = CashflowRow::new(state.projection_month);
 
         // Set timing
@@ -171,13 +340,24 @@ impl ProjectionEngine {
         row.pre_decrement_av = state.pre_decrement_av();
         row.lives = state.lives;
 
-        // Premium (only in month 1 for single premium product)
-        if state.projection_month == 1 {
+        // Premium (only in the issue month for single premium product)
+        let policy_month = policy.policy_month(state.projection_month);
+        if policy_month == 1 {
             row.premium = policy.initial_premium;
         }
 
         // Calculate decrements
-        self.calculate_decrements(policy, state, &mut row);
+        self.calculate_decrements(policy, state, &mut row)?;
+
+        // Joint life: the first death has no cashflow, the contract decrements on the second
+        if let (Some(joint), Some(survival)) = (policy.joint_life, state.joint_survival.as_mut()) {
+            let q_second = self.assumptions.mortality.monthly_rate(
+                joint.attained_age(policy, state.projection_month),
+                joint.gender,
+                state.projection_month,
+            );
+            row.final_mortality = survival.advance(row.final_mortality, q_second);
+        }
 
         // Calculate persistency and apply decrements
         self.apply_decrements(state, &mut row);
@@ -186,7 +366,7 @@ impl ProjectionEngine {
         self.calculate_cashflows(policy, state, &mut row);
 
         // Store first month's total commission for chargeback calculations
-        if state.projection_month == 1 {
+        if policy_month == 1 {
             state.first_month_total_commission = row.agent_commission
                 + row.imo_override
                 + row.wholesaler_override;
@@ -197,6 +377,7 @@ impl ProjectionEngine {
 
         // Update state for next month
         state.eop_av = row.eop_av;
+        state.guaranteed_av = row.guaranteed_av;
         state.av_persistency = row.av_persistency;
         state.bb_persistency = row.bb_persistency;
         state.lives_persistency = row.lives_persistency;
@@ -212,11 +393,11 @@ impl ProjectionEngine {
         // Update benefit base with rollup
         self.update_benefit_base(policy, state, &row);
 
-        row
+        Ok(row)
     }
 
     /// Calculate all decrement rates for the month
-    fn calculate_decrements(&self, policy: &Policy, state: &ProjectionState, row: &mut CashflowRow) {
+    fn calculate_decrements(&self, policy: &Policy, state: &ProjectionState, row: &mut CashflowRow) -> Result<(), ActuarialError> {
         // Mortality
         let baseline_annual = self.assumptions.mortality.baseline_annual_rate(
             state.attained_age,
@@ -278,14 +459,15 @@ impl ProjectionEngine {
             policy.sc_period as u32,
         );
         row.dynamic_lapse_component = self.assumptions.lapse.dynamic_component(itm, state.income_activated);
+        row.interest_lapse_component = self.assumptions.lapse.interest_sensitive_component();
 
         // Final monthly lapse rate
         // No lapses when AV = 0 (nothing to surrender)
         row.final_lapse_rate = if state.bop_av <= 0.0 {
             0.0
         } else if let Some(annual_rate) = self.config.fixed_lapse_rate {
-            // Fixed lapse rate for testing: even 1/12 monthly skew, 0 in month 1
-            if state.projection_month == 1 {
+            // Fixed lapse rate for testing: even 1/12 monthly skew, 0 in the issue month
+            if policy.policy_month(state.projection_month) == 1 {
                 0.0
             } else {
                 1.0 - (1.0 - annual_rate).powf(1.0 / 12.0)
@@ -293,7 +475,7 @@ impl ProjectionEngine {
         } else {
             // Normal predictive model with shock year skew
             self.assumptions.lapse.monthly_lapse_rate_with_skew(
-                state.projection_month,
+                policy.policy_month(state.projection_month),
                 state.policy_year,
                 state.month_in_policy_year,
                 state.income_activated,
@@ -303,9 +485,10 @@ impl ProjectionEngine {
             )
         };
 
-        // Rider charge rate - annual, only applied when MOD(projection_month, 12) = 0
+        // Rider charge rate - annual, only applied at the policy anniversary
+        // (policy month a multiple of 12, so seasoned restarts keep their anniversaries)
         // Excel: =IF(K12=1,1.5%,0.5%)*IF(MOD(B12,12)=0,1,0)
-        row.rider_charge_rate = if state.projection_month % 12 == 0 {
+        row.rider_charge_rate = if policy.policy_month(state.projection_month) % 12 == 0 {
             if state.income_activated {
                 self.assumptions.product.glwb.post_activation_charge
             } else {
@@ -323,32 +506,28 @@ impl ProjectionEngine {
         // Simple monthly calculation: payout_rate / 12 * current_BB
         row.systematic_withdrawal = if state.income_activated {
             // Use locked payout rate (fixed at income activation) not current age-based rate
-            let payout_rate = state.locked_payout_rate.unwrap_or_else(|| {
-                self.assumptions.product.glwb.payout_factors.get_single_life(state.attained_age)
-            });
+            let payout_rate = match state.locked_payout_rate {
+                Some(rate) => rate,
+                None => self.assumptions.product.glwb.payout_factors.for_policy(policy, state.projection_month)?,
+            };
             state.bop_benefit_base * payout_rate / 12.0
         } else {
             0.0
         };
 
         // Rollup rate (for display - actual rollup applied in update_benefit_base)
-        // Continuous monthly rollup: (1+bonus+rollup/12*PM)/(1+bonus+rollup/12*(PM-1))-1
-        // Using rollup/12 gives 10% per year (same as annual), just applied monthly
-        row.rollup_rate = if state.projection_month <= (policy.sc_period as u32) * 12 && !state.income_activated {
-            let bb_bonus = self.assumptions.product.glwb.bonus_rate;
-            let rollup_rate = self.assumptions.product.glwb.rollup_rate;
-            let monthly_rollup = rollup_rate / 12.0;
-            let pm = state.projection_month as f64;
-            let pm_prev = (state.projection_month - 1) as f64;
-            (1.0 + bb_bonus + monthly_rollup * pm) / (1.0 + bb_bonus + monthly_rollup * pm_prev) - 1.0
-        } else {
+        // Policy-driven: simple or compound at the policy's rollup rate and bonus
+        row.rollup_rate = if state.income_activated {
             0.0
+        } else {
+            policy.monthly_rollup_factor(state.projection_month) - 1.0
         };
+        Ok(())
     }
 
     /// Calculate credited rate based on configuration
     fn calculate_credited_rate(&self, policy: &Policy, state: &ProjectionState) -> f64 {
-        match &self.config.crediting {
+        let rate = match &self.config.crediting {
             CreditingApproach::OptionBudget { budget_rate, equity_kicker } => {
                 (*budget_rate + *equity_kicker) / 12.0
             }
@@ -395,6 +574,49 @@ impl ProjectionEngine {
                     }
                 }
             }
+            CreditingApproach::IndexPath { floor, cap, participation, annual_returns, fixed_annual_rate } => {
+                match policy.crediting_strategy {
+                    CreditingStrategy::Fixed => (1.0 + fixed_annual_rate).powf(1.0 / 12.0) - 1.0,
+                    CreditingStrategy::Indexed => {
+                        if state.month_in_policy_year == 1 && state.projection_month > 1 {
+                            // Anniversary in projection month m credits the year ending at month m-1
+                            let year_index = ((state.projection_month - 2) / 12) as usize;
+                            let index_return = annual_returns
+                                .get(year_index)
+                                .or(annual_returns.last())
+                                .copied()
+                                .unwrap_or(0.0);
+                            (index_return * participation).max(*floor).min(*cap)
+                        } else {
+                            0.0
+                        }
+                    }
+                }
+            }
+        };
+
+        self.apply_mgir_floor(policy, state, rate)
+    }
+
+    /// Floor the credited rate at the policy MGIR where the product guarantees it
+    /// Annual (anniversary) crediting is floored at MGIR on the anniversary;
+    /// monthly crediting at the monthly equivalent (1+MGIR)^(1/12)-1
+    fn apply_mgir_floor(&self, policy: &Policy, state: &ProjectionState, rate: f64) -> f64 {
+        if !self.assumptions.product.base.mgir_floor_on_fixed
+            || policy.crediting_strategy != CreditingStrategy::Fixed
+        {
+            return rate;
+        }
+
+        match &self.config.crediting {
+            CreditingApproach::IndexedAnnual { .. } => {
+                if state.month_in_policy_year == 1 && state.policy_year > 1 {
+                    rate.max(policy.mgir)
+                } else {
+                    rate
+                }
+            }
+            _ => rate.max((1.0 + policy.mgir).powf(1.0 / 12.0) - 1.0),
         }
     }
 
@@ -448,6 +670,25 @@ impl ProjectionEngine {
         // Total decrement pool = Pre_dec_AV * (1 - AV_persistency)
         let decrement_pool = pre_dec_av * (1.0 - av_persistency);
 
+        // Guaranteed (nonforfeiture) AV accumulates at MGIR and is reduced by withdrawals,
+        // rider charges and decrements in the same way as the AV
+        let monthly_mgir = (1.0 + policy.mgir).powf(1.0 / 12.0) - 1.0;
+        let pre_dec_gav = (state.guaranteed_av - systematic_wd).max(0.0) * (1.0 + monthly_mgir);
+        let gav_rider_rate = if state.guaranteed_av > 0.0 {
+            row.rider_charge_rate * state.bop_benefit_base / state.guaranteed_av
+        } else {
+            0.0
+        };
+        let gav_persistency = ((1.0 - row.final_mortality)
+            * (1.0 - row.final_lapse_rate)
+            * (1.0 - row.non_systematic_pwd_rate)
+            * (1.0 - gav_rider_rate))
+            .max(0.0);
+        row.guaranteed_av = pre_dec_gav * gav_persistency;
+
+        // Guaranteed value per dollar of AV (surrender value floor)
+        let gav_ratio = if pre_dec_av > 0.0 { pre_dec_gav / pre_dec_av } else { 0.0 };
+
         // Sum of all rates for proportional allocation
         let sum_of_rates = row.final_mortality
             + row.final_lapse_rate
@@ -467,10 +708,18 @@ impl ProjectionEngine {
 
             // Lapse (net of SC) = Pool * S / sum * (FPW% + (1-FPW%)*(1-SC))
             let net_of_sc_factor = fpw_pct + (1.0 - fpw_pct) * (1.0 - row.surrender_charge);
-            let lapse = allocation_base * row.final_lapse_rate * net_of_sc_factor;
-
-            // Surrender charges = Pool * S / sum * (1-FPW%) * SC
-            let surr_chg = allocation_base * row.final_lapse_rate * (1.0 - fpw_pct) * row.surrender_charge;
+            let (lapse, surr_chg) = if gav_ratio > net_of_sc_factor {
+                // Nonforfeiture floor binds: surrender pays the guaranteed value and the
+                // surrender charge is what remains (negative when guarantee exceeds AV)
+                let gross_lapse = allocation_base * row.final_lapse_rate;
+                (gross_lapse * gav_ratio, gross_lapse * (1.0 - gav_ratio))
+            } else {
+                (
+                    allocation_base * row.final_lapse_rate * net_of_sc_factor,
+                    // Surrender charges = Pool * S / sum * (1-FPW%) * SC
+                    allocation_base * row.final_lapse_rate * (1.0 - fpw_pct) * row.surrender_charge,
+                )
+            };
 
             // PWD = Pool * L / sum + Systematic_WD
             let pwd = allocation_base * row.non_systematic_pwd_rate + systematic_wd;
@@ -507,12 +756,17 @@ impl ProjectionEngine {
         // Note: For single-policy projection, we track per-policy EOP AV
         row.eop_av = (bop_av + interest_credits - (mort_dec + lapse_dec + pwd_dec + rider_dec + surr_chg_dec)).max(0.0);
 
+        // Cash surrender value: AV net of SC (on amount over free), floored at guaranteed AV
+        let eop_net_of_sc = row.fpw_pct + (1.0 - row.fpw_pct) * (1.0 - row.surrender_charge);
+        row.cash_surrender_value = (row.eop_av * eop_net_of_sc).max(row.guaranteed_av);
+
         // Expenses: 0.25%/12 of EOP AV (per-policy basis)
         // Per COLUMN_MAPPING row AJ: =0.0025/12*AI11
         row.expenses = row.eop_av * self.assumptions.product.base.expense_rate_of_av / 12.0;
 
-        // Commissions (month 1 only)
-        if state.projection_month == 1 {
+        // Commissions (issue month only)
+        let policy_month = policy.policy_month(state.projection_month);
+        if policy_month == 1 {
             let comm = &self.assumptions.product.commissions;
             let (agent, imo_net, imo_conv, ws_net, ws_conv) =
                 comm.calculate_commissions(policy.initial_premium, policy.issue_age);
@@ -526,7 +780,7 @@ impl ProjectionEngine {
 
         // Bonus compensation at month 13
         // Per COLUMN_MAPPING row AM: =IF(B11=13,O11*bonus_rate,0)
-        if state.projection_month == 13 {
+        if policy_month == 13 {
             let comm = &self.assumptions.product.commissions;
             row.bonus_comp = state.bop_av * comm.bonus_rate(policy.issue_age);
         }
@@ -534,15 +788,15 @@ impl ProjectionEngine {
         // Chargebacks: recover commission from early terminations
         // Per COLUMN_MAPPING row AL: =AA11*(1-Z11)/$G$4*$AK$11*IF(C11>1,0,IF(B11>6,0.5,1))
         let comm = &self.assumptions.product.commissions;
-        let chargeback_factor = comm.chargeback_factor(state.projection_month, state.policy_year);
+        let chargeback_factor = comm.chargeback_factor(policy_month, state.policy_year);
 
         if chargeback_factor > 0.0 && state.initial_lives > 0.0 {
             // Lives lost this month (as proportion of initial)
             let lives_persistency_this_month = row.lives_persistency / state.lives_persistency;
             let lives_lost_rate = 1.0 - lives_persistency_this_month;
 
-            // For month 1, use the commission we just calculated; otherwise use stored value
-            let first_month_commission = if state.projection_month == 1 {
+            // For the issue month, use the commission we just calculated; otherwise use stored value
+            let first_month_commission = if policy_month == 1 {
                 row.agent_commission + row.imo_override + row.wholesaler_override
             } else {
                 state.first_month_total_commission
@@ -630,8 +884,8 @@ impl ProjectionEngine {
         let av_lost = state.bop_av * (1.0 - monthly_av_persistency);
         // Use lagged month_in_policy_year for appreciation (except month 1)
         // This represents how long the derivative was held before the decrement occurs
-        let lagged_month = if state.projection_month == 1 {
-            1 // No lag for first month
+        let lagged_month = if policy.policy_month(state.projection_month) == 1 {
+            1 // No lag in the issue month
         } else if state.month_in_policy_year == 1 {
             12 // At month 1 of new year, lag is month 12 of prior year
         } else {
@@ -646,8 +900,9 @@ impl ProjectionEngine {
     }
 
     /// Update benefit base for next month
-    /// Continuous monthly rollup: BB *= (1+bonus+rollup/12*PM)/(1+bonus+rollup/12*(PM-1))
-    /// Using rollup/12 gives 10% per year (same as annual formula), just applied monthly
+    /// Simple rollup:   BB *= (1+bonus+rollup/12*PM)/(1+bonus+rollup/12*(PM-1))
+    /// Compound rollup: BB *= (1+rollup)^(1/12)
+    /// Bonus, rate, duration and wait period all come from the policy
     fn update_benefit_base(&self, policy: &Policy, state: &mut ProjectionState, row: &CashflowRow) {
         // Calculate BB persistency for this month: (1-mort)*(1-lapse)*(1-pwd)
         let monthly_bb_persistency = (1.0 - row.final_mortality)
@@ -661,22 +916,28 @@ impl ProjectionEngine {
             // After income activation, BB is only reduced by persistency (mortality, lapse)
             // Systematic withdrawals come from AV, not BB
             // No rollup after income activation
-        } else if state.projection_month <= (policy.sc_period as u32) * 12 {
-            // Continuous monthly rollup during SC period when GLWB not activated
-            // Formula: (1+bonus+rollup/12*PM)/(1+bonus+rollup/12*(PM-1))
-            // This gives the same 10% per year as the annual formula, applied monthly
-            let bb_bonus = self.assumptions.product.glwb.bonus_rate;
-            let rollup_rate = self.assumptions.product.glwb.rollup_rate;
-            let monthly_rollup = rollup_rate / 12.0;
-            let pm = state.projection_month as f64;
-            let pm_prev = (state.projection_month - 1) as f64;
-            let rollup_factor = (1.0 + bb_bonus + monthly_rollup * pm)
-                              / (1.0 + bb_bonus + monthly_rollup * pm_prev);
-            state.bop_benefit_base = state.bop_benefit_base * rollup_factor;
+        } else {
+            // Continuous monthly rollup through min(RollupDuration, WaitPeriod)
+            // when GLWB not activated (factor is 1.0 once the rollup period ends)
+            state.bop_benefit_base = state.bop_benefit_base * policy.monthly_rollup_factor(state.projection_month);
         }
     }
 }
 
+/// Linearly interpolate the reserve at month `t` from calculated (month, reserve) points
+/// Returns None outside the calculated range
+fn interpolate_reserve(reserves: &[(u32, f64)], t: u32) -> Option<f64> {
+    match reserves.iter().position(|&(month, _)| month >= t) {
+        Some(i) if reserves[i].0 == t => Some(reserves[i].1),
+        Some(i) if i > 0 => {
+            let (m0, r0) = reserves[i - 1];
+            let (m1, r1) = reserves[i];
+            Some(r0 + (r1 - r0) * (t - m0) as f64 / (m1 - m0) as f64)
+        }
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -711,7 +972,7 @@ mod tests {
         let engine = ProjectionEngine::new(assumptions, config);
         let policy = test_policy();
 
-        let result = engine.project_policy(&policy);
+        let result = engine.project_policy(&policy).unwrap();
 
         assert_eq!(result.cashflows.len(), 12);
         assert!(result.cashflows[0].bop_av > 0.0);
@@ -729,7 +990,7 @@ mod tests {
         let engine = ProjectionEngine::new(assumptions, config);
         let policy = test_policy();
 
-        let result = engine.project_policy(&policy);
+        let result = engine.project_policy(&policy).unwrap();
         let row = &result.cashflows[0];
 
         // All decrement rates should be positive and less than 1
@@ -748,7 +1009,7 @@ mod tests {
         let engine = ProjectionEngine::new(assumptions, config);
         let policy = test_policy();
 
-        let result = engine.project_policy(&policy);
+        let result = engine.project_policy(&policy).unwrap();
 
         // With no crediting, AV should decrease over time due to charges
         let first_av = result.cashflows[0].bop_av;
@@ -756,4 +1017,401 @@ mod tests {
 
         assert!(last_av < first_av);
     }
+
+    #[test]
+    fn test_policy_driven_rollup() {
+        let assumptions = Assumptions::default_pricing();
+        let config = ProjectionConfig {
+            projection_months: 24,
+            ..Default::default()
+        };
+        let engine = ProjectionEngine::new(assumptions, config);
+
+        let simple = test_policy();
+        let mut compound = test_policy().with_rollup_terms(0.07, 10, 98);
+        compound.rollup_type = RollupType::Compound;
+
+        let simple_result = engine.project_policy(&simple).unwrap();
+        let compound_result = engine.project_policy(&compound).unwrap();
+
+        // Simple rollup uses the policy bonus: (1.3 + 0.1/12) / 1.3
+        let expected_simple = (1.3 + 0.10 / 12.0) / 1.3 - 1.0;
+        assert!((simple_result.cashflows[0].rollup_rate - expected_simple).abs() < 1e-12);
+
+        // Compound rollup uses the policy rate: 1.07^(1/12)
+        let expected_compound = 1.07_f64.powf(1.0 / 12.0) - 1.0;
+        assert!((compound_result.cashflows[0].rollup_rate - expected_compound).abs() < 1e-12);
+        assert!((compound_result.cashflows[23].rollup_rate - expected_compound).abs() < 1e-12);
+
+        // No rollup past the wait period
+        let mut waited = test_policy().with_rollup_terms(0.10, 10, 1);
+        waited.glwb_start_year = 99;
+        let waited_result = engine.project_policy(&waited).unwrap();
+        assert!(waited_result.cashflows[11].rollup_rate > 0.0);
+        assert_eq!(waited_result.cashflows[12].rollup_rate, 0.0);
+    }
+
+    #[test]
+    fn test_mgir_floor() {
+        let assumptions = Assumptions::default_pricing();
+        let config = ProjectionConfig {
+            projection_months: 120,
+            crediting: CreditingApproach::Fixed(0.0),
+            ..Default::default()
+        };
+        let engine = ProjectionEngine::new(assumptions, config);
+
+        // Fixed strategy: 0% crediting is floored at the 1% MGIR
+        let mut fixed = test_policy();
+        fixed.crediting_strategy = CreditingStrategy::Fixed;
+        let fixed_result = engine.project_policy(&fixed).unwrap();
+        let expected = 1.01_f64.powf(1.0 / 12.0) - 1.0;
+        assert!((fixed_result.cashflows[0].credited_rate - expected).abs() < 1e-12);
+
+        // Indexed strategy: no credited rate floor, but the CSV is floored at the
+        // guaranteed AV (87.5% of premium at MGIR) once charges erode the AV
+        let indexed_result = engine.project_policy(&test_policy()).unwrap();
+        assert_eq!(indexed_result.cashflows[0].credited_rate, 0.0);
+        for row in &indexed_result.cashflows {
+            assert!(row.guaranteed_av > 0.0);
+            assert!(row.cash_surrender_value >= row.guaranteed_av - 1e-9);
+        }
+        let last = indexed_result.cashflows.last().unwrap();
+        assert!(last.cash_surrender_value > last.eop_av * (1.0 - last.surrender_charge));
+    }
+
+    #[test]
+    fn test_treasury_change_lapse() {
+        let projection = |treasury_change: f64| {
+            let config = ProjectionConfig {
+                projection_months: 24,
+                treasury_change,
+                ..Default::default()
+            };
+            ProjectionEngine::new(Assumptions::default_pricing(), config).project_policy(&test_policy()).unwrap()
+        };
+
+        let base = projection(0.0);
+        let shocked = projection(0.01);
+
+        assert_eq!(base.cashflows[12].interest_lapse_component, 0.0);
+        assert!(shocked.cashflows[12].interest_lapse_component > 0.0);
+        assert!(shocked.cashflows[12].final_lapse_rate > base.cashflows[12].final_lapse_rate);
+    }
+
+    #[test]
+    fn test_reserves_in_projection() {
+        let config = ProjectionConfig {
+            projection_months: 36,
+            reserve_config: Some(ReserveCalcConfig::quick().with_anniversary_reserves()),
+            ..Default::default()
+        };
+        let engine = ProjectionEngine::new(Assumptions::default_pricing(), config);
+        let result = engine.project_policy(&test_policy()).unwrap();
+
+        // Valuation month 0 reserve is attached to the result
+        let reserve = result.reserve_result.as_ref().expect("reserve should be calculated");
+        assert_eq!(reserve.valuation_date, 0);
+        assert!(reserve.gross_reserve > 0.0);
+
+        let first = &result.cashflows[0];
+        assert!((first.bop_reserve.unwrap() - reserve.gross_reserve).abs() < 1e-9);
+
+        // Rows chain: BOP reserve = prior EOP reserve, change = EOP - BOP
+        for pair in result.cashflows.windows(2) {
+            assert!((pair[1].bop_reserve.unwrap() - pair[0].eop_reserve.unwrap()).abs() < 1e-9);
+        }
+        for row in &result.cashflows {
+            let (bop, eop) = (row.bop_reserve.unwrap(), row.eop_reserve.unwrap());
+            assert!(eop > 0.0);
+            assert!((row.change_in_reserve.unwrap() - (eop - bop)).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_reinsurance_in_projection() {
+        let config = ProjectionConfig {
+            projection_months: 36,
+            reserve_config: Some(ReserveCalcConfig::quick()),
+            reinsurance: Some(ReinsuranceTreaty::coinsurance(0.4).with_allowances(0.05, 0.0)),
+            ..Default::default()
+        };
+        let engine = ProjectionEngine::new(Assumptions::default_pricing(), config);
+        let result = engine.project_policy(&test_policy()).unwrap();
+
+        let reserve = result.reserve_result.as_ref().expect("reserve should be calculated");
+        assert!((reserve.net_reserve - 0.6 * reserve.gross_reserve).abs() < 1e-6);
+
+        let ceded = result.reinsurance.as_ref().expect("reinsurance should be applied");
+        assert_eq!(ceded.net.len(), result.cashflows.len());
+        for ((gross, net), settlement) in result.cashflows.iter().zip(&ceded.net).zip(&ceded.settlements) {
+            assert!((net.eop_av - 0.6 * gross.eop_av).abs() < 1e-6);
+            assert!((net.total_net_cashflow - gross.total_net_cashflow - settlement.net_settlement).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_guaranteed_benefit_in_projection() {
+        use crate::reserves::GuaranteedBenefitConfig;
+
+        let gb_config = GuaranteedBenefitConfig {
+            assumed_activation_year: Some(2),
+            ..Default::default()
+        };
+        let config = ProjectionConfig {
+            projection_months: 12,
+            reserve_config: Some(ReserveCalcConfig::quick().with_guaranteed_benefit(gb_config)),
+            ..Default::default()
+        };
+        let result = ProjectionEngine::new(Assumptions::default_pricing(), config).project_policy(&test_policy()).unwrap();
+
+        let reserve = result.reserve_result.as_ref().expect("reserve should be calculated");
+        let gb = reserve.guaranteed_benefit.as_ref().expect("guaranteed benefit should be calculated");
+        assert!(gb.reserve > 0.0);
+        assert!((reserve.total_reserve() - reserve.gross_reserve - gb.reserve).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_joint_life_projection() {
+        let mut assumptions = Assumptions::default_pricing();
+        let joint_factors: std::collections::HashMap<u8, f64> = (50..=100).map(|age| (age, 0.045)).collect();
+        assumptions.product.glwb.payout_factors =
+            assumptions.product.glwb.payout_factors.clone().with_joint_life(&joint_factors);
+
+        let single = Policy::with_glwb_start(
+            1,
+            QualStatus::Q,
+            70,
+            Gender::Male,
+            160_000.0,
+            1.0,
+            100_000.0,
+            CreditingStrategy::Indexed,
+            10,
+            0.0475,
+            0.01,
+            0.3,
+            RollupType::Simple,
+            2,
+        );
+        let joint = single.clone().with_joint_life(68, Gender::Female);
+
+        let config = ProjectionConfig {
+            projection_months: 36,
+            ..Default::default()
+        };
+        let engine = ProjectionEngine::new(assumptions, config);
+        let single_rows = engine.project_policy(&single).unwrap().cashflows;
+        let joint_rows = engine.project_policy(&joint).unwrap().cashflows;
+
+        // The contract only decrements for mortality on the second death
+        assert!(joint_rows.iter().zip(&single_rows).all(|(j, s)| j.final_mortality < s.final_mortality));
+        assert!(joint_rows[35].lives > single_rows[35].lives);
+
+        // Income is locked at the joint factor
+        let income = joint_rows.iter().find(|row| row.systematic_withdrawal > 0.0).expect("income should start");
+        assert!((income.systematic_withdrawal * 12.0 / income.bop_benefit_base - 0.045).abs() < 1e-12);
+        let single_income = single_rows.iter().find(|row| row.systematic_withdrawal > 0.0).unwrap();
+        assert!(single_income.systematic_withdrawal / single_income.bop_benefit_base
+            > income.systematic_withdrawal / income.bop_benefit_base);
+    }
+
+    #[test]
+    fn test_rider_charge_on_seasoned_anniversaries() {
+        let engine = ProjectionEngine::new(Assumptions::default_pricing(), ProjectionConfig {
+            projection_months: 48,
+            ..Default::default()
+        });
+        let policy = test_policy();
+        let full = engine.project_policy(&policy).unwrap().cashflows;
+
+        // Restart mid-year at policy month 30: charges land on policy months 36 and 48
+        let state = engine.state_at_month(&policy, 30).unwrap();
+        let seasoned = InforceSnapshot::from_projection_state(&state).seasoned_policy(&policy, 30);
+        let restarted = ProjectionEngine::new(Assumptions::default_pricing(), ProjectionConfig {
+            projection_months: 18,
+            ..Default::default()
+        })
+        .project_policy(&seasoned)
+        .unwrap()
+        .cashflows;
+
+        for (r, f) in restarted.iter().zip(&full[30..]) {
+            assert_eq!(r.rider_charge_rate, f.rider_charge_rate, "month {}", f.projection_month);
+            assert_eq!(r.rider_charge_rate > 0.0, matches!(r.projection_month, 6 | 18));
+        }
+    }
+
+    #[test]
+    fn test_seasoned_projection_continues() {
+        let policy = Policy::with_glwb_start(
+            1,
+            QualStatus::Q,
+            70,
+            Gender::Male,
+            160_000.0,
+            1.0,
+            100_000.0,
+            CreditingStrategy::Indexed,
+            10,
+            0.0475,
+            0.01,
+            0.3,
+            RollupType::Simple,
+            2,
+        );
+        let joint = policy.clone().with_joint_life(68, Gender::Female);
+
+        let mut assumptions = Assumptions::default_pricing();
+        let joint_factors: std::collections::HashMap<u8, f64> = (50..=100).map(|age| (age, 0.045)).collect();
+        assumptions.product.glwb.payout_factors =
+            assumptions.product.glwb.payout_factors.clone().with_joint_life(&joint_factors);
+
+        // Mortality improvement runs on calendar time, so the restart starts two years later
+        let mut later = assumptions.clone();
+        let table_base_year = later.mortality.table_base_year();
+        let projection_year = later.mortality.projection_year();
+        later.mortality.set_improvement_years(table_base_year, projection_year + 2);
+
+        for policy in [policy, joint] {
+            let engine = ProjectionEngine::new(assumptions.clone(), ProjectionConfig {
+                projection_months: 36,
+                ..Default::default()
+            });
+            let full = engine.project_policy(&policy).unwrap().cashflows;
+
+            // Restart from month 24: income active with a locked rate, withdrawals taken this year
+            let state = engine.state_at_month(&policy, 24).unwrap();
+            let inforce = InforceSnapshot::from_projection_state(&state);
+            let seasoned = inforce.seasoned_policy(&policy, 24);
+            assert_eq!(seasoned.duration_months, 24);
+            assert_eq!(seasoned.initial_pols, state.lives);
+            assert!(seasoned.locked_payout_rate.is_some() && seasoned.ytd_systematic_wd > 0.0);
+            if let (Some(joint), Some(survival)) = (seasoned.joint_life, state.joint_survival) {
+                assert!(survival.first < 1.0 && survival.second < 1.0);
+                assert_eq!((joint.first_alive, joint.second_alive), (survival.first, survival.second));
+            }
+
+            let restart = |seasoned: &Policy| {
+                ProjectionEngine::new(later.clone(), ProjectionConfig {
+                    projection_months: 12,
+                    ..Default::default()
+                })
+                .project_policy(seasoned).unwrap()
+                .cashflows
+            };
+            let restarted = restart(&seasoned);
+
+            // No premium or commission again, and month 1 of the restart can lapse
+            assert_eq!(restarted[0].premium, 0.0);
+            assert_eq!(restarted[0].agent_commission, 0.0);
+            assert!(restarted[0].final_lapse_rate > 0.0);
+
+            let close = |a: f64, b: f64| (a - b).abs() <= 1e-9 * b.abs().max(1.0);
+            for (r, f) in restarted.iter().zip(&full[24..]) {
+                assert_eq!(r.policy_year, f.policy_year);
+                assert!(close(r.bop_av, f.bop_av), "bop_av month {}", f.projection_month);
+                assert!(close(r.lives, f.lives), "lives month {}", f.projection_month);
+                assert!(close(r.final_mortality, f.final_mortality), "final_mortality month {}", f.projection_month);
+                assert!(close(r.final_lapse_rate, f.final_lapse_rate), "final_lapse_rate month {}", f.projection_month);
+                assert!(close(r.systematic_withdrawal, f.systematic_withdrawal), "systematic_withdrawal month {}", f.projection_month);
+                assert!(close(r.hedge_gains, f.hedge_gains), "hedge_gains month {}", f.projection_month);
+                assert!(close(r.total_net_cashflow, f.total_net_cashflow), "total_net_cashflow month {}", f.projection_month);
+            }
+
+            // Restated per surviving policy, the restart is the same contract scaled up
+            let scale = policy.initial_pols / state.lives;
+            let per_policy = restart(&inforce.with_lives(policy.initial_pols).seasoned_policy(&policy, 24));
+            for (p, r) in per_policy.iter().zip(&restarted) {
+                assert!(close(p.bop_av, r.bop_av * scale), "per-policy bop_av month {}", r.projection_month);
+                assert!(close(p.lives, r.lives * scale), "per-policy lives month {}", r.projection_month);
+                assert!(close(p.final_mortality, r.final_mortality), "per-policy final_mortality month {}", r.projection_month);
+            }
+        }
+    }
+
+    #[test]
+    fn test_interpolate_reserve() {
+        let reserves = [(0, 100.0), (12, 112.0)];
+        assert_eq!(interpolate_reserve(&reserves, 0), Some(100.0));
+        assert!((interpolate_reserve(&reserves, 6).unwrap() - 106.0).abs() < 1e-12);
+        assert_eq!(interpolate_reserve(&reserves, 12), Some(112.0));
+        assert_eq!(interpolate_reserve(&reserves, 13), None);
+
+        let reserves = [(12, 112.0), (24, 124.0)];
+        assert_eq!(interpolate_reserve(&reserves, 11), None);
+    }
+
+    #[test]
+    fn test_valuation_only_reserve_leaves_rows_unpopulated() {
+        // A single valuation-month reserve gives no range to interpolate over,
+        // so the rows must not show the whole reserve released in month 1
+        let config = ProjectionConfig {
+            projection_months: 36,
+            reserve_config: Some(ReserveCalcConfig::quick()),
+            ..Default::default()
+        };
+        let engine = ProjectionEngine::new(Assumptions::default_pricing(), config);
+        let result = engine.project_policy(&test_policy()).unwrap();
+
+        assert!(result.reserve_result.as_ref().is_some_and(|r| r.gross_reserve > 0.0));
+        for row in &result.cashflows {
+            assert_eq!((row.bop_reserve, row.eop_reserve, row.change_in_reserve), (None, None, None));
+        }
+
+        // Unpopulated columns are blank in CSV output, not zero
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        writer.serialize(&result.cashflows[0]).unwrap();
+        let output = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+        let mut lines = output.lines();
+        let header: Vec<&str> = lines.next().unwrap().split(',').collect();
+        let values: Vec<&str> = lines.next().unwrap().split(',').collect();
+        for column in ["bop_reserve", "eop_reserve", "change_in_reserve"] {
+            let i = header.iter().position(|&h| h == column).unwrap();
+            assert_eq!(values[i], "");
+        }
+    }
+
+    #[test]
+    fn test_anniversary_reserves_cover_seasoned_projection() {
+        // Mid-year policy: the first anniversary is not month 0, yet month 1
+        // still needs a BOP reserve
+        let mut policy = test_policy();
+        policy.duration_months = 5;
+        let config = ProjectionConfig {
+            projection_months: 36,
+            reserve_config: Some(ReserveCalcConfig::quick().with_anniversary_reserves()),
+            ..Default::default()
+        };
+        let result = ProjectionEngine::new(Assumptions::default_pricing(), config).project_policy(&policy).unwrap();
+
+        let reserve = result.reserve_result.as_ref().expect("reserve should be calculated");
+        assert!((result.cashflows[0].bop_reserve.unwrap() - reserve.gross_reserve).abs() < 1e-9);
+        for row in &result.cashflows {
+            assert!(row.bop_reserve.is_some_and(|r| r > 0.0) && row.eop_reserve.is_some_and(|r| r > 0.0));
+        }
+    }
+
+    #[test]
+    fn test_index_path_crediting() {
+        let config = ProjectionConfig {
+            projection_months: 37,
+            crediting: CreditingApproach::IndexPath {
+                floor: 0.0,
+                cap: 0.08,
+                participation: 1.0,
+                annual_returns: vec![0.05, -0.10, 0.20],
+                fixed_annual_rate: 0.0275,
+            },
+            ..Default::default()
+        };
+        let engine = ProjectionEngine::new(Assumptions::default_pricing(), config);
+        let result = engine.project_policy(&test_policy()).unwrap();
+
+        // Year 1 return credited at month 13, floored year 2 at month 25, capped year 3 at month 37
+        assert!((result.cashflows[12].credited_rate - 0.05).abs() < 1e-12);
+        assert_eq!(result.cashflows[24].credited_rate, 0.0);
+        assert!((result.cashflows[36].credited_rate - 0.08).abs() < 1e-12);
+        assert_eq!(result.cashflows[5].credited_rate, 0.0);
+    }
 }
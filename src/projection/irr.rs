@@ -2,6 +2,8 @@
 //!
 //! Used to calculate Cost of Funds from projection cashflows
 
+use crate::error::ActuarialError;
+
 /// Calculate the Internal Rate of Return (IRR) for a series of cash flows
 /// using the Newton-Raphson method.
 ///
@@ -128,6 +130,22 @@ pub fn calculate_cost_of_funds(net_cashflows: &[f64]) -> Option<f64> {
     calculate_irr(net_cashflows, 12) // Monthly cashflows
 }
 
+/// Cost of Funds, with an error saying why no IRR was found
+pub fn solve_cost_of_funds(net_cashflows: &[f64]) -> Result<f64, ActuarialError> {
+    calculate_cost_of_funds(net_cashflows).ok_or_else(|| {
+        let has_positive = net_cashflows.iter().any(|&cf| cf > 1e-10);
+        let has_negative = net_cashflows.iter().any(|&cf| cf < -1e-10);
+        let message = if net_cashflows.is_empty() {
+            "no cashflows".to_string()
+        } else if !has_positive || !has_negative {
+            "net cashflows never change sign".to_string()
+        } else {
+            format!("no root between -99% and 1000% per month over {} months", net_cashflows.len())
+        };
+        ActuarialError::SolverNonConvergence { solver: "Cost of funds IRR", message }
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -152,4 +170,11 @@ mod tests {
         let irr = calculate_irr(&cashflows, 12);
         assert!(irr.is_some());
     }
+
+    #[test]
+    fn test_no_sign_change() {
+        let err = solve_cost_of_funds(&[100.0, 50.0, 25.0]).unwrap_err();
+        assert!(matches!(err, ActuarialError::SolverNonConvergence { .. }));
+        assert!(err.to_string().contains("never change sign"));
+    }
 }
@@ -8,7 +8,7 @@ mod irr;
 pub use state::ProjectionState;
 pub use engine::{ProjectionEngine, ProjectionConfig, CreditingApproach, HedgeParams};
 pub use cashflows::{CashflowRow, ProjectionResult};
-pub use irr::{calculate_irr, calculate_cost_of_funds};
+pub use irr::{calculate_irr, calculate_cost_of_funds, solve_cost_of_funds};
 
 // ============================================================================
 // Default Crediting Rates
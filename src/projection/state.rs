@@ -1,5 +1,6 @@
 //! Projection state tracking for a single policy
 
+use crate::assumptions::JointSurvival;
 use crate::policy::Policy;
 
 /// State of a policy at a point in time during projection
@@ -64,16 +65,25 @@ pub struct ProjectionState {
 
     /// Initial number of lives at projection start (for chargeback normalization)
     pub initial_lives: f64,
+
+    /// Guaranteed (nonforfeiture) account value accumulating at MGIR
+    pub guaranteed_av: f64,
+
+    /// Survival of each life on a joint contract (None = single life)
+    pub joint_survival: Option<JointSurvival>,
 }
 
 impl ProjectionState {
     /// Initialize state from a policy at projection start
+    ///
+    /// Seasoned policies restore their valuation-date state: locked payout
+    /// rate, prior month's AV/BB for the lagged ITM, and YTD withdrawals.
     pub fn from_policy(policy: &Policy) -> Self {
         Self {
             projection_month: 0,
-            policy_year: 1,
+            policy_year: policy.policy_year(0),
             month_in_policy_year: 0,
-            attained_age: policy.issue_age,
+            attained_age: policy.attained_age(0),
             bop_av: policy.starting_av(),
             bop_benefit_base: policy.starting_benefit_base(),
             eop_av: policy.starting_av(),
@@ -82,16 +92,18 @@ impl ProjectionState {
             bb_persistency: 1.0,
             lives_persistency: 1.0,
             income_activated: policy.income_activated,
-            locked_payout_rate: None, // Set when income activates
-            ytd_systematic_wd: 0.0,
-            ytd_non_systematic_wd: 0.0,
+            locked_payout_rate: policy.locked_payout_rate, // Otherwise set when income activates
+            ytd_systematic_wd: policy.ytd_systematic_wd,
+            ytd_non_systematic_wd: policy.ytd_non_systematic_wd,
             initial_benefit_base: policy.starting_benefit_base(),
-            // Prior BOP values for lagged ITM calc (initial values for first month)
-            prior_bop_av: policy.starting_av(),
-            prior_bop_bb: policy.starting_benefit_base(),
+            // Prior BOP values for lagged ITM calc (starting values for a new issue)
+            prior_bop_av: policy.prior_av.unwrap_or_else(|| policy.starting_av()),
+            prior_bop_bb: policy.prior_benefit_base.unwrap_or_else(|| policy.starting_benefit_base()),
             // Commission tracking for chargebacks
             first_month_total_commission: 0.0, // Set in engine after calculating month 1
             initial_lives: policy.initial_pols,
+            guaranteed_av: 0.0, // Set in engine from the product nonforfeiture terms
+            joint_survival: policy.joint_life.as_ref().map(JointSurvival::from),
         }
     }
 
@@ -0,0 +1,471 @@
+//! Splitting projected cashflows and reserves into gross, ceded and net
+//!
+//! Conventions (all amounts on the projection's `_dec` basis):
+//! - The ceded row is the reinsurer's share of the policy cashflows: a quota
+//!   share of every amount for coinsurance and modco (expenses and commissions
+//!   are retained), or the share of death claims in excess of the reserve for YRT
+//! - Treaty terms (premiums, allowances, modco adjustment, interest on funds
+//!   withheld, experience refunds) are settled separately; a positive
+//!   `net_settlement` is paid to the cedant
+//! - The net row is gross less ceded, except that its total cashflow is the
+//!   gross total plus the settlement, and its reserves are the cedant's reserve
+//!   after reserve credit
+//! - The modco reserve / funds withheld balance is the ceded reserve, or the
+//!   ceded account value when the projection carries no reserves
+
+use serde::{Deserialize, Serialize};
+
+use crate::projection::CashflowRow;
+use crate::reserves::ReserveResult;
+
+use super::treaty::{ReinsuranceTreaty, TreatyType};
+
+/// Monthly settlement between the cedant and the reinsurer
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReinsuranceSettlement {
+    pub projection_month: u32,
+
+    /// Paid to the reinsurer: quota share of premium (plus the reserve
+    /// transferred on a seasoned block), or the YRT premium
+    pub ceded_premium: f64,
+
+    /// Benefits recovered from the reinsurer
+    pub ceded_benefits: f64,
+
+    /// Hedge gains on the ceded account value, passed to the reinsurer
+    pub ceded_hedge_gains: f64,
+
+    /// Ceding and renewal expense allowances received
+    pub allowances: f64,
+
+    /// Modco reserve adjustment / increase in funds withheld, kept by the cedant
+    pub reserve_adjustment: f64,
+
+    /// Interest credited to the reinsurer on the modco reserve / funds withheld
+    pub withheld_interest: f64,
+
+    /// Experience refund received (at policy anniversaries)
+    pub experience_refund: f64,
+
+    /// Net amount received by the cedant
+    pub net_settlement: f64,
+}
+
+impl ReinsuranceSettlement {
+    fn settle(&mut self) {
+        self.net_settlement = -self.ceded_premium
+            + self.ceded_benefits
+            - self.ceded_hedge_gains
+            + self.allowances
+            + self.reserve_adjustment
+            - self.withheld_interest
+            + self.experience_refund;
+    }
+}
+
+/// Reserve before and after reinsurance
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReserveSplit {
+    pub gross_reserve: f64,
+
+    /// Credit taken for reinsurance ceded (coinsurance only)
+    pub reserve_credit: f64,
+
+    /// Reserve held by the cedant
+    pub net_reserve: f64,
+
+    /// Modco reserve or funds withheld balance held for the reinsurer
+    pub withheld_balance: f64,
+}
+
+/// Gross, ceded and net cashflows under one treaty
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReinsuranceResult {
+    pub treaty_name: String,
+    pub treaty_type: TreatyType,
+
+    /// Reinsurer's share of the policy cashflows by month
+    pub ceded: Vec<CashflowRow>,
+
+    /// Cedant's cashflows after reinsurance by month
+    pub net: Vec<CashflowRow>,
+
+    /// Treaty settlements by month
+    pub settlements: Vec<ReinsuranceSettlement>,
+}
+
+impl ReinsuranceResult {
+    /// Net total cashflow by month
+    pub fn net_cashflows(&self) -> Vec<f64> {
+        self.net.iter().map(|r| r.total_net_cashflow).collect()
+    }
+
+    /// Sum of settlements received by the cedant
+    pub fn total_net_settlement(&self) -> f64 {
+        self.settlements.iter().map(|s| s.net_settlement).sum()
+    }
+}
+
+/// Running experience refund account
+#[derive(Debug, Default)]
+struct RefundAccount {
+    reinsurer_gain: f64,
+    risk_charge: f64,
+    carried_loss: f64,
+    reinsurer_reserve: f64,
+}
+
+impl ReinsuranceTreaty {
+    /// Split a gross reserve
+    pub fn split_reserve(&self, gross_reserve: f64) -> ReserveSplit {
+        let ceded = self.quota_share * gross_reserve;
+        let (reserve_credit, withheld_balance) = match self.treaty_type {
+            TreatyType::Coinsurance if self.funds_withheld => (ceded, ceded),
+            TreatyType::Coinsurance => (ceded, 0.0),
+            TreatyType::ModifiedCoinsurance => (0.0, ceded),
+            TreatyType::YearlyRenewableTerm => (0.0, 0.0),
+        };
+
+        ReserveSplit {
+            gross_reserve,
+            reserve_credit,
+            net_reserve: gross_reserve - reserve_credit,
+            withheld_balance,
+        }
+    }
+
+    /// Set a reserve result's net reserve after reserve credit
+    pub fn apply_to_reserve(&self, result: &mut ReserveResult) {
+        result.net_reserve = self.split_reserve(result.gross_reserve).net_reserve;
+    }
+
+    /// Split gross projected cashflows into ceded and net
+    pub fn cede(&self, gross: &[CashflowRow]) -> ReinsuranceResult {
+        let q = self.quota_share;
+        let proportional = self.treaty_type != TreatyType::YearlyRenewableTerm;
+        // Reserves where the projection calculated them, the AV otherwise
+        let basis = |r: &CashflowRow| match (r.bop_reserve, r.eop_reserve) {
+            (Some(bop), Some(eop)) => (bop, eop),
+            _ => (r.bop_av, r.eop_av),
+        };
+
+        // A block already in force when the treaty starts transfers the ceded
+        // reserve; with assets withheld the transfer is withheld too
+        let opening = match gross.first() {
+            Some(first) if proportional && first.premium == 0.0 => q * basis(first).0,
+            _ => 0.0,
+        };
+        let mut withheld = if self.withholds_assets() { opening } else { 0.0 };
+        let monthly_rate = self.monthly_withheld_rate();
+        let mut refund_account = RefundAccount::default();
+
+        let mut result = ReinsuranceResult {
+            treaty_name: self.name.clone(),
+            treaty_type: self.treaty_type,
+            ceded: Vec::with_capacity(gross.len()),
+            net: Vec::with_capacity(gross.len()),
+            settlements: Vec::with_capacity(gross.len()),
+        };
+
+        for (i, row) in gross.iter().enumerate() {
+            let (ceded, mut settlement) = if proportional {
+                self.cede_proportional(row)
+            } else {
+                self.cede_yrt(row)
+            };
+
+            if proportional {
+                let ceded_eop = q * basis(row).1;
+                if self.withholds_assets() {
+                    settlement.reserve_adjustment = ceded_eop - withheld;
+                    settlement.withheld_interest = monthly_rate * withheld;
+                    withheld = ceded_eop;
+                } else if i == 0 {
+                    settlement.ceded_premium += opening;
+                }
+            }
+            settlement.settle();
+
+            if let Some(terms) = &self.experience_refund {
+                // Reinsurer's gain: settlements received less the increase in
+                // the reserve it holds itself (coinsurance without funds withheld)
+                let held = if self.takes_reserve_credit() && !self.withholds_assets() {
+                    q * basis(row).1
+                } else {
+                    0.0
+                };
+                refund_account.reinsurer_gain +=
+                    -settlement.net_settlement - (held - refund_account.reinsurer_reserve);
+                refund_account.reinsurer_reserve = held;
+                refund_account.risk_charge += terms.risk_charge_rate / 12.0 * q * row.bop_av;
+
+                if row.month_in_policy_year == 12 || i + 1 == gross.len() {
+                    let balance = refund_account.carried_loss + refund_account.reinsurer_gain
+                        - refund_account.risk_charge;
+                    if balance > 0.0 {
+                        settlement.experience_refund = terms.refund_pct * balance;
+                        refund_account.carried_loss = 0.0;
+                    } else if terms.carry_forward_losses {
+                        refund_account.carried_loss = balance;
+                    }
+                    refund_account.reinsurer_gain = 0.0;
+                    refund_account.risk_charge = 0.0;
+                    settlement.settle();
+                }
+            }
+
+            let mut net = row.clone();
+            let ceded_amounts: Vec<f64> = amounts_mut(&mut ceded.clone()).into_iter().map(|x| *x).collect();
+            for (amount, ceded_amount) in amounts_mut(&mut net).into_iter().zip(ceded_amounts) {
+                *amount -= ceded_amount;
+            }
+            net.bop_reserve = row.bop_reserve.map(|r| self.split_reserve(r).net_reserve);
+            net.eop_reserve = row.eop_reserve.map(|r| self.split_reserve(r).net_reserve);
+            net.change_in_reserve = net.eop_reserve.zip(net.bop_reserve).map(|(eop, bop)| eop - bop);
+            net.total_net_cashflow = row.total_net_cashflow + settlement.net_settlement;
+
+            result.ceded.push(ceded);
+            result.net.push(net);
+            result.settlements.push(settlement);
+        }
+
+        result
+    }
+
+    /// Quota share of the policy cashflows (coinsurance and modco)
+    fn cede_proportional(&self, row: &CashflowRow) -> (CashflowRow, ReinsuranceSettlement) {
+        let q = self.quota_share;
+        let mut ceded = row.clone();
+        for amount in amounts_mut(&mut ceded) {
+            *amount *= q;
+        }
+        ceded.bop_reserve = row.bop_reserve.map(|r| q * r);
+        ceded.eop_reserve = row.eop_reserve.map(|r| q * r);
+        ceded.change_in_reserve = row.change_in_reserve.map(|r| q * r);
+
+        // Expenses and commissions stay with the cedant (covered by allowances)
+        ceded.expenses = 0.0;
+        ceded.agent_commission = 0.0;
+        ceded.imo_override = 0.0;
+        ceded.imo_conversion_owed = 0.0;
+        ceded.wholesaler_override = 0.0;
+        ceded.wholesaler_conversion_owed = 0.0;
+        ceded.bonus_comp = 0.0;
+        ceded.chargebacks = 0.0;
+
+        let benefits = ceded.mortality_dec + ceded.lapse_dec + ceded.pwd_dec;
+        ceded.total_net_cashflow = ceded.premium - benefits + ceded.hedge_gains;
+
+        let renewal_allowance = if row.policy_year > 1 {
+            self.renewal_allowance / 12.0 * ceded.bop_av
+        } else {
+            0.0
+        };
+
+        let settlement = ReinsuranceSettlement {
+            projection_month: row.projection_month,
+            ceded_premium: ceded.premium,
+            ceded_benefits: benefits,
+            ceded_hedge_gains: ceded.hedge_gains,
+            allowances: self.initial_allowance * ceded.premium + renewal_allowance,
+            ..Default::default()
+        };
+
+        (ceded, settlement)
+    }
+
+    /// Share of death claims in excess of the reserve (YRT)
+    ///
+    /// The death benefit is the pre-decrement AV, so the net amount at risk is
+    /// the AV less the BOP reserve (the whole AV where no reserve was calculated).
+    fn cede_yrt(&self, row: &CashflowRow) -> (CashflowRow, ReinsuranceSettlement) {
+        let q = self.quota_share;
+        let mut ceded = row.clone();
+        for amount in amounts_mut(&mut ceded) {
+            *amount = 0.0;
+        }
+        ceded.bop_reserve = None;
+        ceded.eop_reserve = None;
+        ceded.change_in_reserve = None;
+
+        let net_amount_at_risk = q * (row.pre_decrement_av - row.bop_reserve.unwrap_or(0.0)).max(0.0);
+        let claims = row.final_mortality * net_amount_at_risk;
+        ceded.mortality_dec = claims;
+        ceded.mortality_cf = if row.mortality_dec > 0.0 {
+            row.mortality_cf * claims / row.mortality_dec
+        } else {
+            0.0
+        };
+        ceded.total_net_cashflow = -claims;
+
+        let settlement = ReinsuranceSettlement {
+            projection_month: row.projection_month,
+            ceded_premium: self.yrt_mortality_multiple * row.final_mortality * net_amount_at_risk,
+            ceded_benefits: claims,
+            ..Default::default()
+        };
+
+        (ceded, settlement)
+    }
+}
+
+/// Dollar amounts on a cashflow row (rates, timing and flags excluded)
+fn amounts_mut(row: &mut CashflowRow) -> [&mut f64; 32] {
+    [
+        &mut row.premium,
+        &mut row.bop_av,
+        &mut row.bop_benefit_base,
+        &mut row.pre_decrement_av,
+        &mut row.systematic_withdrawal,
+        &mut row.lives,
+        &mut row.mortality_dec,
+        &mut row.lapse_dec,
+        &mut row.pwd_dec,
+        &mut row.rider_charges_dec,
+        &mut row.surrender_charges_dec,
+        &mut row.interest_credits_dec,
+        &mut row.mortality_cf,
+        &mut row.lapse_cf,
+        &mut row.pwd_cf,
+        &mut row.rider_charges_cf,
+        &mut row.surrender_charges_cf,
+        &mut row.interest_credits_cf,
+        &mut row.eop_av,
+        &mut row.guaranteed_av,
+        &mut row.cash_surrender_value,
+        &mut row.expenses,
+        &mut row.agent_commission,
+        &mut row.imo_override,
+        &mut row.imo_conversion_owed,
+        &mut row.wholesaler_override,
+        &mut row.wholesaler_conversion_owed,
+        &mut row.bonus_comp,
+        &mut row.chargebacks,
+        &mut row.total_net_cashflow,
+        &mut row.net_index_credit_reimbursement,
+        &mut row.hedge_gains,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reinsurance::ExperienceRefund;
+
+    fn gross_rows() -> Vec<CashflowRow> {
+        (1..=24)
+            .map(|m| {
+                let mut row = CashflowRow::new(m);
+                row.policy_year = (m - 1) / 12 + 1;
+                row.month_in_policy_year = (m - 1) % 12 + 1;
+                row.premium = if m == 1 { 100_000.0 } else { 0.0 };
+                row.bop_av = 100_000.0;
+                row.pre_decrement_av = 100_300.0;
+                row.eop_av = 100_000.0;
+                row.final_mortality = 0.001;
+                row.mortality_dec = 100.0;
+                row.mortality_cf = 100.0;
+                row.lapse_dec = 200.0;
+                row.expenses = 20.0;
+                row.agent_commission = if m == 1 { 7_000.0 } else { 0.0 };
+                row.bop_reserve = Some(95_000.0 + 100.0 * (m - 1) as f64);
+                row.eop_reserve = Some(95_000.0 + 100.0 * m as f64);
+                row.change_in_reserve = Some(100.0);
+                row.total_net_cashflow = row.premium - 300.0 - row.expenses - row.agent_commission;
+                row
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_coinsurance_split() {
+        let gross = gross_rows();
+        let treaty = ReinsuranceTreaty::coinsurance(0.5).with_allowances(0.08, 0.0);
+        let result = treaty.cede(&gross);
+
+        let (ceded, net, settlement) = (&result.ceded[0], &result.net[0], &result.settlements[0]);
+        assert!((ceded.premium - 50_000.0).abs() < 1e-9);
+        assert_eq!(ceded.agent_commission, 0.0);
+        assert!((net.agent_commission - 7_000.0).abs() < 1e-9);
+        assert!((net.mortality_dec + ceded.mortality_dec - gross[0].mortality_dec).abs() < 1e-9);
+
+        // Pay half the premium, recover half the benefits and an 8% allowance
+        assert!((settlement.net_settlement - (-50_000.0 + 150.0 + 4_000.0)).abs() < 1e-9);
+        assert!((net.total_net_cashflow - (gross[0].total_net_cashflow + settlement.net_settlement)).abs() < 1e-9);
+
+        // Reserve credit for the ceded half
+        assert!((net.eop_reserve.unwrap() - 0.5 * gross[0].eop_reserve.unwrap()).abs() < 1e-9);
+        assert_eq!(treaty.split_reserve(1_000.0).withheld_balance, 0.0);
+    }
+
+    #[test]
+    fn test_modco_reserve_adjustment() {
+        let gross = gross_rows();
+        let treaty = ReinsuranceTreaty::modco(0.5, 0.04);
+        let result = treaty.cede(&gross);
+
+        // New business: the whole month-1 reserve is withheld, no interest yet
+        let first = &result.settlements[0];
+        assert!((first.reserve_adjustment - 0.5 * gross[0].eop_reserve.unwrap()).abs() < 1e-9);
+        assert_eq!(first.withheld_interest, 0.0);
+
+        let second = &result.settlements[1];
+        let monthly = 1.04_f64.powf(1.0 / 12.0) - 1.0;
+        assert!((second.reserve_adjustment - 50.0).abs() < 1e-9);
+        assert!((second.withheld_interest - monthly * 0.5 * gross[0].eop_reserve.unwrap()).abs() < 1e-9);
+
+        // Cedant keeps the full reserve; funds withheld coinsurance settles the
+        // same cash but takes reserve credit
+        assert!((result.net[1].eop_reserve.unwrap() - gross[1].eop_reserve.unwrap()).abs() < 1e-9);
+        let fw = ReinsuranceTreaty::coinsurance(0.5).with_funds_withheld(0.04);
+        let fw_result = fw.cede(&gross);
+        assert!((fw_result.total_net_settlement() - result.total_net_settlement()).abs() < 1e-6);
+        assert!((fw_result.net[1].eop_reserve.unwrap() - 0.5 * gross[1].eop_reserve.unwrap()).abs() < 1e-9);
+        assert_eq!(fw.split_reserve(1_000.0), ReserveSplit {
+            gross_reserve: 1_000.0,
+            reserve_credit: 500.0,
+            net_reserve: 500.0,
+            withheld_balance: 500.0,
+        });
+    }
+
+    #[test]
+    fn test_yrt_net_amount_at_risk() {
+        let gross = gross_rows();
+        let treaty = ReinsuranceTreaty::yrt(0.9, 1.2);
+        let result = treaty.cede(&gross);
+
+        // NAR = 90% * (100,300 - 95,000); claims at 0.1% mortality, premium at 120% of it
+        let nar = 0.9 * 5_300.0;
+        let settlement = &result.settlements[0];
+        assert!((settlement.ceded_benefits - 0.001 * nar).abs() < 1e-9);
+        assert!((settlement.ceded_premium - 1.2 * 0.001 * nar).abs() < 1e-9);
+        assert_eq!(result.ceded[0].premium, 0.0);
+        assert!((result.net[0].premium - gross[0].premium).abs() < 1e-9);
+        assert!((result.net[0].eop_reserve.unwrap() - gross[0].eop_reserve.unwrap()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_experience_refund() {
+        let gross = gross_rows();
+        let refund = ExperienceRefund { refund_pct: 0.9, risk_charge_rate: 0.0, carry_forward_losses: true };
+        let treaty = ReinsuranceTreaty::yrt(1.0, 1.5).with_experience_refund(refund);
+        let result = treaty.cede(&gross);
+
+        // Reinsurer keeps a third of the YRT premium as profit; 90% comes back at the anniversary
+        let year_one: Vec<_> = result.settlements[..12].iter().collect();
+        let profit: f64 = year_one.iter().map(|s| s.ceded_premium - s.ceded_benefits).sum();
+        assert!(year_one[..11].iter().all(|s| s.experience_refund == 0.0));
+        assert!((year_one[11].experience_refund - 0.9 * profit).abs() < 1e-9);
+
+        // Losses are carried forward: no refund after a year of excess claims
+        let losing = ReinsuranceTreaty::yrt(1.0, 0.5).with_experience_refund(ExperienceRefund {
+            refund_pct: 0.9,
+            risk_charge_rate: 0.0,
+            carry_forward_losses: true,
+        });
+        assert!(losing.cede(&gross).settlements.iter().all(|s| s.experience_refund == 0.0));
+        assert!(ReinsuranceTreaty::coinsurance(1.5).validate().is_err());
+        assert!(ReinsuranceTreaty::modco(0.5, 0.04).with_funds_withheld(0.04).validate().is_err());
+    }
+}
@@ -0,0 +1,16 @@
+//! Reinsurance treaties and the gross / ceded / net split of projections
+//!
+//! Supported treaty forms:
+//! - **Coinsurance**: quota share of premiums, benefits and reserves, with
+//!   optional funds withheld
+//! - **Modified coinsurance**: quota share with the reserve retained by the
+//!   cedant and a monthly modco reserve adjustment
+//! - **YRT**: quota share of the net amount at risk on death
+//!
+//! Ceding and renewal expense allowances and experience refunds apply on top.
+
+mod treaty;
+mod cession;
+
+pub use treaty::{TreatyType, ExperienceRefund, ReinsuranceTreaty};
+pub use cession::{ReinsuranceSettlement, ReserveSplit, ReinsuranceResult};
@@ -0,0 +1,178 @@
+//! Reinsurance treaty definitions
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::ActuarialError;
+
+/// Form of reinsurance
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TreatyType {
+    /// Quota share of all policy cashflows; the reinsurer holds its share of
+    /// the reserve (or the cedant withholds the assets backing it)
+    Coinsurance,
+
+    /// Modified coinsurance: as coinsurance, but the cedant holds the reserve
+    /// and the assets, settling a modco reserve adjustment each month
+    ModifiedCoinsurance,
+
+    /// Yearly renewable term: the reinsurer covers its share of the net amount
+    /// at risk on death for a mortality-based premium
+    YearlyRenewableTerm,
+}
+
+/// Experience refund terms
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExperienceRefund {
+    /// Share of the reinsurer's positive experience refunded to the cedant
+    pub refund_pct: f64,
+
+    /// Reinsurer risk charge (annual rate on the ceded BOP account value)
+    #[serde(default)]
+    pub risk_charge_rate: f64,
+
+    /// Carry negative experience forward against future refunds
+    #[serde(default = "default_carry_forward_losses")]
+    pub carry_forward_losses: bool,
+}
+
+fn default_carry_forward_losses() -> bool { true }
+
+/// Reinsurance treaty terms
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReinsuranceTreaty {
+    /// Treaty name (reporting only)
+    #[serde(default)]
+    pub name: String,
+
+    /// Form of reinsurance
+    pub treaty_type: TreatyType,
+
+    /// Quota share ceded (0.0 to 1.0)
+    pub quota_share: f64,
+
+    /// Coinsurance with funds withheld: the cedant keeps the assets backing the
+    /// ceded reserve and credits interest on them to the reinsurer
+    #[serde(default)]
+    pub funds_withheld: bool,
+
+    /// Annual interest credited on the modco reserve or funds withheld balance
+    #[serde(default)]
+    pub withheld_interest_rate: f64,
+
+    /// Ceding allowance (% of ceded premium)
+    #[serde(default)]
+    pub initial_allowance: f64,
+
+    /// Renewal expense allowance (annual % of ceded BOP account value)
+    #[serde(default)]
+    pub renewal_allowance: f64,
+
+    /// YRT premium rate as a multiple of the projected mortality rate
+    #[serde(default = "default_yrt_mortality_multiple")]
+    pub yrt_mortality_multiple: f64,
+
+    /// Experience refund terms (None = no refund)
+    #[serde(default)]
+    pub experience_refund: Option<ExperienceRefund>,
+}
+
+fn default_yrt_mortality_multiple() -> f64 { 1.0 }
+
+impl ReinsuranceTreaty {
+    fn new(treaty_type: TreatyType, quota_share: f64) -> Self {
+        Self {
+            name: String::new(),
+            treaty_type,
+            quota_share,
+            funds_withheld: false,
+            withheld_interest_rate: 0.0,
+            initial_allowance: 0.0,
+            renewal_allowance: 0.0,
+            yrt_mortality_multiple: default_yrt_mortality_multiple(),
+            experience_refund: None,
+        }
+    }
+
+    /// Quota share coinsurance
+    pub fn coinsurance(quota_share: f64) -> Self {
+        Self::new(TreatyType::Coinsurance, quota_share)
+    }
+
+    /// Quota share modified coinsurance crediting `interest_rate` on the modco reserve
+    pub fn modco(quota_share: f64, interest_rate: f64) -> Self {
+        Self {
+            withheld_interest_rate: interest_rate,
+            ..Self::new(TreatyType::ModifiedCoinsurance, quota_share)
+        }
+    }
+
+    /// YRT on the net amount at risk, priced at a multiple of projected mortality
+    pub fn yrt(quota_share: f64, mortality_multiple: f64) -> Self {
+        Self {
+            yrt_mortality_multiple: mortality_multiple,
+            ..Self::new(TreatyType::YearlyRenewableTerm, quota_share)
+        }
+    }
+
+    /// Set the treaty name
+    pub fn named(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Withhold the assets backing the ceded reserve, crediting `interest_rate`
+    pub fn with_funds_withheld(mut self, interest_rate: f64) -> Self {
+        self.funds_withheld = true;
+        self.withheld_interest_rate = interest_rate;
+        self
+    }
+
+    /// Set the ceding and renewal expense allowances
+    pub fn with_allowances(mut self, initial: f64, renewal: f64) -> Self {
+        self.initial_allowance = initial;
+        self.renewal_allowance = renewal;
+        self
+    }
+
+    /// Add experience refund terms
+    pub fn with_experience_refund(mut self, refund: ExperienceRefund) -> Self {
+        self.experience_refund = Some(refund);
+        self
+    }
+
+    /// Check the terms are consistent
+    pub fn validate(&self) -> Result<(), ActuarialError> {
+        if !(0.0..=1.0).contains(&self.quota_share) {
+            return Err(ActuarialError::Config(format!("Quota share must be between 0 and 1, got {}", self.quota_share)));
+        }
+        if self.funds_withheld && self.treaty_type != TreatyType::Coinsurance {
+            return Err(ActuarialError::Config("Funds withheld applies to coinsurance only".to_string()));
+        }
+        if let Some(refund) = &self.experience_refund {
+            if !(0.0..=1.0).contains(&refund.refund_pct) {
+                return Err(ActuarialError::Config(format!("Experience refund must be between 0 and 1, got {}", refund.refund_pct)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether the cedant takes credit for the ceded reserve
+    pub fn takes_reserve_credit(&self) -> bool {
+        self.treaty_type == TreatyType::Coinsurance
+    }
+
+    /// Whether the cedant keeps the assets backing the ceded reserve
+    /// (modco reserve or funds withheld)
+    pub fn withholds_assets(&self) -> bool {
+        match self.treaty_type {
+            TreatyType::Coinsurance => self.funds_withheld,
+            TreatyType::ModifiedCoinsurance => true,
+            TreatyType::YearlyRenewableTerm => false,
+        }
+    }
+
+    /// Monthly interest rate on the withheld balance
+    pub fn monthly_withheld_rate(&self) -> f64 {
+        (1.0 + self.withheld_interest_rate).powf(1.0 / 12.0) - 1.0
+    }
+}
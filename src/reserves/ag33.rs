@@ -0,0 +1,374 @@
+//! AG33 reserve calculator with integrated benefit streams
+//!
+//! AG33 applies CARVM to contracts with elective benefits by testing every
+//! integrated benefit stream the policyholder could follow: free partial
+//! withdrawals at the coming anniversaries, then one of
+//! - full surrender at the CSV
+//! - GLWB activation (annuitization)
+//! - holding the contract until death
+//!
+//! Death benefits are paid along every stream. The reserve is the greatest
+//! PV over all streams, floored at the CSV at valuation.
+
+use crate::policy::Policy;
+use crate::assumptions::Assumptions;
+use crate::error::ActuarialError;
+
+use super::types::{
+    ReserveResult, ReserveComponents, ReserveMethod, InforceSnapshot, BenefitStream, StreamElection,
+};
+use super::cache::CacheStats;
+use super::benefits::{inforce_cash_surrender_value, BenefitCalculator, IndexCreditAssumption};
+use super::carvm::{CARVMCalculator, CARVMConfig};
+use super::ReserveCalculator;
+
+/// Configuration for AG33 reserve calculation
+#[derive(Debug, Clone)]
+pub struct AG33Config {
+    /// Projection horizon and election window (income activation and surrender)
+    pub carvm_config: CARVMConfig,
+
+    /// Most anniversaries with a free partial withdrawal tested in one stream
+    pub max_free_pwd_years: u32,
+}
+
+impl Default for AG33Config {
+    fn default() -> Self {
+        Self {
+            carvm_config: CARVMConfig::default(),
+            max_free_pwd_years: 10,
+        }
+    }
+}
+
+/// Greatest-PV stream found by the AG33 search
+#[derive(Debug, Clone)]
+struct StreamValue {
+    stream: BenefitStream,
+    components: ReserveComponents,
+}
+
+impl StreamValue {
+    fn total(&self) -> f64 {
+        self.components.total()
+    }
+}
+
+/// AG33 reserve calculator
+pub struct AG33Calculator {
+    carvm: CARVMCalculator,
+    config: AG33Config,
+}
+
+impl AG33Calculator {
+    /// Create a new AG33 calculator
+    pub fn new(assumptions: Assumptions, config: AG33Config) -> Self {
+        let carvm = CARVMCalculator::new(assumptions, config.carvm_config.clone());
+        Self { carvm, config }
+    }
+
+    /// Project indexed account values with assumed index credits instead of
+    /// guaranteed crediting, on every stream and on income in payment
+    pub fn set_index_credits(&mut self, index_credits: Option<IndexCreditAssumption>) {
+        self.carvm.set_index_credits(index_credits);
+    }
+
+    /// Test every integrated benefit stream from the valuation month
+    ///
+    /// Streams are searched in order of free withdrawal years, then election
+    /// month (surrender before activation), then holding to death; a later
+    /// stream replaces the best only if its PV is strictly greater.
+    fn solve(&self, policy: &Policy, valuation_month: u32, inforce: &InforceSnapshot) -> Result<StreamValue, ActuarialError> {
+        let benefit_calc = self.benefit_calculator(policy);
+        let table = benefit_calc.decrement_table(policy, valuation_month);
+
+        let max_deferral = valuation_month + self.config.carvm_config.max_deferral_years * 12;
+        let last_election = max_deferral.min(self.config.carvm_config.max_projection_months);
+
+        let starting_gav = benefit_calc.guaranteed_av(policy, valuation_month, inforce);
+
+        let mut best: Option<StreamValue> = None;
+        let mut consider = |candidate: StreamValue| {
+            if best.as_ref().is_none_or(|b| candidate.total() > b.total()) {
+                best = Some(candidate);
+            }
+        };
+
+        for free_pwd_years in 0..=self.config.max_free_pwd_years {
+            let path = benefit_calc.free_withdrawal_path(
+                policy,
+                &table,
+                inforce.account_value,
+                inforce.benefit_base,
+                starting_gav,
+                free_pwd_years,
+            );
+
+            // Fewer anniversaries remain than withdrawals requested: already tested
+            let withdrawals = path.iter().filter(|p| p.free_pwd_pv > 0.0).count() as u32;
+            if withdrawals < free_pwd_years {
+                break;
+            }
+
+            let mut prior_death_pv = 0.0;
+            let mut free_pwd_pv = 0.0;
+
+            for (i, point) in path.iter().enumerate() {
+                let month = valuation_month + i as u32;
+                free_pwd_pv += point.free_pwd_pv;
+
+                if month <= last_election {
+                    let csv = benefit_calc.cash_surrender_value(policy, month, point.account_value, point.guaranteed_av);
+                    let surrender_pv = table.survival[i] * csv * table.elective_discount[i];
+                    consider(StreamValue {
+                        stream: BenefitStream { free_pwd_years, election: StreamElection::Surrender { month } },
+                        components: stream_components(prior_death_pv, 0.0, surrender_pv, free_pwd_pv),
+                    });
+
+                    let (death_pv, income_pv) = benefit_calc.income_path_pv(
+                        policy,
+                        &table,
+                        i,
+                        point.account_value,
+                        point.benefit_base,
+                    )?;
+                    consider(StreamValue {
+                        stream: BenefitStream { free_pwd_years, election: StreamElection::Annuitize { month } },
+                        components: stream_components(prior_death_pv + death_pv, income_pv, 0.0, free_pwd_pv),
+                    });
+                }
+
+                prior_death_pv += point.death_benefit_pv;
+            }
+
+            consider(StreamValue {
+                stream: BenefitStream { free_pwd_years, election: StreamElection::Death },
+                components: stream_components(prior_death_pv, 0.0, 0.0, free_pwd_pv),
+            });
+        }
+
+        Ok(best.unwrap_or(StreamValue {
+            stream: BenefitStream { free_pwd_years: 0, election: StreamElection::Death },
+            components: ReserveComponents::default(),
+        }))
+    }
+
+    /// Benefit calculator on the configured discount curve (or the policy's
+    /// valuation rate), with the same index credits as the CARVM calculator
+    fn benefit_calculator(&self, policy: &Policy) -> BenefitCalculator<'_> {
+        BenefitCalculator::for_valuation(
+            self.carvm.assumptions(),
+            policy,
+            self.config.carvm_config.discount_curve.as_ref(),
+            self.config.carvm_config.max_projection_months,
+            self.carvm.index_credits(),
+        )
+    }
+
+    fn stamp(&self, mut result: ReserveResult) -> ReserveResult {
+        result.method = ReserveMethod::AG33;
+        result
+    }
+}
+
+fn stream_components(death_pv: f64, income_pv: f64, surrender_pv: f64, free_pwd_pv: f64) -> ReserveComponents {
+    ReserveComponents {
+        death_benefit_pv: death_pv,
+        income_benefit_pv: income_pv,
+        surrender_value_pv: surrender_pv,
+        elective_benefit_pv: income_pv + surrender_pv + free_pwd_pv,
+        free_pwd_pv,
+    }
+}
+
+impl ReserveCalculator for AG33Calculator {
+    fn calculate_reserve(
+        &mut self,
+        policy: &Policy,
+        valuation_month: u32,
+    ) -> Result<ReserveResult, ActuarialError> {
+        // Later months roll the policy record forward, as CARVM does
+        let mut inforce = InforceSnapshot::from_policy(policy);
+        if valuation_month > 0 {
+            inforce = self
+                .benefit_calculator(policy)
+                .roll_forward_inforce(policy, &inforce, 0, valuation_month)?;
+        }
+        self.calculate_reserve_from_state(policy, valuation_month, &inforce)
+    }
+
+    fn calculate_reserve_from_state(
+        &mut self,
+        policy: &Policy,
+        valuation_month: u32,
+        inforce: &InforceSnapshot,
+    ) -> Result<ReserveResult, ActuarialError> {
+        // Contract mortality and the joint payout start from the snapshot's lives
+        let policy = &inforce.policy_with_survival(policy);
+
+        // Income in payment: no elections remain, value as CARVM does
+        if inforce.income_activated {
+            let mut result = self.carvm.calculate_reserve_from_state(policy, valuation_month, inforce)?;
+            result.benefit_stream = Some(BenefitStream {
+                free_pwd_years: 0,
+                election: StreamElection::Annuitize { month: valuation_month },
+            });
+            return Ok(self.stamp(result));
+        }
+
+        let best = self.solve(policy, valuation_month, inforce)?;
+
+        let csv = inforce_cash_surrender_value(self.carvm.assumptions(), policy, valuation_month, inforce);
+
+        let gross_reserve = best.total().max(csv);
+
+        Ok(ReserveResult {
+            policy_id: policy.policy_id,
+            valuation_date: valuation_month,
+            gross_reserve,
+            net_reserve: gross_reserve,
+            optimal_activation_month: match best.stream.election {
+                StreamElection::Annuitize { month } => month,
+                _ => u32::MAX,
+            },
+            reserve_components: best.components,
+            method: ReserveMethod::AG33,
+            from_cache: false,
+            csv_at_valuation: csv,
+            benefit_stream: Some(best.stream),
+            trace: None,
+            guaranteed_benefit: None,
+        })
+    }
+
+    fn cache_statistics(&self) -> CacheStats {
+        self.carvm.cache_statistics()
+    }
+
+    fn clear_cache(&mut self) {
+        self.carvm.clear_cache();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::{QualStatus, Gender, CreditingStrategy, RollupType};
+
+    fn test_policy() -> Policy {
+        Policy::new(
+            2800,
+            QualStatus::Q,
+            65,
+            Gender::Male,
+            130_000.0,
+            1.0,
+            100_000.0,
+            CreditingStrategy::Indexed,
+            10,
+            0.0475,
+            0.01,
+            0.3,
+            RollupType::Simple,
+        )
+    }
+
+    fn test_config() -> AG33Config {
+        AG33Config {
+            carvm_config: CARVMConfig {
+                max_projection_months: 240,
+                max_deferral_years: 10,
+                use_caching: false,
+                ..Default::default()
+            },
+            max_free_pwd_years: 5,
+        }
+    }
+
+    #[test]
+    fn test_ag33_covers_carvm_streams() {
+        let assumptions = Assumptions::default_pricing();
+        let policy = test_policy();
+
+        let mut carvm = CARVMCalculator::new(assumptions.clone(), test_config().carvm_config);
+        let mut ag33 = AG33Calculator::new(assumptions, test_config());
+
+        let carvm_result = carvm.calculate_reserve(&policy, 0).unwrap();
+        let ag33_result = ag33.calculate_reserve(&policy, 0).unwrap();
+
+        assert_eq!(ag33_result.method, ReserveMethod::AG33);
+        assert!(ag33_result.benefit_stream.is_some());
+        assert!(ag33_result.gross_reserve >= carvm_result.gross_reserve - 1e-6);
+    }
+
+    #[test]
+    fn test_free_withdrawal_then_surrender() {
+        // A negligible benefit base leaves no GLWB value, so at an anniversary
+        // the best stream takes the free amount and surrenders the rest
+        let mut ag33 = AG33Calculator::new(Assumptions::default_pricing(), test_config());
+        let policy = test_policy();
+        let mut inforce = InforceSnapshot {
+            account_value: 100_000.0,
+            benefit_base: 1.0,
+            guaranteed_av: None,
+            income_activated: false,
+            locked_payout_rate: None,
+            ..InforceSnapshot::from_policy(&policy)
+        };
+        let sc_rate = ag33.carvm.assumptions().product.base.surrender_charges.get_rate(policy.policy_year(12));
+        let immediate = BenefitStream { free_pwd_years: 1, election: StreamElection::Surrender { month: 12 } };
+
+        // 5% free, then the remaining AV net of the surrender charge; the
+        // nonforfeiture floor (87.5% of premium at MGIR) is not binding
+        let result = ag33.calculate_reserve_from_state(&policy, 12, &inforce).unwrap();
+        assert_eq!(result.benefit_stream, Some(immediate));
+        assert!((result.csv_at_valuation - 100_000.0 * (1.0 - sc_rate)).abs() < 1e-6);
+        assert!((result.reserve_components.free_pwd_pv - 5_000.0).abs() < 1e-6);
+        assert!((result.reserve_components.surrender_value_pv - 95_000.0 * (1.0 - sc_rate)).abs() < 1e-6);
+        assert!((result.gross_reserve - result.reserve_components.total()).abs() < 1e-6);
+        assert_eq!(result.optimal_activation_month, u32::MAX);
+
+        // With a binding floor the free withdrawal reduces the floor pro rata
+        inforce.guaranteed_av = Some(95_000.0);
+        let result = ag33.calculate_reserve_from_state(&policy, 12, &inforce).unwrap();
+        assert_eq!(result.benefit_stream, Some(immediate));
+        assert_eq!(result.csv_at_valuation, 95_000.0);
+        assert!((result.reserve_components.surrender_value_pv - 95_000.0 * 0.95).abs() < 1e-6);
+        assert!((result.gross_reserve - (5_000.0 + 90_250.0)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_index_credits_reach_ag33_streams() {
+        // No GLWB value, so the streams are surrender and death on the AV alone
+        let policy = test_policy();
+        let inforce = InforceSnapshot {
+            benefit_base: 1.0,
+            ..InforceSnapshot::from_policy(&policy)
+        };
+        let mut guaranteed = AG33Calculator::new(Assumptions::default_pricing(), test_config());
+        let mut credited = AG33Calculator::new(Assumptions::default_pricing(), test_config());
+        credited.set_index_credits(Some(IndexCreditAssumption {
+            current_policy_year: 1,
+            current_period_rate: 0.08,
+            future_rate: 0.08,
+            full_rate_years: 10,
+        }));
+
+        let base = guaranteed.calculate_reserve_from_state(&policy, 0, &inforce).unwrap();
+        let with_credits = credited.calculate_reserve_from_state(&policy, 0, &inforce).unwrap();
+        assert_eq!(with_credits.csv_at_valuation, base.csv_at_valuation);
+        assert!(with_credits.gross_reserve > base.gross_reserve);
+    }
+
+    #[test]
+    fn test_benefit_stream_description() {
+        let stream = BenefitStream {
+            free_pwd_years: 2,
+            election: StreamElection::Annuitize { month: 36 },
+        };
+        assert_eq!(stream.to_string(), "free PWDs for 2 year(s), then activate income at month 36");
+
+        let hold = BenefitStream { free_pwd_years: 0, election: StreamElection::Death };
+        assert_eq!(hold.to_string(), "hold until death");
+    }
+}
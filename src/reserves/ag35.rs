@@ -0,0 +1,283 @@
+//! AG35 reserve calculator for equity indexed annuities
+//!
+//! AG35 applies CARVM to indexed contracts, projecting account values with the
+//! index credits implied by the cost of the options that hedge them:
+//! - **Type 1** (Enhanced Discounted Intrinsic Value): the indexing period in
+//!   progress credits its intrinsic value (the implied credit when no index
+//!   experience is supplied); later periods credit the option budget accumulated
+//!   at the valuation rate
+//! - **Type 2** (Hedged as Required): the period in progress credits the hedge
+//!   value, grown at the derivative appreciation net of financing; later
+//!   periods as Type 1
+//!
+//! The optimization over policyholder behavior is shared with `CARVMCalculator`.
+
+use std::path::Path;
+
+use crate::assumptions::Assumptions;
+use crate::error::ActuarialError;
+use crate::policy::Policy;
+use crate::projection::HedgeParams;
+
+use super::types::{ReserveResult, ReserveMethod, InforceSnapshot};
+use super::cache::CacheStats;
+use super::benefits::IndexCreditAssumption;
+use super::carvm::{CARVMCalculator, CARVMConfig};
+use super::ReserveCalculator;
+
+/// AG35 computational method
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AG35Type {
+    /// Type 1 - Enhanced Discounted Intrinsic Value
+    Type1,
+
+    /// Type 2 - Hedged as Required
+    Type2,
+}
+
+/// Configuration for AG35 reserve calculation
+#[derive(Debug, Clone)]
+pub struct AG35Config {
+    /// CARVM optimization settings
+    pub carvm_config: CARVMConfig,
+
+    /// Option budget and derivative assumptions
+    pub hedge_params: HedgeParams,
+
+    /// Index credit earned to date on the period in progress (Type 1 intrinsic value)
+    /// None = use the credit implied by the option budget
+    pub current_period_credit: Option<f64>,
+
+    /// Policy years the full option budget is spent (halved afterwards, as in pricing)
+    pub full_budget_years: u32,
+}
+
+impl Default for AG35Config {
+    fn default() -> Self {
+        Self {
+            carvm_config: CARVMConfig::default(),
+            hedge_params: HedgeParams::default(),
+            current_period_credit: None,
+            full_budget_years: 10,
+        }
+    }
+}
+
+/// AG35 reserve calculator (Type 1 or Type 2)
+pub struct AG35Calculator {
+    carvm: CARVMCalculator,
+    config: AG35Config,
+    method: AG35Type,
+}
+
+impl AG35Calculator {
+    /// Create a new AG35 calculator
+    pub fn new(assumptions: Assumptions, config: AG35Config, method: AG35Type) -> Self {
+        let carvm = CARVMCalculator::new(assumptions, config.carvm_config.clone());
+        Self {
+            carvm,
+            config,
+            method,
+        }
+    }
+
+    /// Create a Type 1 (Enhanced Discounted Intrinsic Value) calculator
+    pub fn type1(assumptions: Assumptions, config: AG35Config) -> Self {
+        Self::new(assumptions, config, AG35Type::Type1)
+    }
+
+    /// Create a Type 2 (Hedged as Required) calculator
+    pub fn type2(assumptions: Assumptions, config: AG35Config) -> Self {
+        Self::new(assumptions, config, AG35Type::Type2)
+    }
+
+    /// Reserve method stamped on results
+    pub fn reserve_method(&self) -> ReserveMethod {
+        match self.method {
+            AG35Type::Type1 => ReserveMethod::AG35Type1,
+            AG35Type::Type2 => ReserveMethod::AG35Type2,
+        }
+    }
+
+    /// Get cache statistics of the underlying CARVM optimization
+    pub fn cache_stats(&self) -> (u64, u64, f64) {
+        self.carvm.cache_stats()
+    }
+
+    /// Warm-start the underlying CARVM cache from a file saved by an earlier run
+    pub fn load_cache(&mut self, path: &Path) -> Result<usize, ActuarialError> {
+        self.carvm.load_cache(path)
+    }
+
+    /// Save the underlying CARVM cache for a later run
+    pub fn save_cache(&self, path: &Path) -> Result<(), ActuarialError> {
+        self.carvm.save_cache(path)
+    }
+
+    /// Option budget spent in a policy year
+    fn option_budget(&self, policy_year: u32) -> f64 {
+        let budget = self.config.hedge_params.option_budget;
+        if policy_year <= self.config.full_budget_years {
+            budget
+        } else {
+            budget * 0.5
+        }
+    }
+
+    /// Index credits assumed from the valuation month
+    ///
+    /// Implied credit = option budget accumulated to the end of the indexing
+    /// period at the valuation rate (the option's forward value).
+    pub fn index_credits(&self, policy: &Policy, valuation_month: u32) -> IndexCreditAssumption {
+        let current_policy_year = policy.policy_year(valuation_month);
+        let current_budget = self.option_budget(current_policy_year);
+        let implied = |budget: f64| budget * (1.0 + policy.val_rate);
+
+        let current_period_rate = match self.method {
+            AG35Type::Type1 => self
+                .config
+                .current_period_credit
+                .unwrap_or_else(|| implied(current_budget)),
+            AG35Type::Type2 => {
+                let params = &self.config.hedge_params;
+                current_budget * (1.0 + params.appreciation_rate - params.financing_fee)
+            }
+        };
+
+        IndexCreditAssumption {
+            current_policy_year,
+            current_period_rate,
+            future_rate: implied(self.config.hedge_params.option_budget),
+            full_rate_years: self.config.full_budget_years,
+        }
+    }
+
+    fn stamp(&self, mut result: ReserveResult) -> ReserveResult {
+        result.method = self.reserve_method();
+        result
+    }
+}
+
+impl ReserveCalculator for AG35Calculator {
+    fn calculate_reserve(
+        &mut self,
+        policy: &Policy,
+        valuation_month: u32,
+    ) -> Result<ReserveResult, ActuarialError> {
+        let credits = self.index_credits(policy, valuation_month);
+        self.carvm.set_index_credits(Some(credits));
+        let result = self.carvm.calculate_reserve(policy, valuation_month)?;
+        Ok(self.stamp(result))
+    }
+
+    fn calculate_reserve_from_state(
+        &mut self,
+        policy: &Policy,
+        valuation_month: u32,
+        inforce: &InforceSnapshot,
+    ) -> Result<ReserveResult, ActuarialError> {
+        let credits = self.index_credits(policy, valuation_month);
+        self.carvm.set_index_credits(Some(credits));
+        let result = self.carvm.calculate_reserve_from_state(policy, valuation_month, inforce)?;
+        Ok(self.stamp(result))
+    }
+
+    fn cache_statistics(&self) -> CacheStats {
+        self.carvm.cache_statistics()
+    }
+
+    fn clear_cache(&mut self) {
+        self.carvm.clear_cache();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::{QualStatus, Gender, CreditingStrategy, RollupType};
+
+    fn test_policy() -> Policy {
+        Policy::new(
+            2800,
+            QualStatus::Q,
+            65,
+            Gender::Male,
+            130_000.0,
+            1.0,
+            100_000.0,
+            CreditingStrategy::Indexed,
+            10,
+            0.0475,
+            0.01,
+            0.3,
+            RollupType::Simple,
+        )
+    }
+
+    fn test_config() -> AG35Config {
+        AG35Config {
+            carvm_config: CARVMConfig {
+                max_projection_months: 240,
+                max_deferral_years: 10,
+                use_caching: false,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_implied_index_credits() {
+        let assumptions = Assumptions::default_pricing();
+        let policy = test_policy();
+
+        let type1 = AG35Calculator::type1(assumptions.clone(), test_config());
+        let credits = type1.index_credits(&policy, 0);
+        let implied = 0.0315 * 1.0475;
+        assert!((credits.current_period_rate - implied).abs() < 1e-12);
+        assert!((credits.annual_rate(5) - implied).abs() < 1e-12);
+        assert!((credits.annual_rate(11) - implied * 0.5).abs() < 1e-12);
+
+        // Type 2 credits the hedge value for the period in progress: 3.15% * (1 + 20% - 5%)
+        let type2 = AG35Calculator::type2(assumptions, test_config());
+        let credits = type2.index_credits(&policy, 0);
+        assert!((credits.current_period_rate - 0.0315 * 1.15).abs() < 1e-12);
+        assert!((credits.annual_rate(2) - implied).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_ag35_reserves() {
+        let assumptions = Assumptions::default_pricing();
+        let policy = test_policy();
+
+        let mut carvm = CARVMCalculator::new(assumptions.clone(), test_config().carvm_config);
+        let mut type1 = AG35Calculator::type1(assumptions.clone(), test_config());
+        let mut type2 = AG35Calculator::type2(assumptions, test_config());
+
+        let carvm_result = carvm.calculate_reserve(&policy, 0).unwrap();
+        let type1_result = type1.calculate_reserve(&policy, 0).unwrap();
+        let type2_result = type2.calculate_reserve(&policy, 0).unwrap();
+
+        assert_eq!(type1_result.method, ReserveMethod::AG35Type1);
+        assert_eq!(type2_result.method, ReserveMethod::AG35Type2);
+
+        // Implied index credits exceed the 1% MGIR, and the Type 2 hedge value
+        // exceeds the Type 1 implied credit for the period in progress
+        assert!(type1_result.gross_reserve >= carvm_result.gross_reserve);
+        assert!(type2_result.gross_reserve >= type1_result.gross_reserve);
+    }
+
+    #[test]
+    fn test_fixed_strategy_uses_guaranteed_crediting() {
+        let assumptions = Assumptions::default_pricing();
+        let mut policy = test_policy();
+        policy.crediting_strategy = CreditingStrategy::Fixed;
+
+        let mut carvm = CARVMCalculator::new(assumptions.clone(), test_config().carvm_config);
+        let mut type1 = AG35Calculator::type1(assumptions, test_config());
+
+        let carvm_result = carvm.calculate_reserve(&policy, 0).unwrap();
+        let type1_result = type1.calculate_reserve(&policy, 0).unwrap();
+        assert!((carvm_result.gross_reserve - type1_result.gross_reserve).abs() < 1e-9);
+    }
+}
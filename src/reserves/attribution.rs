@@ -0,0 +1,440 @@
+//! Reserve analysis of change between two valuations
+//!
+//! Explains the movement from an opening to a closing reserve by stepping
+//! through ordered changes, revaluing after each one:
+//!
+//! 1. **Time unwind**: roll to the closing month on expected values
+//!    (valuation mortality, reserve-basis crediting and charges)
+//! 2. **Decrements**: actual in-force lives instead of expected
+//! 3. **AV / BB**: actual account value and benefit base instead of expected
+//! 4. **Assumptions**: closing assumption set
+//! 5. **Discount rate**: closing discount curve
+//! 6. **Method**: closing reserve method and remaining configuration
+//!
+//! Each step's impact is the change in reserve it causes, so the steps add up
+//! to closing less opening exactly. Steps are order dependent; the order above
+//! matches the usual finance roll.
+
+use serde::{Deserialize, Serialize};
+
+use crate::assumptions::Assumptions;
+use crate::error::ActuarialError;
+use crate::policy::Policy;
+
+use super::benefits::BenefitCalculator;
+use super::discount::DiscountCurve;
+use super::types::InforceSnapshot;
+use super::{ReserveCalcConfig, ReserveCalculator};
+
+/// Assumptions and reserve configuration for one side of the analysis
+#[derive(Debug, Clone)]
+pub struct ValuationBasis {
+    pub assumptions: Assumptions,
+    pub config: ReserveCalcConfig,
+}
+
+impl ValuationBasis {
+    pub fn new(assumptions: Assumptions, config: ReserveCalcConfig) -> Self {
+        Self { assumptions, config }
+    }
+}
+
+/// In-force position of a policy record at a valuation month
+///
+/// Account value, benefit base and guaranteed value are totals for the lives
+/// still in force, as the projection engine reports them.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ValuationPosition {
+    pub valuation_month: u32,
+
+    /// Lives in force
+    pub lives: f64,
+
+    /// In-force values
+    pub inforce: InforceSnapshot,
+}
+
+impl ValuationPosition {
+    pub fn new(valuation_month: u32, lives: f64, inforce: InforceSnapshot) -> Self {
+        Self { valuation_month, lives, inforce }
+    }
+
+    /// Position at projection start from the policy record
+    pub fn from_policy(policy: &Policy) -> Self {
+        Self::new(0, policy.initial_pols, InforceSnapshot::from_policy(policy))
+    }
+}
+
+/// Step in the analysis of change
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChangeStep {
+    TimeUnwind,
+    Decrements,
+    AccountValue,
+    Assumptions,
+    DiscountRate,
+    Method,
+}
+
+impl ChangeStep {
+    /// All steps in the order they are applied
+    pub const ORDER: [ChangeStep; 6] = [
+        ChangeStep::TimeUnwind,
+        ChangeStep::Decrements,
+        ChangeStep::AccountValue,
+        ChangeStep::Assumptions,
+        ChangeStep::DiscountRate,
+        ChangeStep::Method,
+    ];
+
+    /// Report label
+    pub fn label(&self) -> &'static str {
+        match self {
+            ChangeStep::TimeUnwind => "Interest / time unwind",
+            ChangeStep::Decrements => "Actual vs expected decrements",
+            ChangeStep::AccountValue => "AV / BB movement",
+            ChangeStep::Assumptions => "Assumption changes",
+            ChangeStep::DiscountRate => "Discount rate changes",
+            ChangeStep::Method => "Method change",
+        }
+    }
+}
+
+/// Reserve impact of one step
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct StepImpact {
+    pub step: ChangeStep,
+
+    /// Reserve after the step is applied
+    pub reserve_after: f64,
+
+    /// Change in reserve due to the step
+    pub change: f64,
+}
+
+/// Reserve movement explained by step
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReserveAttribution {
+    /// Policy ID (None for aggregate totals)
+    pub policy_id: Option<u32>,
+
+    pub opening_reserve: f64,
+    pub closing_reserve: f64,
+
+    /// Impacts in application order
+    pub steps: Vec<StepImpact>,
+}
+
+impl ReserveAttribution {
+    /// Closing less opening reserve
+    pub fn total_change(&self) -> f64 {
+        self.closing_reserve - self.opening_reserve
+    }
+
+    /// Impact of a single step
+    pub fn change_for(&self, step: ChangeStep) -> f64 {
+        self.steps
+            .iter()
+            .filter(|s| s.step == step)
+            .map(|s| s.change)
+            .sum()
+    }
+
+    /// Add another attribution's amounts into this one
+    fn accumulate(&mut self, other: &ReserveAttribution) {
+        self.opening_reserve += other.opening_reserve;
+        self.closing_reserve += other.closing_reserve;
+        for (total, step) in self.steps.iter_mut().zip(&other.steps) {
+            total.reserve_after += step.reserve_after;
+            total.change += step.change;
+        }
+    }
+}
+
+/// Per-policy and aggregate analysis of change
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnalysisOfChangeResult {
+    pub policies: Vec<ReserveAttribution>,
+    pub totals: ReserveAttribution,
+}
+
+/// Calculators for the opening and each basis change, built once per run
+struct StepCalculators {
+    opening: Box<dyn ReserveCalculator>,
+    assumptions: Box<dyn ReserveCalculator>,
+    discount: Box<dyn ReserveCalculator>,
+    closing: Box<dyn ReserveCalculator>,
+}
+
+/// Analysis of change between an opening and a closing valuation
+pub struct AnalysisOfChange {
+    opening: ValuationBasis,
+    closing: ValuationBasis,
+}
+
+impl AnalysisOfChange {
+    /// Compare two bases (pass the same basis twice to explain movement over time only)
+    pub fn new(opening: ValuationBasis, closing: ValuationBasis) -> Self {
+        Self { opening, closing }
+    }
+
+    /// Same assumptions and configuration at both valuation months
+    pub fn between_months(basis: ValuationBasis) -> Self {
+        Self::new(basis.clone(), basis)
+    }
+
+    /// Analyse one policy record
+    pub fn analyze_policy(
+        &self,
+        policy: &Policy,
+        opening: &ValuationPosition,
+        closing: &ValuationPosition,
+    ) -> Result<ReserveAttribution, ActuarialError> {
+        let mut calculators = self.calculators();
+        self.attribute(&mut calculators, policy, opening, closing)
+    }
+
+    /// Analyse a block of (policy, opening position, closing position) records
+    pub fn analyze(
+        &self,
+        records: &[(Policy, ValuationPosition, ValuationPosition)],
+    ) -> Result<AnalysisOfChangeResult, ActuarialError> {
+        let mut calculators = self.calculators();
+        let mut totals = ReserveAttribution {
+            policy_id: None,
+            opening_reserve: 0.0,
+            closing_reserve: 0.0,
+            steps: ChangeStep::ORDER
+                .iter()
+                .map(|&step| StepImpact { step, reserve_after: 0.0, change: 0.0 })
+                .collect(),
+        };
+
+        let mut policies = Vec::with_capacity(records.len());
+        for (policy, opening, closing) in records {
+            let attribution = self.attribute(&mut calculators, policy, opening, closing)?;
+            totals.accumulate(&attribution);
+            policies.push(attribution);
+        }
+
+        Ok(AnalysisOfChangeResult { policies, totals })
+    }
+
+    /// Build the step calculators (caching off, so every step is a full solve)
+    fn calculators(&self) -> StepCalculators {
+        let uncached = |config: &ReserveCalcConfig| {
+            let mut config = config.clone();
+            config.carvm_config.use_caching = false;
+            config
+        };
+
+        let opening_config = uncached(&self.opening.config);
+        let mut discount_config = opening_config.clone();
+        discount_config.carvm_config.discount_curve = self.closing.config.carvm_config.discount_curve.clone();
+
+        StepCalculators {
+            opening: opening_config.build_calculator(self.opening.assumptions.clone()),
+            assumptions: opening_config.build_calculator(self.closing.assumptions.clone()),
+            discount: discount_config.build_calculator(self.closing.assumptions.clone()),
+            closing: uncached(&self.closing.config).build_calculator(self.closing.assumptions.clone()),
+        }
+    }
+
+    fn attribute(
+        &self,
+        calculators: &mut StepCalculators,
+        policy: &Policy,
+        opening: &ValuationPosition,
+        closing: &ValuationPosition,
+    ) -> Result<ReserveAttribution, ActuarialError> {
+        if closing.valuation_month < opening.valuation_month {
+            return Err(ActuarialError::Config(format!(
+                "Policy {}: closing month {} is before opening month {}",
+                policy.policy_id, closing.valuation_month, opening.valuation_month
+            )));
+        }
+
+        let expected = self.expected_position(policy, opening, closing.valuation_month)?;
+
+        // Expected values per life, scaled to the actual lives in force
+        let actual_lives = if expected.lives > 0.0 {
+            ValuationPosition {
+                lives: closing.lives,
+                inforce: scaled(&expected.inforce, closing.lives / expected.lives),
+                ..expected
+            }
+        } else {
+            *closing
+        };
+
+        let opening_reserve = value(calculators.opening.as_mut(), policy, opening)?;
+        let after = [
+            value(calculators.opening.as_mut(), policy, &expected)?,
+            value(calculators.opening.as_mut(), policy, &actual_lives)?,
+            value(calculators.opening.as_mut(), policy, closing)?,
+            value(calculators.assumptions.as_mut(), policy, closing)?,
+            value(calculators.discount.as_mut(), policy, closing)?,
+            value(calculators.closing.as_mut(), policy, closing)?,
+        ];
+
+        let mut prior = opening_reserve;
+        let steps = ChangeStep::ORDER
+            .iter()
+            .zip(after)
+            .map(|(&step, reserve_after)| {
+                let change = reserve_after - prior;
+                prior = reserve_after;
+                StepImpact { step, reserve_after, change }
+            })
+            .collect();
+
+        Ok(ReserveAttribution {
+            policy_id: Some(policy.policy_id),
+            opening_reserve,
+            closing_reserve: prior,
+            steps,
+        })
+    }
+
+    /// Opening position rolled to `to_month` on the opening basis
+    ///
+    /// Lives decrement at valuation mortality; values per life follow the
+    /// reserve-basis roll-forward (guaranteed crediting, rider charges and
+    /// GLWB withdrawals).
+    fn expected_position(
+        &self,
+        policy: &Policy,
+        opening: &ValuationPosition,
+        to_month: u32,
+    ) -> Result<ValuationPosition, ActuarialError> {
+        let from_month = opening.valuation_month;
+        let calc = BenefitCalculator::new(
+            &self.opening.assumptions,
+            DiscountCurve::single_rate(policy.val_rate),
+            self.opening.config.carvm_config.max_projection_months.max(to_month + 1),
+        );
+
+        let survival = calc
+            .decrement_table(policy, from_month)
+            .survival
+            .get((to_month - from_month) as usize)
+            .copied()
+            .unwrap_or(0.0);
+        let rolled = calc.roll_forward_inforce(policy, &opening.inforce, from_month, to_month)?;
+
+        Ok(ValuationPosition::new(to_month, opening.lives * survival, scaled(&rolled, survival)))
+    }
+}
+
+/// Reserve held for a position (nothing once no lives remain)
+fn value(
+    calculator: &mut dyn ReserveCalculator,
+    policy: &Policy,
+    position: &ValuationPosition,
+) -> Result<f64, ActuarialError> {
+    if position.lives <= 0.0 {
+        return Ok(0.0);
+    }
+    Ok(calculator
+        .calculate_reserve_from_state(policy, position.valuation_month, &position.inforce)?
+        .gross_reserve)
+}
+
+/// In-force values for a share of the lives (amounts and lives scale together)
+fn scaled(inforce: &InforceSnapshot, factor: f64) -> InforceSnapshot {
+    inforce.with_lives(inforce.lives * factor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::{QualStatus, Gender, CreditingStrategy, RollupType};
+    use crate::reserves::{CARVMConfig, CARVMMethod, ReserveMethod, YieldCurve};
+
+    fn test_policy() -> Policy {
+        let mut policy = Policy::new(
+            1,
+            QualStatus::Q,
+            65,
+            Gender::Male,
+            130_000.0,
+            1.0,
+            100_000.0,
+            CreditingStrategy::Indexed,
+            10,
+            0.0475,
+            0.01,
+            0.3,
+            RollupType::Simple,
+        );
+        policy.current_guaranteed_av = Some(87_500.0);
+        policy
+    }
+
+    fn test_basis() -> ValuationBasis {
+        let config = ReserveCalcConfig {
+            carvm_config: CARVMConfig {
+                method: CARVMMethod::BruteForce,
+                max_projection_months: 240,
+                max_deferral_years: 5,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        ValuationBasis::new(Assumptions::default_pricing(), config)
+    }
+
+    #[test]
+    fn test_steps_explain_movement() {
+        let policy = test_policy();
+        let aoc = AnalysisOfChange::between_months(test_basis());
+        let opening = ValuationPosition::from_policy(&policy);
+
+        // Actual experience: more lives lost and a lower account value than expected
+        let expected = aoc.expected_position(&policy, &opening, 12).unwrap();
+        let mut closing = expected;
+        closing.lives *= 0.95;
+        closing.inforce = scaled(&expected.inforce, 0.95);
+        closing.inforce.account_value *= 0.98;
+
+        let attribution = aoc.analyze_policy(&policy, &opening, &closing).unwrap();
+        let explained: f64 = attribution.steps.iter().map(|s| s.change).sum();
+        assert!((explained - attribution.total_change()).abs() < 1e-6);
+
+        assert!(attribution.change_for(ChangeStep::TimeUnwind).abs() > 0.0);
+        assert!(attribution.change_for(ChangeStep::Decrements) < 0.0);
+        assert!(attribution.change_for(ChangeStep::AccountValue) < 0.0);
+
+        // Same basis at both ends: no basis changes
+        assert_eq!(attribution.change_for(ChangeStep::Assumptions), 0.0);
+        assert_eq!(attribution.change_for(ChangeStep::DiscountRate), 0.0);
+        assert_eq!(attribution.change_for(ChangeStep::Method), 0.0);
+
+        // Going backwards is an error
+        assert!(matches!(aoc.analyze_policy(&policy, &closing, &opening), Err(ActuarialError::Config(_))));
+    }
+
+    #[test]
+    fn test_basis_changes_at_one_month() {
+        let policy = test_policy();
+        let opening_basis = test_basis();
+        let mut closing_basis = opening_basis.clone();
+        closing_basis.config.carvm_config.discount_curve =
+            Some(DiscountCurve::from_yield_curve(YieldCurve::flat(0.07)));
+        closing_basis.config.method = ReserveMethod::AG33;
+
+        let aoc = AnalysisOfChange::new(opening_basis, closing_basis);
+        let position = ValuationPosition::from_policy(&policy);
+        let result = aoc.analyze(&[(policy.clone(), position, position)]).unwrap();
+        let attribution = &result.policies[0];
+
+        // Same month and same values: only the basis steps move the reserve
+        assert!(attribution.change_for(ChangeStep::TimeUnwind).abs() < 1e-9);
+        assert!(attribution.change_for(ChangeStep::Decrements).abs() < 1e-9);
+        assert!(attribution.change_for(ChangeStep::AccountValue).abs() < 1e-9);
+        assert!(attribution.change_for(ChangeStep::DiscountRate) < 0.0);
+
+        assert_eq!(result.totals.policy_id, None);
+        assert!((result.totals.total_change() - attribution.total_change()).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,287 @@
+//! Parallel batch reserve calculation
+//!
+//! Policies are sharded by policy ID across a fixed set of calculators, each
+//! behind its own lock. Shards run in parallel on the rayon pool while each
+//! shard values its policies serially, so a policy always meets the same
+//! cache and roll-forwards keep working across successive batches.
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::assumptions::Assumptions;
+use crate::error::ActuarialError;
+use crate::policy::Policy;
+
+use super::types::ReserveResult;
+use super::cache::CacheStats;
+use super::{ReserveCalcConfig, ReserveCalculator};
+
+/// Policy attribute used to group batch totals
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SegmentKey {
+    /// Single segment for the whole batch
+    All,
+    QualStatus,
+    Gender,
+    CreditingStrategy,
+    BenefitBaseBucket,
+    /// Issue age bands of `width` years, e.g. "55-59"
+    IssueAgeBand { width: u8 },
+}
+
+impl SegmentKey {
+    /// Segment label for a policy
+    pub fn label(&self, policy: &Policy) -> String {
+        match self {
+            SegmentKey::All => "All".to_string(),
+            SegmentKey::QualStatus => format!("{:?}", policy.qual_status),
+            SegmentKey::Gender => format!("{:?}", policy.gender),
+            SegmentKey::CreditingStrategy => format!("{:?}", policy.crediting_strategy),
+            SegmentKey::BenefitBaseBucket => format!("{:?}", policy.benefit_base_bucket),
+            SegmentKey::IssueAgeBand { width } => {
+                let width = (*width).max(1);
+                let low = policy.issue_age / width * width;
+                format!("{}-{}", low, low.saturating_add(width - 1))
+            }
+        }
+    }
+}
+
+/// Reserve totals for one segment
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SegmentTotals {
+    pub segment: String,
+    pub policy_count: usize,
+    pub gross_reserve: f64,
+    pub net_reserve: f64,
+    pub cash_surrender_value: f64,
+
+    /// Policies whose reserve was rolled forward from the cache
+    pub from_cache: usize,
+}
+
+impl SegmentTotals {
+    fn add(&mut self, result: &ReserveResult) {
+        self.policy_count += 1;
+        self.gross_reserve += result.gross_reserve;
+        self.net_reserve += result.net_reserve;
+        self.cash_surrender_value += result.csv_at_valuation;
+        if result.from_cache {
+            self.from_cache += 1;
+        }
+    }
+}
+
+/// Total reserves by segment, in segment label order
+///
+/// `results` must line up with `policies`.
+pub fn aggregate_by_segment(policies: &[Policy], results: &[ReserveResult], key: SegmentKey) -> Vec<SegmentTotals> {
+    let mut segments: BTreeMap<String, SegmentTotals> = BTreeMap::new();
+
+    for (policy, result) in policies.iter().zip(results) {
+        let label = key.label(policy);
+        segments
+            .entry(label.clone())
+            .or_insert_with(|| SegmentTotals { segment: label, ..Default::default() })
+            .add(result);
+    }
+
+    segments.into_values().collect()
+}
+
+/// Output of a batch run
+#[derive(Debug, Clone)]
+pub struct BatchReserveResult {
+    /// Per-policy results, in input order
+    pub results: Vec<ReserveResult>,
+
+    /// Totals by the batch's segment key
+    pub segments: Vec<SegmentTotals>,
+
+    /// Totals over the whole batch
+    pub totals: SegmentTotals,
+
+    /// Cache counters summed over all shards (cumulative across batches)
+    pub cache_stats: CacheStats,
+}
+
+/// Thread-safe batch reserve calculator
+pub struct BatchReserveCalculator {
+    shards: Vec<Mutex<Box<dyn ReserveCalculator>>>,
+    segment_by: SegmentKey,
+}
+
+impl BatchReserveCalculator {
+    /// One shard per rayon worker thread
+    pub fn new(assumptions: Assumptions, config: &ReserveCalcConfig) -> Self {
+        Self::with_shards(assumptions, config, rayon::current_num_threads())
+    }
+
+    /// Fixed number of shards (at least one)
+    pub fn with_shards(assumptions: Assumptions, config: &ReserveCalcConfig, num_shards: usize) -> Self {
+        let shards = (0..num_shards.max(1))
+            .map(|_| Mutex::new(config.build_calculator(assumptions.clone())))
+            .collect();
+
+        Self {
+            shards,
+            segment_by: SegmentKey::All,
+        }
+    }
+
+    /// Group batch totals by a policy attribute
+    pub fn segment_by(mut self, key: SegmentKey) -> Self {
+        self.segment_by = key;
+        self
+    }
+
+    /// Number of shards
+    pub fn num_shards(&self) -> usize {
+        self.shards.len()
+    }
+
+    fn shard_of(&self, policy: &Policy) -> usize {
+        policy.policy_id as usize % self.shards.len()
+    }
+
+    /// Value every policy at the valuation month
+    ///
+    /// Fails with the first policy error in input order.
+    pub fn calculate(&self, policies: &[Policy], valuation_month: u32) -> Result<BatchReserveResult, ActuarialError> {
+        // Input positions of each shard's policies
+        let mut assignments: Vec<Vec<usize>> = vec![Vec::new(); self.shards.len()];
+        for (i, policy) in policies.iter().enumerate() {
+            assignments[self.shard_of(policy)].push(i);
+        }
+
+        let shard_results: Vec<Vec<(usize, Result<ReserveResult, ActuarialError>)>> = self
+            .shards
+            .par_iter()
+            .zip(assignments.par_iter())
+            .map(|(shard, indices)| {
+                let mut calculator = shard.lock().unwrap_or_else(|e| e.into_inner());
+                indices
+                    .iter()
+                    .map(|&i| (i, calculator.calculate_reserve(&policies[i], valuation_month)))
+                    .collect()
+            })
+            .collect();
+
+        let mut slots: Vec<Option<Result<ReserveResult, ActuarialError>>> =
+            std::iter::repeat_with(|| None).take(policies.len()).collect();
+        for (i, result) in shard_results.into_iter().flatten() {
+            slots[i] = Some(result);
+        }
+        let results: Vec<ReserveResult> = slots.into_iter().flatten().collect::<Result<_, _>>()?;
+
+        let segments = aggregate_by_segment(policies, &results, self.segment_by);
+        let mut totals = SegmentTotals { segment: "Total".to_string(), ..Default::default() };
+        for result in &results {
+            totals.add(result);
+        }
+
+        Ok(BatchReserveResult {
+            results,
+            segments,
+            totals,
+            cache_stats: self.cache_stats(),
+        })
+    }
+
+    /// Cache counters summed over all shards
+    pub fn cache_stats(&self) -> CacheStats {
+        let mut stats = CacheStats::default();
+        for shard in &self.shards {
+            let calculator = shard.lock().unwrap_or_else(|e| e.into_inner());
+            stats.merge(&calculator.cache_statistics());
+        }
+        stats
+    }
+
+    /// Clear every shard's cache
+    pub fn clear_cache(&self) {
+        for shard in &self.shards {
+            shard.lock().unwrap_or_else(|e| e.into_inner()).clear_cache();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::{QualStatus, Gender, CreditingStrategy, RollupType};
+    use crate::reserves::{CARVMCalculator, CARVMConfig, CARVMMethod};
+
+    fn test_policies() -> Vec<Policy> {
+        (1..=6)
+            .map(|id| {
+                Policy::new(
+                    id,
+                    if id % 2 == 0 { QualStatus::Q } else { QualStatus::N },
+                    60 + id as u8,
+                    Gender::Male,
+                    130_000.0,
+                    1.0,
+                    100_000.0,
+                    CreditingStrategy::Indexed,
+                    10,
+                    0.0475,
+                    0.01,
+                    0.3,
+                    RollupType::Simple,
+                )
+            })
+            .collect()
+    }
+
+    fn test_config() -> ReserveCalcConfig {
+        ReserveCalcConfig {
+            carvm_config: CARVMConfig {
+                method: CARVMMethod::BruteForce,
+                max_projection_months: 120,
+                max_deferral_years: 5,
+                use_caching: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_batch_matches_serial() {
+        let policies = test_policies();
+        let assumptions = Assumptions::default_pricing();
+        let batch = BatchReserveCalculator::with_shards(assumptions.clone(), &test_config(), 3)
+            .segment_by(SegmentKey::QualStatus);
+        let output = batch.calculate(&policies, 0).unwrap();
+
+        let mut serial = CARVMCalculator::new(assumptions, test_config().carvm_config);
+        for (policy, result) in policies.iter().zip(&output.results) {
+            assert_eq!(result.policy_id, policy.policy_id);
+            let expected = serial.calculate_reserve(policy, 0).unwrap();
+            assert!((result.gross_reserve - expected.gross_reserve).abs() < 1e-9);
+        }
+
+        // N and Q segments add up to the total
+        assert_eq!(output.segments.len(), 2);
+        assert_eq!(output.segments[0].segment, "N");
+        let segment_sum: f64 = output.segments.iter().map(|s| s.gross_reserve).sum();
+        assert!((segment_sum - output.totals.gross_reserve).abs() < 1e-6);
+        assert_eq!(output.totals.policy_count, 6);
+
+        // One full solve per policy, one cached path each
+        assert_eq!(output.cache_stats.misses, 6);
+        assert_eq!(output.cache_stats.entries, 6);
+    }
+
+    #[test]
+    fn test_issue_age_band_labels() {
+        let policies = test_policies();
+        let key = SegmentKey::IssueAgeBand { width: 5 };
+        assert_eq!(key.label(&policies[0]), "60-64");
+        assert_eq!(key.label(&policies[5]), "65-69");
+    }
+}
@@ -9,15 +9,108 @@
 //! for elective vs non-elective benefits per AG33/AG35 requirements.
 
 use crate::assumptions::Assumptions;
-use crate::policy::Policy;
+use crate::error::ActuarialError;
+use crate::policy::{Policy, CreditingStrategy};
 use super::discount::DiscountCurve;
-use super::types::PolicyState;
+use super::types::{PolicyState, InforceSnapshot};
+use super::trace::PathTraceRow;
+
+/// Per-month decrement and discount factors measured from a valuation month
+///
+/// Index `i` is month `valuation_month + i`. The table stops at the same
+/// survival cutoff as the PV loops below, so path values assembled from it
+/// agree with `death_benefit_pv` and `income_benefit_pv`.
+#[derive(Debug, Clone)]
+pub struct DecrementTable {
+    pub valuation_month: u32,
+
+    /// Monthly mortality rate
+    pub mortality: Vec<f64>,
+
+    /// Probability of surviving from valuation to the start of the month
+    pub survival: Vec<f64>,
+
+    /// Death benefit discount factor from valuation
+    pub death_discount: Vec<f64>,
+
+    /// Elective benefit discount factor from valuation
+    pub elective_discount: Vec<f64>,
+
+    /// PV at valuation of 1 per month paid from month i onward (annuity due)
+    pub annuity_due: Vec<f64>,
+}
+
+impl DecrementTable {
+    /// Number of months in the table
+    pub fn len(&self) -> usize {
+        self.mortality.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.mortality.is_empty()
+    }
+
+    /// PV at valuation of the death benefit paid in month index i
+    pub fn death_pv(&self, i: usize, death_benefit: f64) -> f64 {
+        self.survival[i] * self.mortality[i] * death_benefit * self.death_discount[i]
+    }
+}
+
+/// Policy state at the start of a month along the accumulation path
+#[derive(Debug, Clone, Copy)]
+pub struct AccumulationPoint {
+    pub account_value: f64,
+    pub benefit_base: f64,
+
+    /// Guaranteed (nonforfeiture) AV, the floor on the cash surrender value
+    pub guaranteed_av: f64,
+
+    /// PV at valuation of the death benefit paid in this month
+    pub death_benefit_pv: f64,
+
+    /// PV at valuation of the free partial withdrawal taken at the start of this month
+    pub free_pwd_pv: f64,
+}
+
+/// Assumed annual index credits for projecting indexed account values
+///
+/// Replaces guaranteed (MGIR) crediting for indexed policies, e.g. the
+/// credits implied by option costs under AG35. Credits for a policy year's
+/// performance are applied at the start of the next year, as in pricing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexCreditAssumption {
+    /// Policy year in progress at valuation
+    pub current_policy_year: u32,
+
+    /// Credit for the indexing period in progress at valuation
+    pub current_period_rate: f64,
+
+    /// Credit for each later indexing period
+    pub future_rate: f64,
+
+    /// Policy years with the full future credit (halved afterwards, as the option budget is)
+    pub full_rate_years: u32,
+}
+
+impl IndexCreditAssumption {
+    /// Annual credit for a policy year's index performance
+    pub fn annual_rate(&self, policy_year: u32) -> f64 {
+        if policy_year <= self.current_policy_year {
+            self.current_period_rate
+        } else if policy_year <= self.full_rate_years {
+            self.future_rate
+        } else {
+            self.future_rate * 0.5
+        }
+    }
+}
 
 /// Calculator for benefit stream present values
 pub struct BenefitCalculator<'a> {
     assumptions: &'a Assumptions,
     discount_curve: DiscountCurve,
     max_projection_months: u32,
+    index_credits: Option<IndexCreditAssumption>,
 }
 
 impl<'a> BenefitCalculator<'a> {
@@ -31,9 +124,16 @@ impl<'a> BenefitCalculator<'a> {
             assumptions,
             discount_curve,
             max_projection_months,
+            index_credits: None,
         }
     }
 
+    /// Credit indexed policies with assumed index credits instead of MGIR
+    pub fn with_index_credits(mut self, index_credits: IndexCreditAssumption) -> Self {
+        self.index_credits = Some(index_credits);
+        self
+    }
+
     /// Create with policy's valuation rate
     pub fn from_policy(assumptions: &'a Assumptions, policy: &Policy) -> Self {
         Self::new(
@@ -43,6 +143,25 @@ impl<'a> BenefitCalculator<'a> {
         )
     }
 
+    /// Create for a reserve valuation: the configured discount curve (or the
+    /// policy's valuation rate) and any assumed index credits
+    pub fn for_valuation(
+        assumptions: &'a Assumptions,
+        policy: &Policy,
+        discount_curve: Option<&DiscountCurve>,
+        max_projection_months: u32,
+        index_credits: Option<&IndexCreditAssumption>,
+    ) -> Self {
+        Self {
+            assumptions,
+            discount_curve: discount_curve
+                .cloned()
+                .unwrap_or_else(|| DiscountCurve::single_rate(policy.val_rate)),
+            max_projection_months,
+            index_credits: index_credits.cloned(),
+        }
+    }
+
     // ========================================================================
     // DEATH BENEFIT CALCULATIONS (Non-Elective)
     // ========================================================================
@@ -66,6 +185,50 @@ impl<'a> BenefitCalculator<'a> {
         activation_month: Option<u32>,
         starting_av: f64,
         starting_bb: f64,
+    ) -> Result<f64, ActuarialError> {
+        let payout_rate = match activation_month {
+            Some(am) => self.locked_payout_rate(policy, am)?,
+            None => 0.0,
+        };
+
+        Ok(self.death_pv_along_path(
+            policy,
+            valuation_month,
+            activation_month,
+            payout_rate,
+            starting_av,
+            starting_bb,
+        ))
+    }
+
+    /// Calculate PV of death benefits if already in income phase
+    pub fn remaining_death_benefit_pv(
+        &self,
+        policy: &Policy,
+        valuation_month: u32,
+        current_av: f64,
+        current_bb: f64,
+        locked_payout_rate: f64,
+    ) -> f64 {
+        self.death_pv_along_path(
+            policy,
+            valuation_month,
+            Some(valuation_month),
+            locked_payout_rate,
+            current_av,
+            current_bb,
+        )
+    }
+
+    /// Death benefit PV along a path with income withdrawals at `payout_rate`
+    fn death_pv_along_path(
+        &self,
+        policy: &Policy,
+        valuation_month: u32,
+        activation_month: Option<u32>,
+        payout_rate: f64,
+        starting_av: f64,
+        starting_bb: f64,
     ) -> f64 {
         let mut death_pv = 0.0;
         let mut survival_prob = 1.0;
@@ -74,9 +237,8 @@ impl<'a> BenefitCalculator<'a> {
         let mut projected_av = starting_av;
         let mut projected_bb = starting_bb;
 
-        let v_death = self.discount_curve.death_benefit_discount_factor();
-
-        for t in valuation_month..self.max_projection_months {
+        let contract_mortality = self.assumptions.mortality.contract_rates(policy, valuation_month);
+        for (t, q) in (valuation_month..self.max_projection_months).zip(contract_mortality) {
             let months_from_val = t - valuation_month;
 
             // Determine policy state at this month
@@ -87,8 +249,6 @@ impl<'a> BenefitCalculator<'a> {
             };
 
             // Get mortality rate
-            let attained_age = policy.attained_age(t);
-            let q = self.assumptions.mortality.monthly_rate(attained_age, policy.gender, t);
 
             // Calculate death benefit amount for this state
             let db = self.death_benefit_amount(
@@ -100,7 +260,7 @@ impl<'a> BenefitCalculator<'a> {
             );
 
             // PV contribution: survival to t × probability of death × DB × discount
-            death_pv += survival_prob * q * db * v_death.powi(months_from_val as i32);
+            death_pv += survival_prob * q * db * self.discount_curve.discount_to_month_death(months_from_val);
 
             // Update survival probability
             survival_prob *= 1.0 - q;
@@ -110,11 +270,12 @@ impl<'a> BenefitCalculator<'a> {
                 break;
             }
 
-            // Project AV and BB forward (simplified - would use actual projection)
+            // Project AV and BB forward
             self.project_state_forward(
                 policy,
                 t,
                 state,
+                payout_rate,
                 &mut projected_av,
                 &mut projected_bb,
             );
@@ -156,44 +317,41 @@ impl<'a> BenefitCalculator<'a> {
     /// * `policy` - The policy to calculate for
     /// * `valuation_month` - Starting month for discounting
     /// * `activation_month` - Month when income starts
-    /// * `starting_bb` - Benefit base at activation (frozen at that point)
+    /// * `starting_bb` - Benefit base at activation (frozen at that point);
+    ///   see `benefit_base_at_activation`
     pub fn income_benefit_pv(
         &self,
         policy: &Policy,
         valuation_month: u32,
         activation_month: u32,
         starting_bb: f64,
-    ) -> f64 {
+    ) -> Result<f64, ActuarialError> {
         if activation_month < valuation_month {
             // Already past activation - this shouldn't happen in normal use
-            return 0.0;
+            return Ok(0.0);
         }
 
         let mut income_pv = 0.0;
         let mut survival_prob = 1.0;
 
         // Get payout rate at activation age
-        let activation_age = policy.attained_age(activation_month);
-        let payout_rate = self.assumptions.product.glwb.payout_factors.get_single_life(activation_age);
+        let payout_rate = self.locked_payout_rate(policy, activation_month)?;
 
         // Monthly income amount (benefit base × annual payout rate / 12)
         let monthly_income = starting_bb * payout_rate / 12.0;
 
-        let v_elective = self.discount_curve.elective_discount_factor();
-
         // Project forward from valuation month
         // Income payments start at activation_month
-        for t in valuation_month..self.max_projection_months {
+        let contract_mortality = self.assumptions.mortality.contract_rates(policy, valuation_month);
+        for (t, q) in (valuation_month..self.max_projection_months).zip(contract_mortality) {
             let months_from_val = t - valuation_month;
 
             // Get mortality rate
-            let attained_age = policy.attained_age(t);
-            let q = self.assumptions.mortality.monthly_rate(attained_age, policy.gender, t);
 
             // Income only paid after activation
             if t >= activation_month {
                 // Income payment at start of month (annuity due)
-                income_pv += survival_prob * monthly_income * v_elective.powi(months_from_val as i32);
+                income_pv += survival_prob * monthly_income * self.discount_curve.discount_to_month_elective(months_from_val);
             }
 
             // Update survival
@@ -204,7 +362,7 @@ impl<'a> BenefitCalculator<'a> {
             }
         }
 
-        income_pv
+        Ok(income_pv)
     }
 
     /// Calculate PV of income benefits if already in income phase
@@ -219,16 +377,14 @@ impl<'a> BenefitCalculator<'a> {
         let mut survival_prob = 1.0;
 
         let monthly_income = current_bb * locked_payout_rate / 12.0;
-        let v_elective = self.discount_curve.elective_discount_factor();
 
-        for t in valuation_month..self.max_projection_months {
+        let contract_mortality = self.assumptions.mortality.contract_rates(policy, valuation_month);
+        for (t, q) in (valuation_month..self.max_projection_months).zip(contract_mortality) {
             let months_from_val = t - valuation_month;
 
-            let attained_age = policy.attained_age(t);
-            let q = self.assumptions.mortality.monthly_rate(attained_age, policy.gender, t);
 
             // Income payment
-            income_pv += survival_prob * monthly_income * v_elective.powi(months_from_val as i32);
+            income_pv += survival_prob * monthly_income * self.discount_curve.discount_to_month_elective(months_from_val);
 
             survival_prob *= 1.0 - q;
 
@@ -240,88 +396,355 @@ impl<'a> BenefitCalculator<'a> {
         income_pv
     }
 
+    // ========================================================================
+    // PATH TABLES (Dynamic Programming)
+    // ========================================================================
+
+    /// Build decrement and discount tables from the valuation month
+    pub fn decrement_table(&self, policy: &Policy, valuation_month: u32) -> DecrementTable {
+        let mut table = DecrementTable {
+            valuation_month,
+            mortality: Vec::new(),
+            survival: Vec::new(),
+            death_discount: Vec::new(),
+            elective_discount: Vec::new(),
+            annuity_due: Vec::new(),
+        };
+
+        let mut survival_prob = 1.0;
+
+        let contract_mortality = self.assumptions.mortality.contract_rates(policy, valuation_month);
+        for (t, q) in (valuation_month..self.max_projection_months).zip(contract_mortality) {
+            let months_from_val = t - valuation_month;
+
+            table.mortality.push(q);
+            table.survival.push(survival_prob);
+            table.death_discount.push(self.discount_curve.discount_to_month_death(months_from_val));
+            table.elective_discount.push(self.discount_curve.discount_to_month_elective(months_from_val));
+
+            survival_prob *= 1.0 - q;
+
+            if survival_prob < 1e-10 {
+                break;
+            }
+        }
+
+        // Annuity factors accumulate backward from the end of the table
+        let mut annuity = 0.0;
+        table.annuity_due = vec![0.0; table.len()];
+        for i in (0..table.len()).rev() {
+            annuity += table.survival[i] * table.elective_discount[i];
+            table.annuity_due[i] = annuity;
+        }
+
+        table
+    }
+
+    /// Project the never-activate (accumulation) path over the table from
+    /// the in-force values at the valuation month
+    pub fn accumulation_path(
+        &self,
+        policy: &Policy,
+        table: &DecrementTable,
+        inforce: &InforceSnapshot,
+    ) -> Vec<AccumulationPoint> {
+        let starting_gav = self.guaranteed_av(policy, table.valuation_month, inforce);
+        self.free_withdrawal_path(policy, table, inforce.account_value, inforce.benefit_base, starting_gav, 0)
+    }
+
+    /// Guaranteed AV of an in-force snapshot at `month`: the snapshot's own
+    /// value, or the product nonforfeiture value if it has none
+    pub fn guaranteed_av(&self, policy: &Policy, month: u32, inforce: &InforceSnapshot) -> f64 {
+        inforce_guaranteed_av(self.assumptions, policy, month, inforce)
+    }
+
+    /// Project the accumulation path taking the full free partial withdrawal
+    /// at each of the next `free_pwd_years` policy anniversaries
+    ///
+    /// The free amount (including RMDs for qualified contracts) is withdrawn
+    /// from the AV at the start of the policy year and reduces the BB and the
+    /// guaranteed AV pro rata, as in the projection engine. Each point holds
+    /// values after that month's withdrawal.
+    pub fn free_withdrawal_path(
+        &self,
+        policy: &Policy,
+        table: &DecrementTable,
+        starting_av: f64,
+        starting_bb: f64,
+        starting_gav: f64,
+        free_pwd_years: u32,
+    ) -> Vec<AccumulationPoint> {
+        let free_pct = self.assumptions.product.base.free_withdrawal_pct;
+        let mut av = starting_av;
+        let mut bb = starting_bb;
+        let mut gav = starting_gav;
+        let mut withdrawals_taken = 0;
+        let mut path = Vec::with_capacity(table.len());
+
+        for i in 0..table.len() {
+            let t = table.valuation_month + i as u32;
+
+            // Engine projection month t+1 starting a policy year
+            let mut free_pwd_pv = 0.0;
+            if withdrawals_taken < free_pwd_years && policy.month_in_policy_year(t + 1) == 1 {
+                let pct = self.assumptions.pwd.get_fpw_pct(
+                    policy.policy_year(t + 1),
+                    policy.attained_age(t + 1),
+                    policy.qual_status,
+                    free_pct,
+                );
+                if pct > 0.0 && av > 0.0 {
+                    let withdrawal = av * pct;
+                    free_pwd_pv = table.survival[i] * withdrawal * table.elective_discount[i];
+                    av -= withdrawal;
+                    bb *= 1.0 - pct;
+                    gav *= 1.0 - pct;
+                    withdrawals_taken += 1;
+                }
+            }
+
+            let db = self.death_benefit_amount(policy, t, PolicyState::Accumulation, av, bb);
+
+            path.push(AccumulationPoint {
+                account_value: av,
+                benefit_base: bb,
+                guaranteed_av: gav,
+                death_benefit_pv: table.death_pv(i, db),
+                free_pwd_pv,
+            });
+
+            self.project_guaranteed_forward(policy, t, PolicyState::Accumulation, 0.0, bb, &mut gav);
+            self.project_state_forward(policy, t, PolicyState::Accumulation, 0.0, &mut av, &mut bb);
+        }
+
+        path
+    }
+
+    /// PV at valuation of (death, income) benefits when income activates at
+    /// table index `start`, given the AV and BB reached at activation
+    pub fn income_path_pv(
+        &self,
+        policy: &Policy,
+        table: &DecrementTable,
+        start: usize,
+        activation_av: f64,
+        activation_bb: f64,
+    ) -> Result<(f64, f64), ActuarialError> {
+        if start >= table.len() {
+            return Ok((0.0, 0.0));
+        }
+
+        let activation_month = table.valuation_month + start as u32;
+        let payout_rate = self.locked_payout_rate(policy, activation_month)?;
+        let income_pv = activation_bb * payout_rate / 12.0 * table.annuity_due[start];
+
+        let mut av = activation_av;
+        let mut bb = activation_bb;
+        let mut death_pv = 0.0;
+
+        for i in start..table.len() {
+            let t = table.valuation_month + i as u32;
+            let db = self.death_benefit_amount(policy, t, PolicyState::IncomeActive, av, bb);
+            death_pv += table.death_pv(i, db);
+
+            self.project_state_forward(policy, t, PolicyState::IncomeActive, payout_rate, &mut av, &mut bb);
+
+            // Once the AV is exhausted it stays at zero, as does the death benefit
+            if av <= 0.0 {
+                break;
+            }
+        }
+
+        Ok((death_pv, income_pv))
+    }
+
+    /// Roll in-force values forward from `from_month` to `to_month`
+    ///
+    /// Values are per surviving policy (survival is handled separately), so no
+    /// mortality is applied and the snapshot's lives and joint survival are
+    /// kept. Income activates per the policy's GLWB start year. The guaranteed
+    /// AV (the product nonforfeiture value if the snapshot has none) is reduced
+    /// by withdrawals and rider charges as in the engine; the prior month's
+    /// values and YTD withdrawals are tracked for re-seasoning.
+    pub fn roll_forward_inforce(
+        &self,
+        policy: &Policy,
+        inforce: &InforceSnapshot,
+        from_month: u32,
+        to_month: u32,
+    ) -> Result<InforceSnapshot, ActuarialError> {
+        let mut rolled = *inforce;
+        let mut gav = self.guaranteed_av(policy, from_month, inforce);
+
+        for t in from_month..to_month {
+            // Engine activates at the start of projection month t+1
+            if !rolled.income_activated && policy.should_activate_income(t + 1) {
+                rolled.income_activated = true;
+            }
+
+            // Lock in payout rate when income first activates
+            if rolled.income_activated && rolled.locked_payout_rate.is_none() {
+                rolled.locked_payout_rate = Some(self.locked_payout_rate(policy, t + 1)?);
+            }
+
+            let (state, payout_rate) = match rolled.locked_payout_rate {
+                Some(rate) if rolled.income_activated => (PolicyState::IncomeActive, rate),
+                _ => (PolicyState::Accumulation, 0.0),
+            };
+
+            if policy.month_in_policy_year(t + 1) == 1 {
+                rolled.ytd_systematic_wd = 0.0;
+                rolled.ytd_non_systematic_wd = 0.0;
+            }
+            if state == PolicyState::IncomeActive {
+                rolled.ytd_systematic_wd += rolled.benefit_base * payout_rate / 12.0;
+            }
+            rolled.prior_av = Some(rolled.account_value);
+            rolled.prior_benefit_base = Some(rolled.benefit_base);
+
+            self.project_guaranteed_forward(policy, t, state, payout_rate, rolled.benefit_base, &mut gav);
+            self.project_state_forward(
+                policy,
+                t,
+                state,
+                payout_rate,
+                &mut rolled.account_value,
+                &mut rolled.benefit_base,
+            );
+        }
+
+        rolled.guaranteed_av = Some(gav);
+
+        Ok(rolled)
+    }
+
     // ========================================================================
     // SURRENDER VALUE CALCULATIONS (Elective)
     // ========================================================================
 
     /// Calculate cash surrender value at a given month
+    /// Floored at the guaranteed (nonforfeiture) AV, which the caller carries
+    /// net of withdrawals and rider charges
     pub fn cash_surrender_value(
         &self,
         policy: &Policy,
         month: u32,
         account_value: f64,
+        guaranteed_av: f64,
     ) -> f64 {
-        let policy_year = policy.policy_year(month);
-        let sc_rate = self.assumptions.product.base.surrender_charges.get_rate(policy_year);
-
-        account_value * (1.0 - sc_rate)
+        surrender_value(self.assumptions, policy, month, account_value, guaranteed_av)
     }
 
     // ========================================================================
     // HELPER METHODS
     // ========================================================================
 
-    /// Project AV and BB forward by one month (simplified)
+    /// Project AV and BB forward by one month, per surviving policy
     ///
-    /// This is a simplified projection for reserve calculations.
-    /// For full accuracy, would use the actual projection engine.
+    /// Same AV roll-forward as `ProjectionEngine`, crediting the guaranteed rate
+    /// (or the assumed index credits for indexed policies, if set):
+    ///   Pre-decrement AV = MAX(0, AV - SysWD) * (1 + MGIR)^(1/12)
+    ///   EOP AV = Pre-decrement AV * MAX(0, 1 - RiderCharge * BB / AV)
+    /// Moving from month t to t+1 is the engine's projection month t+1.
+    /// Survival is applied by the callers, so no decrements are taken here.
     fn project_state_forward(
         &self,
         policy: &Policy,
         month: u32,
         state: PolicyState,
+        payout_rate: f64,
         av: &mut f64,
         bb: &mut f64,
     ) {
-        let attained_age = policy.attained_age(month);
-        let policy_year = policy.policy_year(month);
-        let month_in_py = policy.month_in_policy_year(month);
-
-        // Mortality decrement
-        let q = self.assumptions.mortality.monthly_rate(attained_age, policy.gender, month);
-
-        // Rider charge (annual, applied at month 12)
-        let rider_charge = if month % 12 == 0 {
-            let rate = if state == PolicyState::IncomeActive {
-                self.assumptions.product.glwb.post_activation_charge
+        let projection_month = month + 1;
+        let income_active = state == PolicyState::IncomeActive;
+        let glwb = &self.assumptions.product.glwb;
+
+        // Rider charge: annual rate on BB, charged in the last month of each policy year
+        let rider_charge_rate = if policy.month_in_policy_year(projection_month) == 12 {
+            if income_active {
+                glwb.post_activation_charge
             } else {
-                self.assumptions.product.glwb.pre_activation_charge
-            };
-            *bb * rate
+                glwb.pre_activation_charge
+            }
         } else {
             0.0
         };
+        let rider_rate = if *av > 0.0 { rider_charge_rate * *bb / *av } else { 0.0 };
+
+        // Systematic withdrawal at the payout rate locked at activation
+        let systematic_wd = if income_active { *bb * payout_rate / 12.0 } else { 0.0 };
+
+        // Guaranteed crediting at MGIR, or the prior year's index credit at month 1
+        let credited_rate = match &self.index_credits {
+            Some(credits) if policy.crediting_strategy == CreditingStrategy::Indexed => {
+                let policy_year = policy.policy_year(projection_month);
+                if policy.month_in_policy_year(projection_month) == 1 && policy_year > 1 {
+                    credits.annual_rate(policy_year - 1).max(0.0)
+                } else {
+                    0.0
+                }
+            }
+            _ => (1.0 + policy.mgir).powf(1.0 / 12.0) - 1.0,
+        };
+        let pre_dec_av = (*av - systematic_wd).max(0.0) * (1.0 + credited_rate);
+        *av = pre_dec_av * (1.0 - rider_rate).max(0.0);
+
+        // BB rolls up during accumulation and is frozen once income starts
+        if !income_active {
+            *bb *= policy.monthly_rollup_factor(projection_month);
+        }
+    }
+
+    /// Project the guaranteed AV forward by one month, per surviving policy
+    ///
+    /// Same as the engine: accumulates at MGIR after the systematic withdrawal
+    /// and bears the rider charge on the BB (taken before this month's step):
+    ///   GAV' = MAX(0, GAV - SysWD) * (1 + MGIR)^(1/12) * MAX(0, 1 - RiderCharge * BB / GAV)
+    fn project_guaranteed_forward(
+        &self,
+        policy: &Policy,
+        month: u32,
+        state: PolicyState,
+        payout_rate: f64,
+        bb: f64,
+        gav: &mut f64,
+    ) {
+        let projection_month = month + 1;
+        let income_active = state == PolicyState::IncomeActive;
+        let glwb = &self.assumptions.product.glwb;
 
-        // Systematic withdrawal if in income
-        let systematic_wd = if state == PolicyState::IncomeActive {
-            let payout_rate = self.assumptions.product.glwb.payout_factors.get_single_life(attained_age);
-            *bb * payout_rate / 12.0
+        let rider_charge_rate = if policy.month_in_policy_year(projection_month) == 12 {
+            if income_active {
+                glwb.post_activation_charge
+            } else {
+                glwb.pre_activation_charge
+            }
         } else {
             0.0
         };
+        let rider_rate = if *gav > 0.0 { rider_charge_rate * bb / *gav } else { 0.0 };
+        let systematic_wd = if income_active { bb * payout_rate / 12.0 } else { 0.0 };
 
-        // Update AV (simplified - ignoring credited interest for conservative estimate)
-        *av = (*av - systematic_wd - rider_charge).max(0.0);
-
-        // Update BB
-        // During accumulation, BB grows via rollup; after income, BB is frozen
-        if state == PolicyState::Accumulation {
-            // Benefit base rollup at month 12 during SC period
-            if month_in_py == 12 && policy_year <= policy.sc_period as u32 {
-                let bb_bonus = self.assumptions.product.glwb.bonus_rate;
-                let rollup_rate = self.assumptions.product.glwb.rollup_rate;
-                let py = (policy_year as f64).min(10.0);
-                let py_prev = ((policy_year - 1) as f64).min(10.0);
-                let rollup_factor = (1.0 + bb_bonus + rollup_rate * py)
-                    / (1.0 + bb_bonus + rollup_rate * py_prev);
-                *bb *= rollup_factor;
-            }
-        }
-        // In income phase, BB is frozen (no changes)
+        let monthly_mgir = (1.0 + policy.mgir).powf(1.0 / 12.0) - 1.0;
+        *gav = (*gav - systematic_wd).max(0.0) * (1.0 + monthly_mgir) * (1.0 - rider_rate).max(0.0);
+    }
 
-        // Apply mortality decrement to both
-        *av *= 1.0 - q;
-        *bb *= 1.0 - q;
+    /// Payout rate locked when income activates at a given month
+    fn locked_payout_rate(&self, policy: &Policy, activation_month: u32) -> Result<f64, ActuarialError> {
+        self.assumptions.product.glwb.payout_factors.for_policy(policy, activation_month)
+    }
+
+    /// Benefit base at activation, rolled up from the valuation month
+    pub fn benefit_base_at_activation(
+        &self,
+        policy: &Policy,
+        valuation_month: u32,
+        activation_month: u32,
+        current_bb: f64,
+    ) -> f64 {
+        (valuation_month..activation_month)
+            .fold(current_bb, |bb, t| bb * policy.monthly_rollup_factor(t + 1))
     }
 
     /// Calculate total reserve for a specific activation path
@@ -334,7 +757,7 @@ impl<'a> BenefitCalculator<'a> {
         activation_month: Option<u32>,
         starting_av: f64,
         starting_bb: f64,
-    ) -> f64 {
+    ) -> Result<f64, ActuarialError> {
         // Death benefit PV (non-elective)
         let death_pv = self.death_benefit_pv(
             policy,
@@ -342,23 +765,132 @@ impl<'a> BenefitCalculator<'a> {
             activation_month,
             starting_av,
             starting_bb,
-        );
+        )?;
 
         // Elective benefit PV
         let elective_pv = if let Some(am) = activation_month {
             // Project BB to activation month, then calculate income PV
-            // Simplified: use starting BB (would need projection for accuracy)
-            self.income_benefit_pv(policy, valuation_month, am, starting_bb)
+            let activation_bb = self.benefit_base_at_activation(policy, valuation_month, am, starting_bb);
+            self.income_benefit_pv(policy, valuation_month, am, activation_bb)?
         } else {
             // Never activate - elective benefit is surrender
             // For CARVM, we test this as one of the paths
             0.0
         };
 
-        death_pv + elective_pv
+        Ok(death_pv + elective_pv)
+    }
+
+    /// Month-by-month benefit stream of a path, for audit traces
+    ///
+    /// `activation` is the activation month and locked payout rate (None =
+    /// never activate). Income is paid on the benefit base rolled up to
+    /// activation, as in `income_benefit_pv`, and the PV contributions sum to
+    /// the path's death benefit PV plus income PV.
+    pub fn path_trace(
+        &self,
+        policy: &Policy,
+        valuation_month: u32,
+        activation: Option<(u32, f64)>,
+        starting_av: f64,
+        starting_bb: f64,
+    ) -> Vec<PathTraceRow> {
+        let payout_rate = activation.map_or(0.0, |(_, rate)| rate);
+        let monthly_income = activation.map_or(0.0, |(am, rate)| {
+            self.benefit_base_at_activation(policy, valuation_month, am, starting_bb) * rate / 12.0
+        });
+
+        let mut rows = Vec::new();
+        let mut survival_prob = 1.0;
+        let mut projected_av = starting_av;
+        let mut projected_bb = starting_bb;
+
+        let contract_mortality = self.assumptions.mortality.contract_rates(policy, valuation_month);
+        for (t, q) in (valuation_month..self.max_projection_months).zip(contract_mortality) {
+            let months_from_val = t - valuation_month;
+
+            let state = if activation.is_some_and(|(am, _)| t >= am) {
+                PolicyState::IncomeActive
+            } else {
+                PolicyState::Accumulation
+            };
+
+            let death_benefit = self.death_benefit_amount(policy, t, state, projected_av, projected_bb);
+            let income_payment = if state == PolicyState::IncomeActive { monthly_income } else { 0.0 };
+            let death_discount = self.discount_curve.discount_to_month_death(months_from_val);
+            let elective_discount = self.discount_curve.discount_to_month_elective(months_from_val);
+
+            rows.push(PathTraceRow {
+                month: t,
+                survival: survival_prob,
+                mortality_rate: q,
+                account_value: projected_av,
+                benefit_base: projected_bb,
+                death_benefit,
+                income_payment,
+                death_discount,
+                elective_discount,
+                pv_contribution: survival_prob * (q * death_benefit * death_discount + income_payment * elective_discount),
+            });
+
+            survival_prob *= 1.0 - q;
+
+            if survival_prob < 1e-10 {
+                break;
+            }
+
+            self.project_state_forward(
+                policy,
+                t,
+                state,
+                payout_rate,
+                &mut projected_av,
+                &mut projected_bb,
+            );
+        }
+
+        rows
     }
 }
 
+/// Guaranteed AV of an in-force snapshot at `month`: the snapshot's own
+/// value, or the product nonforfeiture value if it has none
+pub fn inforce_guaranteed_av(
+    assumptions: &Assumptions,
+    policy: &Policy,
+    month: u32,
+    inforce: &InforceSnapshot,
+) -> f64 {
+    inforce
+        .guaranteed_av
+        .unwrap_or_else(|| assumptions.product.base.policy_nonforfeiture_value(policy, month))
+}
+
+/// Cash surrender value of an in-force snapshot at `month`, floored at its
+/// guaranteed AV
+pub fn inforce_cash_surrender_value(
+    assumptions: &Assumptions,
+    policy: &Policy,
+    month: u32,
+    inforce: &InforceSnapshot,
+) -> f64 {
+    let guaranteed_av = inforce_guaranteed_av(assumptions, policy, month, inforce);
+    surrender_value(assumptions, policy, month, inforce.account_value, guaranteed_av)
+}
+
+/// AV net of the surrender charge for the month's policy year, floored at
+/// the guaranteed AV
+fn surrender_value(
+    assumptions: &Assumptions,
+    policy: &Policy,
+    month: u32,
+    account_value: f64,
+    guaranteed_av: f64,
+) -> f64 {
+    let sc_rate = assumptions.product.base.surrender_charges.get_rate(policy.policy_year(month));
+    (account_value * (1.0 - sc_rate)).max(guaranteed_av)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -408,9 +940,84 @@ mod tests {
         let calc = BenefitCalculator::from_policy(&assumptions, &policy);
 
         // In year 1, SC is typically ~10%
-        let csv = calc.cash_surrender_value(&policy, 1, 100_000.0);
+        let csv = calc.cash_surrender_value(&policy, 1, 100_000.0, 87_500.0);
         // CSV should be less than AV due to surrender charge
         assert!(csv < 100_000.0);
         assert!(csv > 85_000.0); // But not too much less
+
+        // CSV is floored at the guaranteed value (87.5% of premium at MGIR)
+        let guaranteed = policy.initial_premium * 0.875 * 1.01_f64.powf(1.0 / 12.0);
+        let floored = calc.cash_surrender_value(&policy, 1, 50_000.0, guaranteed);
+        assert!((floored - guaranteed).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_accumulation_path_starts_from_inforce_guaranteed_av() {
+        let assumptions = Assumptions::default_pricing();
+        let policy = test_policy();
+        let calc = BenefitCalculator::from_policy(&assumptions, &policy);
+        let table = calc.decrement_table(&policy, 24);
+
+        // Without a snapshot value the product nonforfeiture value applies
+        let mut inforce = InforceSnapshot::from_policy(&policy);
+        let path = calc.accumulation_path(&policy, &table, &inforce);
+        let nonforfeiture = assumptions.product.base.policy_nonforfeiture_value(&policy, 24);
+        assert!((path[0].guaranteed_av - nonforfeiture).abs() < 1e-9);
+
+        // A guaranteed AV already reduced by withdrawals is carried as is
+        inforce.guaranteed_av = Some(60_000.0);
+        let path = calc.accumulation_path(&policy, &table, &inforce);
+        assert_eq!(path[0].guaranteed_av, 60_000.0);
+        assert!(path[1].guaranteed_av > 60_000.0 && path[1].guaranteed_av < nonforfeiture);
+    }
+
+    #[test]
+    fn test_state_projection_matches_engine() {
+        use crate::projection::{ProjectionEngine, ProjectionConfig, CreditingApproach};
+
+        let assumptions = Assumptions::default_pricing();
+
+        for income_activated in [false, true] {
+            let mut policy = test_policy();
+            policy.income_activated = income_activated;
+            policy.crediting_strategy = CreditingStrategy::Fixed;
+
+            // Engine credits 0% floored at MGIR with no lapses; policy year 1 has no PWDs
+            let config = ProjectionConfig {
+                projection_months: 12,
+                crediting: CreditingApproach::Fixed(0.0),
+                fixed_lapse_rate: Some(0.0),
+                hedge_params: None,
+                ..Default::default()
+            };
+            let result = ProjectionEngine::new(assumptions.clone(), config).project_policy(&policy).unwrap();
+
+            let calc = BenefitCalculator::from_policy(&assumptions, &policy);
+            let start = InforceSnapshot::from_policy(&policy);
+
+            for row in &result.cashflows {
+                let rolled = calc.roll_forward_inforce(&policy, &start, 0, row.projection_month).unwrap();
+
+                // Reserve values are per survivor, engine values are per in-force
+                let inforce_av = rolled.account_value * row.lives / policy.initial_pols;
+                assert!(
+                    (inforce_av - row.eop_av).abs() < 1e-6 * row.eop_av,
+                    "Month {}: reserve AV {} vs engine AV {}",
+                    row.projection_month,
+                    inforce_av,
+                    row.eop_av
+                );
+
+                // Guaranteed AV bears the same withdrawals and rider charges
+                let inforce_gav = rolled.guaranteed_av.unwrap() * row.lives / policy.initial_pols;
+                assert!(
+                    (inforce_gav - row.guaranteed_av).abs() < 1e-6 * row.guaranteed_av,
+                    "Month {}: reserve guaranteed AV {} vs engine {}",
+                    row.projection_month,
+                    inforce_gav,
+                    row.guaranteed_av
+                );
+            }
+        }
     }
 }
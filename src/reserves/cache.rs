@@ -8,9 +8,35 @@
 //! - Always: CSV is a floor
 //!
 //! This can provide ~30x speedup for monthly reserve calculations.
+//!
+//! Cached paths can be saved to a JSON file and loaded in a later run. The file
+//! is stamped with a fingerprint of the assumptions and each entry with a
+//! fingerprint of its policy record, so stale entries are dropped automatically.
+
+use std::collections::HashMap;
+use std::fmt::{self, Debug};
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter};
+use std::path::Path;
 
 use serde::{Deserialize, Serialize};
 
+use crate::error::ActuarialError;
+
+/// Version of the persisted cache file layout
+const CACHE_FILE_VERSION: u32 = 1;
+
+/// Stable 64-bit fingerprint (FNV-1a) of a value's Debug representation
+///
+/// Unlike `DefaultHasher`, the result is the same across runs and builds.
+pub fn fingerprint<T: Debug + ?Sized>(value: &T) -> u64 {
+    format!("{:?}", value)
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+        })
+}
+
 /// Cached optimal path information for efficient roll-forward
 ///
 /// Stores the results of a full CARVM solve so that subsequent valuations
@@ -59,6 +85,10 @@ pub struct CachedReservePath {
 
     /// Remaining free withdrawal amount at solve time
     pub remaining_free_amount_at_solve: f64,
+
+    /// AV the solve expects by months since the solve (index 0 = solve month)
+    #[serde(default)]
+    pub expected_av: Vec<f64>,
 }
 
 impl CachedReservePath {
@@ -87,9 +117,24 @@ impl CachedReservePath {
             death_benefit_pv_remaining: death_pv,
             optimal_pwd_schedule: None,
             remaining_free_amount_at_solve: av * 0.10, // Typical 10% free
+            expected_av: Vec::new(),
         }
     }
 
+    /// Attach the AV path the solve expects from the solve month
+    pub fn with_expected_av(mut self, expected_av: Vec<f64>) -> Self {
+        self.expected_av = expected_av;
+        self
+    }
+
+    /// AV expected at a month, if the cached path covers it
+    pub fn expected_av_at(&self, current_month: u32) -> Option<f64> {
+        if current_month < self.solve_month {
+            return None;
+        }
+        self.expected_av.get((current_month - self.solve_month) as usize).copied()
+    }
+
     /// Check if the cache is still potentially valid for the given month
     pub fn is_potentially_valid(&self, current_month: u32) -> bool {
         // Cache is only valid for months after the solve month
@@ -113,6 +158,64 @@ impl CachedReservePath {
     }
 }
 
+/// Why a cached path cannot be rolled forward
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RevalidationReason {
+    /// Periodic re-solve is due
+    Periodic { months_elapsed: u32 },
+
+    /// ITM (BB/AV) moved by more than the threshold since the solve
+    ItmChange { change: f64, threshold: f64 },
+
+    /// Valuation is within the threshold of the optimal activation month
+    ApproachingActivation { months_to_activation: u32, threshold_months: u32 },
+
+    /// AV deviates from the AV the cached path expected at this month
+    AvDeviation { expected_av: f64, current_av: f64, deviation: f64, threshold: f64 },
+
+    /// The surrender charge rate changed since the solve
+    SurrenderChargeBoundary { rate_at_solve: f64, current_rate: f64 },
+
+    /// In-force state the roll-forward does not handle
+    UnexpectedState,
+}
+
+impl fmt::Display for RevalidationReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RevalidationReason::Periodic { months_elapsed } => {
+                write!(f, "Periodic revalidation: {} months since last solve", months_elapsed)
+            }
+            RevalidationReason::ItmChange { change, threshold } => write!(
+                f,
+                "ITM changed by {:.1}% (threshold: {:.1}%)",
+                change * 100.0,
+                threshold * 100.0
+            ),
+            RevalidationReason::ApproachingActivation { months_to_activation, threshold_months } => write!(
+                f,
+                "{} months to optimal activation (threshold: {} months)",
+                months_to_activation, threshold_months
+            ),
+            RevalidationReason::AvDeviation { expected_av, current_av, deviation, threshold } => write!(
+                f,
+                "AV {:.2} deviates {:.1}% from expected {:.2} (threshold: {:.1}%)",
+                current_av,
+                deviation * 100.0,
+                expected_av,
+                threshold * 100.0
+            ),
+            RevalidationReason::SurrenderChargeBoundary { rate_at_solve, current_rate } => write!(
+                f,
+                "Surrender charge changed from {:.2}% to {:.2}%",
+                rate_at_solve * 100.0,
+                current_rate * 100.0
+            ),
+            RevalidationReason::UnexpectedState => write!(f, "Unexpected state in roll forward"),
+        }
+    }
+}
+
 /// Result of attempting to roll forward a cached reserve
 #[derive(Debug, Clone)]
 pub enum RollForwardResult {
@@ -126,13 +229,13 @@ pub enum RollForwardResult {
         still_valid: bool,
 
         /// Reason for any validation concerns
-        validation_notes: Option<String>,
+        validation_notes: Option<RevalidationReason>,
     },
 
     /// Could not roll forward, need full re-solve
     NeedsResolve {
         /// Reason why roll-forward failed
-        reason: String,
+        reason: RevalidationReason,
     },
 }
 
@@ -148,10 +251,11 @@ pub struct RevalidationCriteria {
     /// Re-solve if within N months of optimal activation
     pub activation_proximity_months: u32,
 
-    /// Re-solve if AV changed by more than this fraction from expected
+    /// Re-solve if AV deviates by more than this fraction from the AV the
+    /// cached path expected (AV at solve time if the path does not cover the month)
     pub av_deviation_threshold: f64,
 
-    /// Re-solve if surrender charge period boundary crossed
+    /// Re-solve if the surrender charge rate changed since the solve
     pub check_sc_boundaries: bool,
 }
 
@@ -175,15 +279,12 @@ impl RevalidationCriteria {
         current_month: u32,
         current_av: f64,
         current_bb: f64,
-        _current_sc_period: u32,
-    ) -> Option<String> {
+        current_sc_rate: f64,
+    ) -> Option<RevalidationReason> {
         // 1. Periodic revalidation
         let months_elapsed = current_month.saturating_sub(cached.solve_month);
         if months_elapsed >= self.periodic_revalidation_months {
-            return Some(format!(
-                "Periodic revalidation: {} months since last solve",
-                months_elapsed
-            ));
+            return Some(RevalidationReason::Periodic { months_elapsed });
         }
 
         // 2. ITM change
@@ -194,44 +295,99 @@ impl RevalidationCriteria {
         };
         let itm_change = (current_itm - cached.itm_at_solve).abs() / cached.itm_at_solve.max(0.01);
         if itm_change > self.itm_change_threshold {
-            return Some(format!(
-                "ITM changed by {:.1}% (threshold: {:.1}%)",
-                itm_change * 100.0,
-                self.itm_change_threshold * 100.0
-            ));
+            return Some(RevalidationReason::ItmChange {
+                change: itm_change,
+                threshold: self.itm_change_threshold,
+            });
         }
 
         // 3. Approaching optimal activation
         if cached.approaching_activation(current_month, self.activation_proximity_months) {
-            return Some(format!(
-                "Within {} months of optimal activation",
-                self.activation_proximity_months
-            ));
+            return Some(RevalidationReason::ApproachingActivation {
+                months_to_activation: cached.optimal_activation_month.saturating_sub(current_month),
+                threshold_months: self.activation_proximity_months,
+            });
         }
 
-        // 4. AV deviation from expected
-        // (This would require projecting expected AV, simplified here)
-        let av_change = (current_av - cached.av_at_solve).abs() / cached.av_at_solve.max(1.0);
-        if av_change > self.av_deviation_threshold {
-            return Some(format!(
-                "AV changed by {:.1}% from solve time",
-                av_change * 100.0
-            ));
+        // 4. AV deviation from the AV the cached path expected at this month
+        let expected_av = cached.expected_av_at(current_month).unwrap_or(cached.av_at_solve);
+        let deviation = (current_av - expected_av).abs() / expected_av.max(1.0);
+        if deviation > self.av_deviation_threshold {
+            return Some(RevalidationReason::AvDeviation {
+                expected_av,
+                current_av,
+                deviation,
+                threshold: self.av_deviation_threshold,
+            });
         }
 
-        // 5. Surrender charge boundary (would need to track policy year)
-        // Simplified: check if SC rate changed significantly
-        // This would be implemented with actual SC lookup
+        // 5. Surrender charge boundary: the rate changes at policy anniversaries
+        if self.check_sc_boundaries && (current_sc_rate - cached.sc_rate_at_solve).abs() > 1e-12 {
+            return Some(RevalidationReason::SurrenderChargeBoundary {
+                rate_at_solve: cached.sc_rate_at_solve,
+                current_rate: current_sc_rate,
+            });
+        }
 
         None // No revalidation needed
     }
 }
 
+/// Cache entry as written to disk
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedEntry {
+    policy_fingerprint: u64,
+    path: CachedReservePath,
+}
+
+/// Cache file contents
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedCache {
+    version: u32,
+    assumption_fingerprint: u64,
+    entries: Vec<PersistedEntry>,
+}
+
+/// Cache counters, summable across calculators
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct CacheStats {
+    /// Cached paths held
+    pub entries: u64,
+    pub hits: u64,
+    pub misses: u64,
+    pub revalidations: u64,
+    pub invalidations: u64,
+}
+
+impl CacheStats {
+    /// Add another calculator's counters
+    pub fn merge(&mut self, other: &CacheStats) {
+        self.entries += other.entries;
+        self.hits += other.hits;
+        self.misses += other.misses;
+        self.revalidations += other.revalidations;
+        self.invalidations += other.invalidations;
+    }
+
+    /// Share of lookups served from the cache
+    pub fn hit_rate(&self) -> f64 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            0.0
+        } else {
+            self.hits as f64 / total as f64
+        }
+    }
+}
+
 /// Cache manager for multiple policies
 #[derive(Debug, Default)]
 pub struct ReserveCache {
     /// Cached paths by policy ID
-    entries: std::collections::HashMap<u64, CachedReservePath>,
+    entries: HashMap<u64, CachedReservePath>,
+
+    /// Fingerprint of the policy record each path was solved for
+    policy_fingerprints: HashMap<u64, u64>,
 
     /// Revalidation criteria
     criteria: RevalidationCriteria,
@@ -240,6 +396,10 @@ pub struct ReserveCache {
     pub cache_hits: u64,
     pub cache_misses: u64,
     pub revalidations: u64,
+    pub invalidations: u64,
+
+    /// Why the most recent revalidation was triggered
+    pub last_revalidation: Option<RevalidationReason>,
 }
 
 impl ReserveCache {
@@ -261,22 +421,100 @@ impl ReserveCache {
         self.entries.get(&policy_id)
     }
 
+    /// Get cached path for a policy if it was solved for the same policy record
+    /// A path solved for a different record is dropped
+    pub fn get_for_policy(&mut self, policy_id: u64, policy_fingerprint: u64) -> Option<&CachedReservePath> {
+        let stale = self
+            .policy_fingerprints
+            .get(&policy_id)
+            .is_some_and(|&stored| stored != policy_fingerprint);
+        if stale {
+            self.remove(policy_id);
+            self.invalidations += 1;
+        }
+        self.entries.get(&policy_id)
+    }
+
     /// Store a cached path for a policy
     pub fn insert(&mut self, path: CachedReservePath) {
+        self.policy_fingerprints.remove(&path.policy_id);
+        self.entries.insert(path.policy_id, path);
+    }
+
+    /// Store a cached path with the fingerprint of the policy record it was solved for
+    pub fn insert_for_policy(&mut self, path: CachedReservePath, policy_fingerprint: u64) {
+        self.policy_fingerprints.insert(path.policy_id, policy_fingerprint);
         self.entries.insert(path.policy_id, path);
     }
 
     /// Remove cached path for a policy
     pub fn remove(&mut self, policy_id: u64) -> Option<CachedReservePath> {
+        self.policy_fingerprints.remove(&policy_id);
         self.entries.remove(&policy_id)
     }
 
     /// Clear all cached data
     pub fn clear(&mut self) {
         self.entries.clear();
+        self.policy_fingerprints.clear();
         self.cache_hits = 0;
         self.cache_misses = 0;
         self.revalidations = 0;
+        self.invalidations = 0;
+        self.last_revalidation = None;
+    }
+
+    /// Save entries with a policy fingerprint to a JSON file
+    ///
+    /// The file is written next to the target and renamed into place, so an
+    /// interrupted save never leaves a truncated cache behind.
+    pub fn save(&self, path: &Path, assumption_fingerprint: u64) -> Result<(), ActuarialError> {
+        let mut entries: Vec<PersistedEntry> = self
+            .policy_fingerprints
+            .iter()
+            .filter_map(|(policy_id, &policy_fingerprint)| {
+                self.entries.get(policy_id).map(|path| PersistedEntry {
+                    policy_fingerprint,
+                    path: path.clone(),
+                })
+            })
+            .collect();
+        entries.sort_by_key(|e| e.path.policy_id);
+
+        let persisted = PersistedCache {
+            version: CACHE_FILE_VERSION,
+            assumption_fingerprint,
+            entries,
+        };
+
+        let tmp_path = path.with_extension("tmp");
+        let file = File::create(&tmp_path).map_err(|e| cache_file_error("create", &tmp_path, e))?;
+        serde_json::to_writer(BufWriter::new(file), &persisted)?;
+        std::fs::rename(&tmp_path, path).map_err(|e| cache_file_error("replace", path, e))?;
+        Ok(())
+    }
+
+    /// Load entries saved under the same assumption fingerprint
+    ///
+    /// Returns the number of entries loaded: 0 when the file does not exist or
+    /// was written under different assumptions or an older layout.
+    pub fn load(&mut self, path: &Path, assumption_fingerprint: u64) -> Result<usize, ActuarialError> {
+        if !path.exists() {
+            return Ok(0);
+        }
+
+        let file = File::open(path).map_err(|e| cache_file_error("open", path, e))?;
+        let persisted: PersistedCache = serde_json::from_reader(BufReader::new(file))?;
+
+        if persisted.version != CACHE_FILE_VERSION || persisted.assumption_fingerprint != assumption_fingerprint {
+            return Ok(0);
+        }
+
+        let count = persisted.entries.len();
+        for entry in persisted.entries {
+            self.insert_for_policy(entry.path, entry.policy_fingerprint);
+        }
+        Ok(count)
     }
 
     /// Get number of cached entries
@@ -299,9 +537,21 @@ impl ReserveCache {
         self.cache_misses += 1;
     }
 
-    /// Record a revalidation
-    pub fn record_revalidation(&mut self) {
+    /// Record a revalidation and its reason
+    pub fn record_revalidation(&mut self, reason: RevalidationReason) {
         self.revalidations += 1;
+        self.last_revalidation = Some(reason);
+    }
+
+    /// Snapshot of the cache counters
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            entries: self.entries.len() as u64,
+            hits: self.cache_hits,
+            misses: self.cache_misses,
+            revalidations: self.revalidations,
+            invalidations: self.invalidations,
+        }
     }
 
     /// Get cache hit rate
@@ -315,6 +565,14 @@ impl ReserveCache {
     }
 }
 
+/// I/O error on a cache file, naming the file
+fn cache_file_error(action: &str, path: &Path, error: io::Error) -> ActuarialError {
+    ActuarialError::Io(io::Error::new(
+        error.kind(),
+        format!("cannot {} cache file {}: {}", action, path.display(), error),
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -358,16 +616,54 @@ mod tests {
         let cache = CachedReservePath::new(1, 0, 96, 50000.0, 100000.0, 130000.0, 1000.0, 5000.0, 0.08);
 
         // Should trigger periodic revalidation
-        assert!(criteria
-            .needs_revalidation(&cache, 13, 100000.0, 130000.0, 10)
-            .is_some());
+        assert_eq!(
+            criteria.needs_revalidation(&cache, 13, 100000.0, 130000.0, 0.08),
+            Some(RevalidationReason::Periodic { months_elapsed: 13 })
+        );
 
         // Should not trigger at month 6
         assert!(criteria
-            .needs_revalidation(&cache, 6, 100000.0, 130000.0, 10)
+            .needs_revalidation(&cache, 6, 100000.0, 130000.0, 0.08)
             .is_none());
     }
 
+    #[test]
+    fn test_surrender_charge_boundary() {
+        let criteria = RevalidationCriteria::default();
+        let cache = CachedReservePath::new(1, 0, 96, 50000.0, 100000.0, 130000.0, 1000.0, 5000.0, 0.08);
+
+        let reason = criteria.needs_revalidation(&cache, 6, 100000.0, 130000.0, 0.07);
+        assert_eq!(
+            reason,
+            Some(RevalidationReason::SurrenderChargeBoundary { rate_at_solve: 0.08, current_rate: 0.07 })
+        );
+        assert_eq!(reason.unwrap().to_string(), "Surrender charge changed from 8.00% to 7.00%");
+
+        let unchecked = RevalidationCriteria { check_sc_boundaries: false, ..Default::default() };
+        assert!(unchecked.needs_revalidation(&cache, 6, 100000.0, 130000.0, 0.07).is_none());
+    }
+
+    #[test]
+    fn test_expected_av_deviation() {
+        let criteria = RevalidationCriteria::default();
+        let expected: Vec<f64> = (0..=12).map(|m| 100000.0 * (1.0 - 0.02 * m as f64)).collect();
+        let cache = CachedReservePath::new(1, 0, 96, 50000.0, 100000.0, 130000.0, 1000.0, 5000.0, 0.08)
+            .with_expected_av(expected);
+
+        // 80% of the solve-time AV is on the expected path at month 10
+        assert_eq!(cache.expected_av_at(10), Some(80000.0));
+        assert!(criteria.needs_revalidation(&cache, 10, 80000.0, 104000.0, 0.08).is_none());
+
+        // Same AV as at solve time is 25% above expectation
+        match criteria.needs_revalidation(&cache, 10, 100000.0, 130000.0, 0.08) {
+            Some(RevalidationReason::AvDeviation { expected_av, deviation, .. }) => {
+                assert_eq!(expected_av, 80000.0);
+                assert!((deviation - 0.25).abs() < 1e-12);
+            }
+            other => panic!("expected AV deviation, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_reserve_cache() {
         let mut cache = ReserveCache::new();
@@ -382,4 +678,19 @@ mod tests {
         cache.clear();
         assert!(cache.is_empty());
     }
+
+    #[test]
+    fn test_policy_fingerprint_invalidation() {
+        assert_eq!(fingerprint(&1.5_f64), fingerprint(&1.5_f64));
+        assert_ne!(fingerprint(&1.5_f64), fingerprint(&1.25_f64));
+
+        let mut cache = ReserveCache::new();
+        let path = CachedReservePath::new(7, 0, 96, 50000.0, 100000.0, 130000.0, 1000.0, 5000.0, 0.08);
+        cache.insert_for_policy(path, 42);
+
+        assert!(cache.get_for_policy(7, 42).is_some());
+        assert!(cache.get_for_policy(7, 43).is_none());
+        assert!(cache.is_empty());
+        assert_eq!(cache.invalidations, 1);
+    }
 }
@@ -7,22 +7,31 @@
 //! # Algorithm Options
 //!
 //! - **Brute Force**: O(T × N) - Tests all activation times, guaranteed correct
-//! - **Dynamic Programming**: O(N) - Faster but more complex
-//! - **Hybrid**: DP with brute-force validation for a subset
+//! - **Dynamic Programming**: Backward induction over (month, policy state).
+//!   One pass builds the accumulation path; each income leg reuses the shared
+//!   decrement tables and stops once the AV is exhausted.
+//! - **Hybrid**: DP with brute-force validation for a sample of policies
 //!
 //! # Caching
 //!
 //! Uses roll-forward caching for efficient multi-timestep calculations:
 //! - Full solve at t=0 determines optimal activation time T*
 //! - Subsequent reserves roll forward until T* or revalidation trigger
+//! - Solves can be saved and reloaded across runs (`save_cache` / `load_cache`)
+
+use std::path::Path;
 
 use crate::assumptions::Assumptions;
+use crate::error::ActuarialError;
 use crate::policy::Policy;
 
-use super::types::{ReserveResult, ReserveComponents, ReserveMethod};
+use super::types::{ReserveResult, ReserveComponents, ReserveMethod, InforceSnapshot};
 use super::discount::DiscountCurve;
-use super::benefits::BenefitCalculator;
-use super::cache::{CachedReservePath, RollForwardResult, ReserveCache, RevalidationCriteria};
+use super::benefits::{inforce_cash_surrender_value, BenefitCalculator, IndexCreditAssumption};
+use super::trace::{ActivationTest, ReserveTrace};
+use super::cache::{
+    CachedReservePath, RollForwardResult, ReserveCache, RevalidationCriteria, RevalidationReason, CacheStats, fingerprint,
+};
 use super::ReserveCalculator;
 
 /// CARVM calculation method
@@ -31,7 +40,7 @@ pub enum CARVMMethod {
     /// Test all possible activation times - O(T × N), guaranteed correct
     BruteForce,
 
-    /// Dynamic programming - O(N), faster but more complex
+    /// Dynamic programming - backward induction over (month, policy state)
     DynamicProgramming,
 
     /// DP with periodic brute-force validation
@@ -65,6 +74,21 @@ pub struct CARVMConfig {
     /// Maximum deferral period to test (in years)
     /// Limits brute force search space
     pub max_deferral_years: u32,
+
+    /// Hybrid method: validate DP against brute force for 1 in N policies
+    /// (sampled by policy id). 0 disables validation.
+    pub hybrid_validation_interval: u32,
+
+    /// Relative reserve difference above which DP and brute force disagree
+    pub hybrid_tolerance: f64,
+
+    /// Discount curve for all policies (e.g. Treasury + spread)
+    /// None = each policy's valuation rate
+    pub discount_curve: Option<DiscountCurve>,
+
+    /// Attach a detailed trace (winning path and every tested activation
+    /// month) to each result. Traced results are always fully solved.
+    pub detailed_trace: bool,
 }
 
 impl Default for CARVMConfig {
@@ -76,10 +100,42 @@ impl Default for CARVMConfig {
             revalidation_frequency: 12,
             revalidation_criteria: RevalidationCriteria::default(),
             max_deferral_years: 30,
+            hybrid_validation_interval: 100,
+            hybrid_tolerance: 1e-6,
+            discount_curve: None,
+            detailed_trace: false,
         }
     }
 }
 
+/// A policy where the DP and brute-force solvers disagreed
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolverDiscrepancy {
+    pub policy_id: u32,
+    pub valuation_month: u32,
+    pub dp_activation_month: u32,
+    pub brute_force_activation_month: u32,
+    pub dp_reserve: f64,
+    pub brute_force_reserve: f64,
+}
+
+impl SolverDiscrepancy {
+    /// Reserve difference relative to the brute-force reserve
+    pub fn relative_difference(&self) -> f64 {
+        (self.dp_reserve - self.brute_force_reserve).abs() / self.brute_force_reserve.abs().max(1.0)
+    }
+}
+
+/// Hybrid method cross-validation results
+#[derive(Debug, Clone, Default)]
+pub struct HybridValidationStats {
+    /// Number of solves checked against brute force
+    pub validations: u64,
+
+    /// Solves where DP and brute force disagreed (brute force result was used)
+    pub discrepancies: Vec<SolverDiscrepancy>,
+}
+
 /// Main CARVM calculator
 ///
 /// Calculates CARVM reserves using the configured method, with optional
@@ -88,6 +144,8 @@ pub struct CARVMCalculator {
     assumptions: Assumptions,
     config: CARVMConfig,
     cache: ReserveCache,
+    validation: HybridValidationStats,
+    index_credits: Option<IndexCreditAssumption>,
 }
 
 impl CARVMCalculator {
@@ -98,6 +156,8 @@ impl CARVMCalculator {
             assumptions,
             config,
             cache,
+            validation: HybridValidationStats::default(),
+            index_credits: None,
         }
     }
 
@@ -121,6 +181,74 @@ impl CARVMCalculator {
         (self.cache.cache_hits, self.cache.cache_misses, self.cache.hit_rate())
     }
 
+    /// Fingerprint of everything a cached solve depends on besides the policy:
+    /// assumptions, method, horizon and discount curve
+    pub fn assumption_fingerprint(&self) -> u64 {
+        fingerprint(&(
+            &self.assumptions,
+            self.config.method,
+            self.config.max_projection_months,
+            self.config.max_deferral_years,
+            &self.config.discount_curve,
+        ))
+    }
+
+    /// Fingerprint of a policy's contract terms (with any assumed index credits)
+    ///
+    /// The valuation-date values (duration, AV, benefit base, guaranteed AV,
+    /// prior and YTD values) move every valuation, so they are left out and
+    /// drift is caught by the revalidation criteria instead.
+    fn policy_fingerprint(&self, policy: &Policy) -> u64 {
+        let terms = Policy {
+            duration_months: 0,
+            current_av: None,
+            current_benefit_base: None,
+            current_guaranteed_av: None,
+            prior_av: None,
+            prior_benefit_base: None,
+            ytd_systematic_wd: 0.0,
+            ytd_non_systematic_wd: 0.0,
+            ..policy.clone()
+        };
+        fingerprint(&(terms, &self.index_credits))
+    }
+
+    /// Warm-start the cache from a file saved by an earlier run
+    ///
+    /// Entries saved under different assumptions are ignored, and entries for a
+    /// policy whose contract terms have since changed are dropped on first use.
+    /// Returns the number of entries loaded.
+    pub fn load_cache(&mut self, path: &Path) -> Result<usize, ActuarialError> {
+        let assumption_fingerprint = self.assumption_fingerprint();
+        self.cache.load(path, assumption_fingerprint)
+    }
+
+    /// Save the cache for a later run
+    pub fn save_cache(&self, path: &Path) -> Result<(), ActuarialError> {
+        self.cache.save(path, self.assumption_fingerprint())
+    }
+
+    /// Number of cached solves (e.g. after `load_cache`)
+    pub fn cached_policies(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Project indexed account values with assumed index credits instead of
+    /// guaranteed crediting (used by AG35)
+    pub fn set_index_credits(&mut self, index_credits: Option<IndexCreditAssumption>) {
+        self.index_credits = index_credits;
+    }
+
+    /// Assumed index credits, if any
+    pub fn index_credits(&self) -> Option<&IndexCreditAssumption> {
+        self.index_credits.as_ref()
+    }
+
+    /// Get hybrid DP vs brute-force validation results
+    pub fn validation_stats(&self) -> &HybridValidationStats {
+        &self.validation
+    }
+
     // ========================================================================
     // MAIN CALCULATION
     // ========================================================================
@@ -130,40 +258,49 @@ impl CARVMCalculator {
         &mut self,
         policy: &Policy,
         valuation_month: u32,
-    ) -> ReserveResult {
+        inforce: &InforceSnapshot,
+    ) -> Result<ReserveResult, ActuarialError> {
+        // Contract mortality and the joint payout start from the snapshot's lives
+        let policy = &inforce.policy_with_survival(policy);
         let policy_id = policy.policy_id as u64;
-
-        // Get current state for validation
-        let current_av = self.get_av_at_month(policy, valuation_month);
-        let current_bb = self.get_bb_at_month(policy, valuation_month);
-        let current_sc_period = policy.sc_period as u32;
-
-        // Try to use cache
-        if self.config.use_caching {
+        let policy_fingerprint = self.policy_fingerprint(policy);
+
+        // Current in-force state for validation
+        let current_av = inforce.account_value;
+        let current_bb = inforce.benefit_base;
+        let current_sc_rate = self
+            .assumptions
+            .product
+            .base
+            .surrender_charges
+            .get_rate(policy.policy_year(valuation_month));
+
+        // Try to use cache (traces need a full solve)
+        if self.config.use_caching && !self.config.detailed_trace {
             // Clone cached data to avoid borrow issues
-            let cached_data = self.cache.get(policy_id).cloned();
+            let cached_data = self.cache.get_for_policy(policy_id, policy_fingerprint).cloned();
 
             if let Some(cached) = cached_data {
                 // Check if revalidation is needed
-                if let Some(_reason) = self.config.revalidation_criteria.needs_revalidation(
+                if let Some(reason) = self.config.revalidation_criteria.needs_revalidation(
                     &cached,
                     valuation_month,
                     current_av,
                     current_bb,
-                    current_sc_period,
+                    current_sc_rate,
                 ) {
-                    self.cache.record_revalidation();
+                    self.cache.record_revalidation(reason);
                     // Fall through to full solve
                 } else {
                     // Try roll forward
-                    match self.try_roll_forward(policy, valuation_month, cached.clone()) {
+                    match self.try_roll_forward(policy, valuation_month, cached.clone(), inforce)? {
                         RollForwardResult::Success { reserve, .. } => {
                             self.cache.record_hit();
 
-                            let csv = self.cash_surrender_value(policy, valuation_month, current_av);
+                            let csv = self.cash_surrender_value(policy, valuation_month, inforce);
                             let final_reserve = reserve.max(csv);
 
-                            return ReserveResult {
+                            return Ok(ReserveResult {
                                 policy_id: policy.policy_id,
                                 valuation_date: valuation_month,
                                 gross_reserve: final_reserve,
@@ -179,7 +316,10 @@ impl CARVMCalculator {
                                 method: ReserveMethod::CARVM,
                                 from_cache: true,
                                 csv_at_valuation: csv,
-                            };
+                                benefit_stream: None,
+                                trace: None,
+                                guaranteed_benefit: None,
+                            });
                         }
                         RollForwardResult::NeedsResolve { .. } => {
                             self.cache.record_miss();
@@ -193,7 +333,7 @@ impl CARVMCalculator {
         }
 
         // Full solve
-        self.full_solve_and_cache(policy, valuation_month, current_av, current_bb)
+        self.full_solve_and_cache(policy, valuation_month, inforce)
     }
 
     /// Perform full CARVM optimization and cache result
@@ -201,23 +341,32 @@ impl CARVMCalculator {
         &mut self,
         policy: &Policy,
         valuation_month: u32,
-        current_av: f64,
-        current_bb: f64,
-    ) -> ReserveResult {
-        let (optimal_month, reserve, components) = match self.config.method {
-            CARVMMethod::BruteForce => self.brute_force_solve(policy, valuation_month, current_av, current_bb),
-            CARVMMethod::DynamicProgramming => self.dp_solve(policy, valuation_month, current_av, current_bb),
-            CARVMMethod::Hybrid => self.hybrid_solve(policy, valuation_month, current_av, current_bb),
+        inforce: &InforceSnapshot,
+    ) -> Result<ReserveResult, ActuarialError> {
+        let current_av = inforce.account_value;
+        let current_bb = inforce.benefit_base;
+
+        let (optimal_month, reserve, components) = if inforce.income_activated {
+            self.income_active_solve(policy, valuation_month, inforce)?
+        } else {
+            match self.config.method {
+                CARVMMethod::BruteForce => self.brute_force_solve(policy, valuation_month, inforce),
+                CARVMMethod::DynamicProgramming => self.dp_solve(policy, valuation_month, inforce),
+                CARVMMethod::Hybrid => self.hybrid_solve(policy, valuation_month, inforce),
+            }?
         };
 
-        let csv = self.cash_surrender_value(policy, valuation_month, current_av);
+        let csv = self.cash_surrender_value(policy, valuation_month, inforce);
         let final_reserve = reserve.max(csv);
 
         // Update cache
         if self.config.use_caching {
             let monthly_income = if optimal_month < u32::MAX {
-                let activation_age = policy.attained_age(optimal_month);
-                let payout_rate = self.assumptions.product.glwb.payout_factors.get_single_life(activation_age);
+                let payout_rate = match inforce.locked_payout_rate {
+                    Some(rate) if inforce.income_activated => rate,
+                    _ => self.assumptions.product.glwb.payout_factors
+                        .for_policy(policy, optimal_month)?,
+                };
                 current_bb * payout_rate / 12.0
             } else {
                 0.0
@@ -227,6 +376,17 @@ impl CARVMCalculator {
                 policy.policy_year(valuation_month)
             );
 
+            // AV the solve expects until the next periodic re-solve
+            let benefit_calc = self.benefit_calculator(policy);
+            let horizon = self.config.revalidation_criteria.periodic_revalidation_months;
+            let mut rolled = *inforce;
+            let mut expected_av = Vec::with_capacity(horizon as usize + 1);
+            expected_av.push(rolled.account_value);
+            for t in valuation_month..valuation_month + horizon {
+                rolled = benefit_calc.roll_forward_inforce(policy, &rolled, t, t + 1)?;
+                expected_av.push(rolled.account_value);
+            }
+
             let cached_path = CachedReservePath::new(
                 policy.policy_id as u64,
                 valuation_month,
@@ -237,15 +397,22 @@ impl CARVMCalculator {
                 monthly_income,
                 components.death_benefit_pv,
                 sc_rate,
-            );
+            )
+            .with_expected_av(expected_av);
 
-            self.cache.insert(cached_path);
+            self.cache.insert_for_policy(cached_path, self.policy_fingerprint(policy));
         }
 
+        let trace = if self.config.detailed_trace {
+            Some(self.reserve_trace(policy, valuation_month, inforce, optimal_month)?)
+        } else {
+            None
+        };
+
         // Determine if CSV is binding
         let is_csv_binding = (final_reserve - csv).abs() < 0.01;
 
-        ReserveResult {
+        Ok(ReserveResult {
             policy_id: policy.policy_id,
             valuation_date: valuation_month,
             gross_reserve: final_reserve,
@@ -262,7 +429,103 @@ impl CARVMCalculator {
             method: ReserveMethod::CARVM,
             from_cache: false,
             csv_at_valuation: csv,
-        }
+            benefit_stream: None,
+            trace,
+            guaranteed_benefit: None,
+        })
+    }
+
+    /// Audit trace of a solve
+    ///
+    /// The path is the winning benefit stream before the CSV floor, so it is
+    /// still reported when the CSV binds.
+    fn reserve_trace(
+        &self,
+        policy: &Policy,
+        valuation_month: u32,
+        inforce: &InforceSnapshot,
+        optimal_month: u32,
+    ) -> Result<ReserveTrace, ActuarialError> {
+        let payout_factors = &self.assumptions.product.glwb.payout_factors;
+
+        let (activation, tested) = if inforce.income_activated {
+            let payout_rate = match inforce.locked_payout_rate {
+                Some(rate) => rate,
+                None => payout_factors.for_policy(policy, valuation_month)?,
+            };
+            (Some((valuation_month, payout_rate)), Vec::new())
+        } else {
+            let activation = if optimal_month != u32::MAX {
+                Some((optimal_month, payout_factors.for_policy(policy, optimal_month)?))
+            } else {
+                None
+            };
+            let tested = self.activation_tests(policy, valuation_month, inforce.account_value, inforce.benefit_base)?;
+            (activation, tested)
+        };
+
+        let path = self.benefit_calculator(policy).path_trace(
+            policy,
+            valuation_month,
+            activation,
+            inforce.account_value,
+            inforce.benefit_base,
+        );
+
+        Ok(ReserveTrace {
+            policy_id: policy.policy_id,
+            valuation_month,
+            activation_month: activation.map(|(month, _)| month),
+            path,
+            tested,
+        })
+    }
+
+    // ========================================================================
+    // INCOME ALREADY ACTIVE
+    // ========================================================================
+
+    /// Income already active: no election remains, so the reserve is the
+    /// remaining income at the locked payout rate plus death benefits
+    fn income_active_solve(
+        &self,
+        policy: &Policy,
+        valuation_month: u32,
+        inforce: &InforceSnapshot,
+    ) -> Result<(u32, f64, ReserveComponents), ActuarialError> {
+        let benefit_calc = self.benefit_calculator(policy);
+
+        let payout_rate = match inforce.locked_payout_rate {
+            Some(rate) => rate,
+            None => self.assumptions.product.glwb.payout_factors.for_policy(policy, valuation_month)?,
+        };
+
+        let income_pv = benefit_calc.remaining_income_pv(
+            policy,
+            valuation_month,
+            inforce.benefit_base,
+            payout_rate,
+        );
+
+        let death_pv = benefit_calc.remaining_death_benefit_pv(
+            policy,
+            valuation_month,
+            inforce.account_value,
+            inforce.benefit_base,
+            payout_rate,
+        );
+
+        Ok((
+            valuation_month,
+            death_pv + income_pv,
+            ReserveComponents {
+                death_benefit_pv: death_pv,
+                income_benefit_pv: income_pv,
+                surrender_value_pv: 0.0,
+                elective_benefit_pv: income_pv,
+                free_pwd_pv: 0.0,
+            },
+        ))
     }
 
     // ========================================================================
@@ -274,22 +537,48 @@ impl CARVMCalculator {
         &self,
         policy: &Policy,
         valuation_month: u32,
-        current_av: f64,
-        current_bb: f64,
-    ) -> (u32, f64, ReserveComponents) {
-        let discount_curve = DiscountCurve::single_rate(policy.val_rate);
-        let benefit_calc = BenefitCalculator::new(
-            &self.assumptions,
-            discount_curve,
-            self.config.max_projection_months,
-        );
-
+        inforce: &InforceSnapshot,
+    ) -> Result<(u32, f64, ReserveComponents), ActuarialError> {
         let mut best_reserve = 0.0;
         let mut best_activation = u32::MAX;
         let mut best_components = ReserveComponents::default();
 
+        // Ties go to the earliest activation; never activating must be strictly better
+        for test in self.activation_tests(policy, valuation_month, inforce.account_value, inforce.benefit_base)? {
+            let total = test.total_pv();
+
+            if total > best_reserve {
+                best_reserve = total;
+                best_activation = test.activation_month.unwrap_or(u32::MAX);
+                best_components = ReserveComponents {
+                    death_benefit_pv: test.death_benefit_pv,
+                    income_benefit_pv: test.income_benefit_pv,
+                    surrender_value_pv: 0.0,
+                    elective_benefit_pv: test.income_benefit_pv,
+                    free_pwd_pv: 0.0,
+                };
+            }
+        }
+
+        Ok((best_activation, best_reserve, best_components))
+    }
+
+    /// PV of every activation month up to the deferral limit, then of never activating
+    fn activation_tests(
+        &self,
+        policy: &Policy,
+        valuation_month: u32,
+        current_av: f64,
+        current_bb: f64,
+    ) -> Result<Vec<ActivationTest>, ActuarialError> {
+        let benefit_calc = self.benefit_calculator(policy);
+        let mut tests = Vec::new();
+
         let max_deferral = valuation_month + self.config.max_deferral_years * 12;
 
+        // BB rolls up until activation, income is based on the BB at activation
+        let mut activation_bb = current_bb;
+
         // Test each possible activation month
         for activation_month in valuation_month..=max_deferral.min(self.config.max_projection_months) {
             let death_pv = benefit_calc.death_benefit_pv(
@@ -298,28 +587,21 @@ impl CARVMCalculator {
                 Some(activation_month),
                 current_av,
                 current_bb,
-            );
+            )?;
 
             let income_pv = benefit_calc.income_benefit_pv(
                 policy,
                 valuation_month,
                 activation_month,
-                current_bb,
-            );
-
-            let total = death_pv + income_pv;
-
-            if total > best_reserve {
-                best_reserve = total;
-                best_activation = activation_month;
-                best_components = ReserveComponents {
-                    death_benefit_pv: death_pv,
-                    income_benefit_pv: income_pv,
-                    surrender_value_pv: 0.0,
-                    elective_benefit_pv: income_pv,
-                    free_pwd_pv: 0.0,
-                };
-            }
+                activation_bb,
+            )?;
+            activation_bb *= policy.monthly_rollup_factor(activation_month + 1);
+
+            tests.push(ActivationTest {
+                activation_month: Some(activation_month),
+                death_benefit_pv: death_pv,
+                income_benefit_pv: income_pv,
+            });
         }
 
         // Also test "never activate" path
@@ -329,51 +611,142 @@ impl CARVMCalculator {
             None,
             current_av,
             current_bb,
-        );
+        )?;
 
-        if never_death_pv > best_reserve {
-            best_reserve = never_death_pv;
-            best_activation = u32::MAX;
-            best_components = ReserveComponents {
-                death_benefit_pv: never_death_pv,
-                income_benefit_pv: 0.0,
-                surrender_value_pv: 0.0,
-                elective_benefit_pv: 0.0,
-                free_pwd_pv: 0.0,
-            };
-        }
+        tests.push(ActivationTest {
+            activation_month: None,
+            death_benefit_pv: never_death_pv,
+            income_benefit_pv: 0.0,
+        });
 
-        (best_activation, best_reserve, best_components)
+        Ok(tests)
     }
 
     // ========================================================================
     // DYNAMIC PROGRAMMING SOLVER
     // ========================================================================
 
-    /// Dynamic programming solver (placeholder - would implement full DP)
+    /// Dynamic programming solver: backward induction over (month, state)
+    ///
+    /// All values are PVs at the valuation month, so death (non-elective) and
+    /// income (elective) tracks keep their own discount rates and add directly:
+    ///
+    ///   Income(t) = death + income PV of the path activating at t
+    ///   Defer(t)  = DB PV in month t on the accumulation path + Value(t+1)
+    ///   Value(t)  = max(Income(t), Defer(t))
+    ///
+    /// Beyond the deferral limit the policy never activates. Ties go to the
+    /// earlier activation, matching the brute-force search.
     fn dp_solve(
         &self,
         policy: &Policy,
         valuation_month: u32,
-        current_av: f64,
-        current_bb: f64,
-    ) -> (u32, f64, ReserveComponents) {
-        // TODO: Implement full DP solver with separate death/elective tracks
-        // For now, fall back to brute force
-        self.brute_force_solve(policy, valuation_month, current_av, current_bb)
+        inforce: &InforceSnapshot,
+    ) -> Result<(u32, f64, ReserveComponents), ActuarialError> {
+        let benefit_calc = self.benefit_calculator(policy);
+
+        let table = benefit_calc.decrement_table(policy, valuation_month);
+        let path = benefit_calc.accumulation_path(policy, &table, inforce);
+        let accumulation_db = |i: usize| path.get(i).map_or(0.0, |p| p.death_benefit_pv);
+
+        let max_deferral = valuation_month + self.config.max_deferral_years * 12;
+        let last_activation = max_deferral.min(self.config.max_projection_months);
+
+        // Terminal value: never activate after the last tested month
+        let first_never = if last_activation >= valuation_month {
+            (last_activation - valuation_month + 1) as usize
+        } else {
+            0
+        };
+        let mut value: f64 = (first_never..path.len()).map(accumulation_db).sum();
+
+        // Backward pass; legs[i] = (death PV, income PV) of activating at index i
+        let mut legs = vec![(0.0, 0.0); first_never];
+        let mut activate = vec![false; first_never];
+
+        for i in (0..first_never).rev() {
+            if let Some(point) = path.get(i) {
+                legs[i] = benefit_calc.income_path_pv(
+                    policy,
+                    &table,
+                    i,
+                    point.account_value,
+                    point.benefit_base,
+                )?;
+            }
+            let income_value = legs[i].0 + legs[i].1;
+            let defer_value = accumulation_db(i) + value;
+
+            if income_value >= defer_value {
+                activate[i] = true;
+                value = income_value;
+            } else {
+                value = defer_value;
+            }
+        }
+
+        if value <= 0.0 {
+            return Ok((u32::MAX, 0.0, ReserveComponents::default()));
+        }
+
+        // Forward pass: the first month where activation beats deferral
+        let optimal = activate.iter().position(|&a| a);
+        let (activation_month, death_pv, income_pv) = match optimal {
+            Some(i) => {
+                let prior_db: f64 = (0..i).map(accumulation_db).sum();
+                (valuation_month + i as u32, prior_db + legs[i].0, legs[i].1)
+            }
+            None => (u32::MAX, path.iter().map(|p| p.death_benefit_pv).sum(), 0.0),
+        };
+
+        Ok((
+            activation_month,
+            death_pv + income_pv,
+            ReserveComponents {
+                death_benefit_pv: death_pv,
+                income_benefit_pv: income_pv,
+                surrender_value_pv: 0.0,
+                elective_benefit_pv: income_pv,
+                free_pwd_pv: 0.0,
+            },
+        ))
     }
 
-    /// Hybrid solver: DP with validation
+    /// Hybrid solver: DP, cross-validated against brute force on a sample
+    ///
+    /// Sampled policies are re-solved by brute force. Disagreements are
+    /// recorded in the validation stats and the brute-force result is used.
     fn hybrid_solve(
-        &self,
+        &mut self,
         policy: &Policy,
         valuation_month: u32,
-        current_av: f64,
-        current_bb: f64,
-    ) -> (u32, f64, ReserveComponents) {
-        // TODO: Run DP, validate against brute force for first N policies
-        // For now, just use brute force
-        self.brute_force_solve(policy, valuation_month, current_av, current_bb)
+        inforce: &InforceSnapshot,
+    ) -> Result<(u32, f64, ReserveComponents), ActuarialError> {
+        let dp = self.dp_solve(policy, valuation_month, inforce)?;
+
+        let interval = self.config.hybrid_validation_interval;
+        if interval == 0 || !policy.policy_id.is_multiple_of(interval) {
+            return Ok(dp);
+        }
+
+        let bf = self.brute_force_solve(policy, valuation_month, inforce)?;
+        self.validation.validations += 1;
+
+        let discrepancy = SolverDiscrepancy {
+            policy_id: policy.policy_id,
+            valuation_month,
+            dp_activation_month: dp.0,
+            brute_force_activation_month: bf.0,
+            dp_reserve: dp.1,
+            brute_force_reserve: bf.1,
+        };
+
+        if dp.0 != bf.0 || discrepancy.relative_difference() > self.config.hybrid_tolerance {
+            self.validation.discrepancies.push(discrepancy);
+            return Ok(bf);
+        }
+
+        Ok(dp)
     }
 
     // ========================================================================
@@ -386,13 +759,14 @@ impl CARVMCalculator {
         policy: &Policy,
         valuation_month: u32,
         cached: CachedReservePath,
-    ) -> RollForwardResult {
+        inforce: &InforceSnapshot,
+    ) -> Result<RollForwardResult, ActuarialError> {
         let t_star = cached.optimal_activation_month;
         let _months_elapsed = valuation_month.saturating_sub(cached.solve_month);
 
         // Get current state
-        let current_av = self.get_av_at_month(policy, valuation_month);
-        let current_bb = self.get_bb_at_month(policy, valuation_month);
+        let current_av = inforce.account_value;
+        let current_bb = inforce.benefit_base;
 
         // Case A: Still in accumulation, before optimal activation
         if valuation_month < t_star {
@@ -408,25 +782,22 @@ impl CARVMCalculator {
             let current_itm = if current_av > 0.0 { current_bb / current_av } else { f64::MAX };
             let still_valid = (current_itm - cached.itm_at_solve).abs() / cached.itm_at_solve.max(0.01) < 0.10;
 
-            return RollForwardResult::Success {
+            return Ok(RollForwardResult::Success {
                 reserve: rolled,
                 still_valid,
                 validation_notes: None,
-            };
+            });
         }
 
         // Case B: At or past optimal activation time
         if valuation_month >= t_star && t_star < u32::MAX {
-            let discount_curve = DiscountCurve::single_rate(policy.val_rate);
-            let benefit_calc = BenefitCalculator::new(
-                &self.assumptions,
-                discount_curve,
-                self.config.max_projection_months,
-            );
+            let benefit_calc = self.benefit_calculator(policy);
 
             // Simple calculation: PV of remaining income + death benefits
-            let activation_age = policy.attained_age(t_star);
-            let payout_rate = self.assumptions.product.glwb.payout_factors.get_single_life(activation_age);
+            let payout_rate = match inforce.locked_payout_rate {
+                Some(rate) => rate,
+                None => self.assumptions.product.glwb.payout_factors.for_policy(policy, t_star)?,
+            };
 
             let income_pv = benefit_calc.remaining_income_pv(
                 policy,
@@ -435,24 +806,24 @@ impl CARVMCalculator {
                 payout_rate,
             );
 
-            let death_pv = benefit_calc.death_benefit_pv(
+            let death_pv = benefit_calc.remaining_death_benefit_pv(
                 policy,
                 valuation_month,
-                Some(t_star),
                 current_av,
                 current_bb,
+                payout_rate,
             );
 
-            return RollForwardResult::Success {
+            return Ok(RollForwardResult::Success {
                 reserve: income_pv + death_pv,
                 still_valid: true,
                 validation_notes: None,
-            };
+            });
         }
 
-        RollForwardResult::NeedsResolve {
-            reason: "Unexpected state in roll forward".into(),
-        }
+        Ok(RollForwardResult::NeedsResolve {
+            reason: RevalidationReason::UnexpectedState,
+        })
     }
 
     /// Roll reserve forward through accumulation period
@@ -463,14 +834,17 @@ impl CARVMCalculator {
         t_prev: u32,
         t_now: u32,
     ) -> f64 {
-        let v = 1.0 / (1.0 + policy.val_rate / 12.0);
+        let curve = self.discount_curve(policy);
         let mut reserve = r_prev;
 
-        for t in t_prev..t_now {
-            let attained_age = policy.attained_age(t);
-            let q = self.assumptions.mortality.monthly_rate(attained_age, policy.gender, t);
+        let contract_mortality = self.assumptions.mortality.contract_rates(policy, t_prev);
+        for (t, q) in (t_prev..t_now).zip(contract_mortality) {
             let p = 1.0 - q;
 
+            // One-month discount factor along the curve from the solve month
+            let k = t - t_prev;
+            let v = curve.discount_to_month_elective(k + 1) / curve.discount_to_month_elective(k);
+
             // Simplified roll forward (ignoring DB cost for now)
             // Full version would subtract DB cost at each step
             reserve = reserve / (p * v);
@@ -483,32 +857,43 @@ impl CARVMCalculator {
     // HELPER METHODS
     // ========================================================================
 
-    /// Get account value at a specific month (simplified)
-    fn get_av_at_month(&self, policy: &Policy, month: u32) -> f64 {
-        if month == 0 {
-            policy.starting_av()
-        } else {
-            // Would need actual projection or state tracking
-            // For now, return starting AV (conservative)
-            policy.starting_av()
-        }
+    /// Discount curve from the config, or the policy's valuation rate
+    fn discount_curve(&self, policy: &Policy) -> DiscountCurve {
+        self.config
+            .discount_curve
+            .clone()
+            .unwrap_or_else(|| DiscountCurve::single_rate(policy.val_rate))
     }
 
-    /// Get benefit base at a specific month (simplified)
-    fn get_bb_at_month(&self, policy: &Policy, month: u32) -> f64 {
+    /// Benefit calculator on the configured discount curve
+    fn benefit_calculator(&self, policy: &Policy) -> BenefitCalculator<'_> {
+        BenefitCalculator::for_valuation(
+            &self.assumptions,
+            policy,
+            self.config.discount_curve.as_ref(),
+            self.config.max_projection_months,
+            self.index_credits.as_ref(),
+        )
+    }
+
+    /// In-force values at a valuation month when no snapshot is supplied
+    ///
+    /// Month 0 is the policy record itself (seasoned fields); later months roll
+    /// the record forward with the reserve projection. Both are per surviving
+    /// policy, unlike the engine's cohort snapshots (see `InforceSnapshot`).
+    fn inforce_at_month(&self, policy: &Policy, month: u32) -> Result<InforceSnapshot, ActuarialError> {
+        let inforce = InforceSnapshot::from_policy(policy);
         if month == 0 {
-            policy.starting_benefit_base()
-        } else {
-            // Would need actual projection
-            policy.starting_benefit_base()
+            return Ok(inforce);
         }
+
+        let benefit_calc = self.benefit_calculator(policy);
+        benefit_calc.roll_forward_inforce(policy, &inforce, 0, month)
     }
 
-    /// Calculate cash surrender value
-    fn cash_surrender_value(&self, policy: &Policy, month: u32, av: f64) -> f64 {
-        let policy_year = policy.policy_year(month);
-        let sc_rate = self.assumptions.product.base.surrender_charges.get_rate(policy_year);
-        av * (1.0 - sc_rate)
+    /// Calculate cash surrender value (floored at the MGIR nonforfeiture value)
+    fn cash_surrender_value(&self, policy: &Policy, month: u32, inforce: &InforceSnapshot) -> f64 {
+        inforce_cash_surrender_value(&self.assumptions, policy, month, inforce)
     }
 }
 
@@ -517,8 +902,22 @@ impl ReserveCalculator for CARVMCalculator {
         &mut self,
         policy: &Policy,
         valuation_month: u32,
-    ) -> ReserveResult {
-        self.calculate_with_cache(policy, valuation_month)
+    ) -> Result<ReserveResult, ActuarialError> {
+        let inforce = self.inforce_at_month(policy, valuation_month)?;
+        self.calculate_with_cache(policy, valuation_month, &inforce)
+    }
+
+    fn calculate_reserve_from_state(
+        &mut self,
+        policy: &Policy,
+        valuation_month: u32,
+        inforce: &InforceSnapshot,
+    ) -> Result<ReserveResult, ActuarialError> {
+        self.calculate_with_cache(policy, valuation_month, inforce)
+    }
+
+    fn cache_statistics(&self) -> CacheStats {
+        self.cache.stats()
     }
 
     fn clear_cache(&mut self) {
@@ -571,7 +970,7 @@ mod tests {
         let mut calc = CARVMCalculator::new(assumptions, config);
         let policy = test_policy();
 
-        let result = calc.calculate_reserve(&policy, 0);
+        let result = calc.calculate_reserve(&policy, 0).unwrap();
 
         // Reserve should be positive
         assert!(result.gross_reserve > 0.0);
@@ -595,14 +994,66 @@ mod tests {
         let policy = test_policy();
 
         // First call - should be cache miss
-        let _result1 = calc.calculate_reserve(&policy, 0);
+        let _result1 = calc.calculate_reserve(&policy, 0).unwrap();
         assert_eq!(calc.cache.cache_misses, 1);
 
         // Second call at same month - should be cache hit
-        let _result2 = calc.calculate_reserve(&policy, 0);
+        let _result2 = calc.calculate_reserve(&policy, 0).unwrap();
         // Note: Same month might trigger revalidation, so we just check it runs
     }
 
+    #[test]
+    fn test_cache_warm_start() {
+        let config = CARVMConfig {
+            method: CARVMMethod::BruteForce,
+            max_projection_months: 60,
+            max_deferral_years: 5,
+            use_caching: true,
+            ..Default::default()
+        };
+        let path = std::env::temp_dir().join(format!("carvm_cache_{}.json", std::process::id()));
+        let policy = test_policy();
+
+        let mut first_run = CARVMCalculator::new(Assumptions::default_pricing(), config.clone());
+        let solved = first_run.calculate_reserve(&policy, 0).unwrap();
+        first_run.save_cache(&path).unwrap();
+
+        // Next run starts from the saved solve (here revalidated, as activation is near)
+        let mut second_run = CARVMCalculator::new(Assumptions::default_pricing(), config.clone());
+        assert_eq!(second_run.load_cache(&path).unwrap(), 1);
+        assert_eq!(second_run.cached_policies(), 1);
+        let warm = second_run.calculate_reserve(&policy, 0).unwrap();
+        assert_eq!(second_run.cache.cache_misses, 0);
+        assert_eq!(second_run.cache.revalidations, 1);
+        assert!((warm.gross_reserve - solved.gross_reserve).abs() < 1e-9);
+
+        // Valuation-date values are left to revalidation
+        let mut seasoned = policy.clone();
+        seasoned.duration_months = 13;
+        seasoned.current_av = Some(95_000.0);
+        seasoned.current_benefit_base = Some(140_000.0);
+        seasoned.current_guaranteed_av = Some(88_000.0);
+        seasoned.prior_av = Some(95_500.0);
+        seasoned.ytd_non_systematic_wd = 1_000.0;
+        assert_eq!(second_run.policy_fingerprint(&seasoned), second_run.policy_fingerprint(&policy));
+
+        // A changed contract term is re-solved
+        let mut changed = policy.clone();
+        changed.initial_benefit_base *= 1.5;
+        let resolved = second_run.calculate_reserve(&changed, 1).unwrap();
+        assert!(!resolved.from_cache);
+        assert_eq!(second_run.cache.invalidations, 1);
+        assert_eq!(second_run.cache.cache_misses, 1);
+
+        // Different assumptions ignore the file
+        let mut assumptions = Assumptions::default_pricing();
+        assumptions.product.base.expense_rate_of_av *= 2.0;
+        let mut other = CARVMCalculator::new(assumptions, config);
+        assert_eq!(other.load_cache(&path).unwrap(), 0);
+
+        std::fs::remove_file(&path).ok();
+    }
+
     #[test]
     fn test_csv_is_floor() {
         // CARVM reserve should always be at least as large as CSV
@@ -618,7 +1069,7 @@ mod tests {
         let mut calc = CARVMCalculator::new(assumptions, config);
         let policy = test_policy();
 
-        let result = calc.calculate_reserve(&policy, 0);
+        let result = calc.calculate_reserve(&policy, 0).unwrap();
 
         // Reserve must be >= CSV (CSV is the floor)
         assert!(
@@ -629,6 +1080,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_csv_floor_reduced_by_withdrawals() {
+        // Income from year 1 on a BB well above premium exhausts the AV within 10 years
+        let mut policy = test_policy();
+        policy.initial_benefit_base = 200_000.0;
+        policy.glwb_start_year = 1;
+
+        let mut calc = CARVMCalculator::new(Assumptions::default_pricing(), CARVMConfig {
+            method: CARVMMethod::BruteForce,
+            max_projection_months: 240,
+            use_caching: false,
+            ..Default::default()
+        });
+        let inforce = calc.inforce_at_month(&policy, 120).unwrap();
+        assert_eq!(inforce.account_value, 0.0);
+        assert_eq!(inforce.guaranteed_av, Some(0.0));
+
+        // The floor went with the AV, not 87.5% of premium accumulated at MGIR
+        let result = calc.calculate_reserve(&policy, 120).unwrap();
+        assert_eq!(result.csv_at_valuation, 0.0);
+    }
+
     #[test]
     fn test_reserve_components_sum() {
         let assumptions = Assumptions::default_pricing();
@@ -643,7 +1116,7 @@ mod tests {
         let mut calc = CARVMCalculator::new(assumptions, config);
         let policy = test_policy();
 
-        let result = calc.calculate_reserve(&policy, 0);
+        let result = calc.calculate_reserve(&policy, 0).unwrap();
 
         // When CSV is not binding, death PV + elective PV should approximately equal gross reserve
         if !result.is_csv_binding() {
@@ -685,8 +1158,8 @@ mod tests {
 
         let mut calc = CARVMCalculator::new(assumptions, config);
 
-        let result_young = calc.calculate_reserve(&policy_young, 0);
-        let result_old = calc.calculate_reserve(&policy_old, 0);
+        let result_young = calc.calculate_reserve(&policy_young, 0).unwrap();
+        let result_old = calc.calculate_reserve(&policy_old, 0).unwrap();
 
         // Both reserves should be positive
         assert!(result_young.gross_reserve > 0.0);
@@ -729,8 +1202,8 @@ mod tests {
 
         let mut calc = CARVMCalculator::new(assumptions, config);
 
-        let result_low = calc.calculate_reserve(&policy_low_itm, 0);
-        let result_high = calc.calculate_reserve(&policy_high_itm, 0);
+        let result_low = calc.calculate_reserve(&policy_low_itm, 0).unwrap();
+        let result_high = calc.calculate_reserve(&policy_high_itm, 0).unwrap();
 
         // Both reserves should be positive
         assert!(result_low.gross_reserve > 0.0);
@@ -759,7 +1232,7 @@ mod tests {
         let mut calc = CARVMCalculator::new(assumptions, config);
         let policy = test_policy();
 
-        let result = calc.calculate_reserve(&policy, 0);
+        let result = calc.calculate_reserve(&policy, 0).unwrap();
 
         // Optimal activation month should be within tested range or u32::MAX
         if result.optimal_activation_month != u32::MAX {
@@ -786,8 +1259,8 @@ mod tests {
         let policy = test_policy();
 
         // Calculate at month 0 and month 12
-        let result_0 = calc.calculate_reserve(&policy, 0);
-        let result_12 = calc.calculate_reserve(&policy, 12);
+        let result_0 = calc.calculate_reserve(&policy, 0).unwrap();
+        let result_12 = calc.calculate_reserve(&policy, 12).unwrap();
 
         // Both should have positive reserves
         assert!(result_0.gross_reserve > 0.0);
@@ -796,4 +1269,269 @@ mod tests {
         // Reserves should be in a reasonable range
         // (Without actual projection, they may be similar due to simplified state tracking)
     }
+
+    #[test]
+    fn test_dp_matches_brute_force() {
+        let assumptions = Assumptions::default_pricing();
+        let config = CARVMConfig {
+            max_projection_months: 360,
+            max_deferral_years: 20,
+            use_caching: false,
+            ..Default::default()
+        };
+        let calc = CARVMCalculator::new(assumptions, config);
+
+        let policies = [
+            (55, 130_000.0, 100_000.0),
+            (65, 130_000.0, 100_000.0),
+            (72, 150_000.0, 100_000.0),
+            (80, 100_000.0, 100_000.0),
+        ];
+
+        for (i, (age, bb, premium)) in policies.iter().enumerate() {
+            let policy = Policy::new(
+                i as u32 + 1, QualStatus::Q, *age, Gender::Female, *bb, 1.0, *premium,
+                CreditingStrategy::Indexed, 10, 0.0475, 0.01, 0.3, RollupType::Simple,
+            );
+
+            for valuation_month in [0, 24] {
+                let inforce = InforceSnapshot::from_policy(&policy);
+                let (bf_month, bf_reserve, bf_comp) = calc.brute_force_solve(&policy, valuation_month, &inforce).unwrap();
+                let (dp_month, dp_reserve, dp_comp) = calc.dp_solve(&policy, valuation_month, &inforce).unwrap();
+
+                assert_eq!(dp_month, bf_month, "age {} month {}", age, valuation_month);
+                assert!(
+                    (dp_reserve - bf_reserve).abs() < 1e-6 * bf_reserve,
+                    "DP reserve {} should match brute force {}",
+                    dp_reserve,
+                    bf_reserve
+                );
+                assert!((dp_comp.death_benefit_pv - bf_comp.death_benefit_pv).abs() < 1e-6 * bf_reserve);
+                assert!((dp_comp.income_benefit_pv - bf_comp.income_benefit_pv).abs() < 1e-6 * bf_reserve);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hybrid_validation() {
+        let assumptions = Assumptions::default_pricing();
+        let config = CARVMConfig {
+            method: CARVMMethod::Hybrid,
+            max_projection_months: 240,
+            max_deferral_years: 10,
+            use_caching: false,
+            hybrid_validation_interval: 2,
+            ..Default::default()
+        };
+
+        let mut calc = CARVMCalculator::new(assumptions.clone(), config.clone());
+        for id in 1..=4 {
+            let policy = Policy::new(
+                id, QualStatus::N, 60 + id as u8 * 3, Gender::Male, 130_000.0, 1.0, 100_000.0,
+                CreditingStrategy::Indexed, 10, 0.0475, 0.01, 0.3, RollupType::Simple,
+            );
+            calc.calculate_reserve(&policy, 0).unwrap();
+        }
+
+        // Policies 2 and 4 sampled, DP agrees with brute force
+        assert_eq!(calc.validation_stats().validations, 2);
+        assert!(calc.validation_stats().discrepancies.is_empty());
+
+        // A negative tolerance flags every sampled solve
+        let mut strict = CARVMCalculator::new(assumptions, CARVMConfig {
+            hybrid_tolerance: -1.0,
+            ..config
+        });
+        let policy = test_policy();
+        strict.calculate_reserve(&policy, 0).unwrap();
+
+        let stats = strict.validation_stats();
+        assert_eq!(stats.discrepancies.len(), 1);
+        assert_eq!(stats.discrepancies[0].policy_id, 2800);
+        assert_eq!(stats.discrepancies[0].dp_activation_month, stats.discrepancies[0].brute_force_activation_month);
+    }
+
+    #[test]
+    fn test_reserve_from_inforce_state() {
+        let assumptions = Assumptions::default_pricing();
+        let config = CARVMConfig {
+            method: CARVMMethod::BruteForce,
+            max_projection_months: 240,
+            max_deferral_years: 10,
+            use_caching: false,
+            ..Default::default()
+        };
+
+        let mut calc = CARVMCalculator::new(assumptions, config);
+        let policy = test_policy();
+
+        // Without a snapshot, month 60 rolls the policy record forward
+        let at_0 = calc.calculate_reserve(&policy, 0).unwrap();
+        let at_60 = calc.calculate_reserve(&policy, 60).unwrap();
+        assert!((at_60.csv_at_valuation - at_0.csv_at_valuation).abs() > 1.0);
+
+        // A snapshot drives the valuation values
+        let snapshot = InforceSnapshot {
+            account_value: 80_000.0,
+            benefit_base: 150_000.0,
+            guaranteed_av: Some(70_000.0),
+            income_activated: false,
+            locked_payout_rate: None,
+            ..InforceSnapshot::from_policy(&policy)
+        };
+        let low_av = calc.calculate_reserve_from_state(&policy, 60, &snapshot).unwrap();
+        let high_av = calc.calculate_reserve_from_state(&policy, 60, &InforceSnapshot {
+            account_value: 120_000.0,
+            ..snapshot
+        }).unwrap();
+        assert!(high_av.csv_at_valuation > low_av.csv_at_valuation);
+        assert!(low_av.csv_at_valuation >= 70_000.0);
+
+        // Income already active: valued at the locked payout rate from the valuation month
+        let active = calc.calculate_reserve_from_state(&policy, 60, &InforceSnapshot {
+            income_activated: true,
+            locked_payout_rate: Some(0.06),
+            ..snapshot
+        }).unwrap();
+        let higher = calc.calculate_reserve_from_state(&policy, 60, &InforceSnapshot {
+            income_activated: true,
+            locked_payout_rate: Some(0.07),
+            ..snapshot
+        }).unwrap();
+
+        // Income of 9,000 a year is worth more than the 75,200 CSV, so the
+        // reserve is the income stream from the valuation month plus death benefits
+        assert!(!active.is_csv_binding());
+        assert_eq!(active.optimal_activation_month, 60);
+        assert!(active.reserve_components.income_benefit_pv > active.csv_at_valuation);
+        assert!((active.gross_reserve - active.reserve_components.death_benefit_pv
+            - active.reserve_components.income_benefit_pv).abs() < 1e-6);
+
+        // Guaranteed income is paid to the life whatever the AV, so its PV is
+        // proportional to the locked rate
+        let ratio = higher.reserve_components.income_benefit_pv / active.reserve_components.income_benefit_pv;
+        assert!((ratio - 0.07 / 0.06).abs() < 1e-9);
+
+        // A cohort snapshot (fewer lives, same contract) is valued in proportion
+        let cohort = calc.calculate_reserve_from_state(&policy, 60, &InforceSnapshot {
+            income_activated: true,
+            locked_payout_rate: Some(0.06),
+            ..snapshot
+        }.with_lives(0.5)).unwrap();
+        assert!((cohort.gross_reserve - 0.5 * active.gross_reserve).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_joint_life_reserve() {
+        let config = CARVMConfig {
+            method: CARVMMethod::BruteForce,
+            max_projection_months: 360,
+            max_deferral_years: 10,
+            use_caching: false,
+            ..Default::default()
+        };
+        let mut assumptions = Assumptions::default_pricing();
+        let single = test_policy();
+        let joint = single.clone().with_joint_life(single.issue_age, Gender::Female);
+
+        // Same payout factor, but income runs to the second death
+        let joint_factors = std::iter::once((single.issue_age, assumptions.product.glwb.payout_factors.for_policy(&single, 0).unwrap()))
+            .collect();
+        assumptions.product.glwb.payout_factors = assumptions.product.glwb.payout_factors.clone().with_joint_life(&joint_factors);
+        let mut calc = CARVMCalculator::new(assumptions, config);
+
+        let single_reserve = calc.calculate_reserve(&single, 0).unwrap();
+        let joint_reserve = calc.calculate_reserve(&joint, 0).unwrap();
+        assert!(joint_reserve.gross_reserve > single_reserve.gross_reserve);
+
+        // A widowed snapshot is valued on the survivor alone, as the same
+        // state recorded on the policy is
+        let both_alive = InforceSnapshot::from_policy(&joint);
+        let widowed = InforceSnapshot {
+            joint_survival: Some(crate::assumptions::JointSurvival { first: 0.0, second: 1.0 }),
+            ..both_alive
+        };
+        let both_alive_reserve = calc.calculate_reserve_from_state(&joint, 0, &both_alive).unwrap();
+        let widowed_reserve = calc.calculate_reserve_from_state(&joint, 0, &widowed).unwrap();
+        let recorded = calc.calculate_reserve(&joint.clone().with_joint_survival(0.0, 1.0), 0).unwrap();
+        assert!((both_alive_reserve.gross_reserve - widowed_reserve.gross_reserve).abs() > 1.0);
+        assert!((widowed_reserve.gross_reserve - recorded.gross_reserve).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_discount_curve_config() {
+        use crate::reserves::YieldCurve;
+
+        let assumptions = Assumptions::default_pricing();
+        let policy = test_policy();
+        let config = CARVMConfig {
+            method: CARVMMethod::DynamicProgramming,
+            max_projection_months: 240,
+            max_deferral_years: 10,
+            use_caching: false,
+            ..Default::default()
+        };
+
+        // A flat curve at the effective equivalent of the valuation rate matches it
+        let effective = (1.0 + policy.val_rate / 12.0).powi(12) - 1.0;
+        let mut flat_rate = CARVMCalculator::new(assumptions.clone(), config.clone());
+        let mut flat_curve = CARVMCalculator::new(assumptions.clone(), CARVMConfig {
+            discount_curve: Some(DiscountCurve::from_yield_curve(YieldCurve::flat(effective))),
+            ..config.clone()
+        });
+        let by_rate = flat_rate.calculate_reserve(&policy, 0).unwrap();
+        let by_curve = flat_curve.calculate_reserve(&policy, 0).unwrap();
+        assert!((by_rate.gross_reserve - by_curve.gross_reserve).abs() < 1e-6);
+
+        // Lower rates on the curve raise the reserve when it exceeds the CSV
+        let mut low_curve = CARVMCalculator::new(assumptions, CARVMConfig {
+            discount_curve: Some(DiscountCurve::from_yield_curve(YieldCurve::flat(0.01))),
+            ..config
+        });
+        let low = low_curve.calculate_reserve(&policy, 0).unwrap();
+        assert!(low.gross_reserve >= by_curve.gross_reserve);
+        assert!(low.reserve_components.total() > by_curve.reserve_components.total());
+    }
+
+    #[test]
+    fn test_detailed_trace() {
+        use crate::reserves::{write_activation_csv, write_path_csv};
+
+        let assumptions = Assumptions::default_pricing();
+        let policy = test_policy();
+        let config = CARVMConfig {
+            method: CARVMMethod::BruteForce,
+            max_projection_months: 240,
+            max_deferral_years: 5,
+            detailed_trace: true,
+            ..Default::default()
+        };
+        let mut calculator = CARVMCalculator::new(assumptions, config);
+        let result = calculator.calculate_reserve(&policy, 0).unwrap();
+        let trace = result.trace.as_ref().expect("trace requested");
+
+        // Every activation month through the deferral limit, plus never
+        assert_eq!(trace.tested.len(), 5 * 12 + 2);
+
+        // Winning path reconciles to the solved PV and is the best tested path
+        let solved = result.reserve_components.death_benefit_pv + result.reserve_components.income_benefit_pv;
+        assert!((trace.path_pv() - solved).abs() < 1e-6 * solved);
+        let best = trace.tested.iter().map(|t| t.total_pv()).fold(f64::MIN, f64::max);
+        assert!((best - solved).abs() < 1e-9);
+        assert_eq!(trace.path[0].survival, 1.0);
+
+        // Traced results are never rolled forward from the cache
+        assert!(!calculator.calculate_reserve(&policy, 1).unwrap().from_cache);
+
+        let mut paths = Vec::new();
+        write_path_csv(std::slice::from_ref(trace), &mut paths).unwrap();
+        let paths = String::from_utf8(paths).unwrap();
+        assert!(paths.starts_with("PolicyID,ValuationMonth,ActivationMonth,Month"));
+        assert_eq!(paths.lines().count(), trace.path.len() + 1);
+
+        let mut tested = Vec::new();
+        write_activation_csv(std::slice::from_ref(trace), &mut tested).unwrap();
+        let tested = String::from_utf8(tested).unwrap();
+        assert_eq!(tested.lines().filter(|line| line.ends_with(",true")).count(), 1);
+    }
 }
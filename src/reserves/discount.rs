@@ -3,11 +3,275 @@
 //! Supports:
 //! - Single valuation rate (standard CARVM)
 //! - Separate rates for death benefits vs elective benefits
-//! - Full spot rate curves (for advanced calculations)
+//! - Term structures built from spot, forward or par curves, interpolated
+//!   between tenors (e.g. Treasury + spread curves loaded from CSV)
+
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
 
 use serde::{Deserialize, Serialize};
 
+use crate::error::ActuarialError;
+
+/// How the rates of a term structure are quoted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CurveType {
+    /// Annual effective zero-coupon rate from today to each tenor
+    Spot,
+
+    /// Annual effective forward rate from the previous tenor to each tenor
+    Forward,
+
+    /// Annual-coupon par yield for each tenor
+    Par,
+}
+
+/// Interpolation between curve tenors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Interpolation {
+    /// Linear in the spot rate
+    #[default]
+    Linear,
+
+    /// Linear in the log discount factor (flat forward rates between tenors)
+    LogLinear,
+}
+
+/// Interest rate term structure
+///
+/// Stored as annual effective spot rates at each tenor (in years). Rates are
+/// held flat before the first tenor and beyond the last.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct YieldCurve {
+    tenors: Vec<f64>,
+    spot_rates: Vec<f64>,
+    interpolation: Interpolation,
+}
+
+impl YieldCurve {
+    /// Build a curve from rates quoted at tenors (years, strictly increasing)
+    pub fn new(
+        curve_type: CurveType,
+        tenors: Vec<f64>,
+        rates: Vec<f64>,
+        interpolation: Interpolation,
+    ) -> Result<Self, ActuarialError> {
+        if tenors.is_empty() || tenors.len() != rates.len() {
+            return Err(ActuarialError::Config(format!(
+                "Curve needs matching tenors and rates (got {} tenors, {} rates)",
+                tenors.len(),
+                rates.len()
+            )));
+        }
+        if tenors[0] <= 0.0 || tenors.windows(2).any(|w| w[1] <= w[0]) {
+            return Err(ActuarialError::Config("Curve tenors must be positive and strictly increasing".to_string()));
+        }
+
+        let spot_rates = match curve_type {
+            CurveType::Spot => rates,
+            CurveType::Forward => spots_from_forwards(&tenors, &rates),
+            CurveType::Par => return Ok(bootstrap_par(&tenors, &rates, interpolation)),
+        };
+
+        Ok(Self {
+            tenors,
+            spot_rates,
+            interpolation,
+        })
+    }
+
+    /// Flat curve at a single annual effective rate
+    pub fn flat(rate: f64) -> Self {
+        Self {
+            tenors: vec![1.0],
+            spot_rates: vec![rate],
+            interpolation: Interpolation::Linear,
+        }
+    }
+
+    /// Load a curve from a CSV file with `tenor,rate` columns (tenor in years)
+    pub fn from_csv(
+        path: &Path,
+        curve_type: CurveType,
+        interpolation: Interpolation,
+    ) -> Result<Self, ActuarialError> {
+        let file = File::open(path).map_err(|source| ActuarialError::AssumptionFileMissing {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::read(file, path, curve_type, interpolation)
+    }
+
+    /// Load a curve from any CSV reader with `tenor,rate` columns
+    pub fn from_reader<R: Read>(
+        reader: R,
+        curve_type: CurveType,
+        interpolation: Interpolation,
+    ) -> Result<Self, ActuarialError> {
+        Self::read(reader, Path::new("yield curve"), curve_type, interpolation)
+    }
+
+    /// Parse `tenor,rate` rows, reporting errors against `path`
+    fn read<R: Read>(
+        reader: R,
+        path: &Path,
+        curve_type: CurveType,
+        interpolation: Interpolation,
+    ) -> Result<Self, ActuarialError> {
+        let malformed = |line: Option<&csv::Position>, message: String| ActuarialError::AssumptionMalformed {
+            path: path.to_path_buf(),
+            line: line.map_or(0, |p| p.line()),
+            message,
+        };
+
+        let mut reader = csv::Reader::from_reader(reader);
+        let mut points: Vec<(f64, f64)> = Vec::new();
+
+        for result in reader.records() {
+            let record = result.map_err(|e| malformed(e.position(), e.to_string()))?;
+            let parse = |index: usize, name: &str| {
+                let value = record.get(index).unwrap_or("").trim();
+                value
+                    .parse::<f64>()
+                    .map_err(|_| malformed(record.position(), format!("Invalid {} in yield curve: {:?}", name, value)))
+            };
+            points.push((parse(0, "tenor")?, parse(1, "rate")?));
+        }
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let (tenors, rates) = points.into_iter().unzip();
+        Self::new(curve_type, tenors, rates, interpolation).map_err(|e| malformed(None, e.to_string()))
+    }
+
+    /// Curve shifted in parallel by a spread on the spot rates (e.g. Treasury + spread)
+    pub fn with_spread(&self, spread: f64) -> Self {
+        Self {
+            spot_rates: self.spot_rates.iter().map(|r| r + spread).collect(),
+            ..self.clone()
+        }
+    }
+
+    /// Annual effective spot rate to time `t` (years)
+    pub fn spot_rate(&self, t: f64) -> f64 {
+        let last = self.tenors.len() - 1;
+        if t <= self.tenors[0] {
+            return self.spot_rates[0];
+        }
+        if t >= self.tenors[last] {
+            return self.spot_rates[last];
+        }
+
+        let i = self.tenors.partition_point(|&tenor| tenor < t);
+        let (t0, t1) = (self.tenors[i - 1], self.tenors[i]);
+        let (r0, r1) = (self.spot_rates[i - 1], self.spot_rates[i]);
+        let w = (t - t0) / (t1 - t0);
+
+        match self.interpolation {
+            Interpolation::Linear => r0 + w * (r1 - r0),
+            Interpolation::LogLinear => {
+                let ln_df = (1.0 - w) * -t0 * (1.0 + r0).ln() + w * -t1 * (1.0 + r1).ln();
+                (-ln_df / t).exp() - 1.0
+            }
+        }
+    }
+
+    /// Discount factor to time `t` (years)
+    pub fn discount_factor(&self, t: f64) -> f64 {
+        if t <= 0.0 {
+            return 1.0;
+        }
+        (1.0 + self.spot_rate(t)).powf(-t)
+    }
+
+    /// Discount factor to a number of months from valuation
+    pub fn discount_to_month(&self, months: u32) -> f64 {
+        self.discount_factor(months as f64 / 12.0)
+    }
+
+    /// Annual effective forward rate between times `t1` and `t2` (years)
+    pub fn forward_rate(&self, t1: f64, t2: f64) -> f64 {
+        if t2 <= t1 {
+            return self.spot_rate(t1);
+        }
+        (self.discount_factor(t1) / self.discount_factor(t2)).powf(1.0 / (t2 - t1)) - 1.0
+    }
+
+    /// Annual-coupon par yield for a whole number of years
+    pub fn par_rate(&self, years: u32) -> f64 {
+        if years == 0 {
+            return self.spot_rate(0.0);
+        }
+        let annuity: f64 = (1..=years).map(|k| self.discount_factor(k as f64)).sum();
+        (1.0 - self.discount_factor(years as f64)) / annuity
+    }
+
+    /// Tenors (years) the curve is defined at
+    pub fn tenors(&self) -> &[f64] {
+        &self.tenors
+    }
+}
+
+/// Spot rates from forward rates quoted between consecutive tenors
+fn spots_from_forwards(tenors: &[f64], forwards: &[f64]) -> Vec<f64> {
+    let mut log_df = 0.0;
+    let mut prior_tenor = 0.0;
+
+    tenors
+        .iter()
+        .zip(forwards)
+        .map(|(&tenor, &forward)| {
+            log_df -= (tenor - prior_tenor) * (1.0 + forward).ln();
+            prior_tenor = tenor;
+            (-log_df / tenor).exp() - 1.0
+        })
+        .collect()
+}
+
+/// Bootstrap spot rates from annual-coupon par yields
+///
+/// Tenors up to one year pay no interim coupon, so their par yield is the
+/// spot rate. Beyond that, par yields are interpolated to each whole year
+/// and discount factors solved in turn: DF(n) = (1 - c(n) * sum DF(1..n-1)) / (1 + c(n)).
+fn bootstrap_par(tenors: &[f64], par_rates: &[f64], interpolation: Interpolation) -> YieldCurve {
+    let par_curve = YieldCurve {
+        tenors: tenors.to_vec(),
+        spot_rates: par_rates.to_vec(),
+        interpolation: Interpolation::Linear,
+    };
+
+    let mut spot_tenors: Vec<f64> = Vec::new();
+    let mut spot_rates: Vec<f64> = Vec::new();
+    for (&tenor, &rate) in tenors.iter().zip(par_rates) {
+        if tenor < 1.0 {
+            spot_tenors.push(tenor);
+            spot_rates.push(rate);
+        }
+    }
+
+    let max_year = tenors[tenors.len() - 1].ceil().max(1.0) as u32;
+    let mut annuity = 0.0;
+    for year in 1..=max_year {
+        let coupon = par_curve.spot_rate(year as f64);
+        let df = (1.0 - coupon * annuity) / (1.0 + coupon);
+        annuity += df;
+
+        spot_tenors.push(year as f64);
+        spot_rates.push(df.powf(-1.0 / year as f64) - 1.0);
+    }
+
+    YieldCurve {
+        tenors: spot_tenors,
+        spot_rates,
+        interpolation,
+    }
+}
+
 /// Discount curve for reserve calculations
+///
+/// Flat rates use monthly discounting at rate/12. A term structure, when
+/// set, takes precedence for its benefit track; death benefits fall back to
+/// the elective term structure unless a separate death benefit rate is set.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DiscountCurve {
     /// Primary valuation interest rate (annual)
@@ -18,9 +282,11 @@ pub struct DiscountCurve {
     /// If None, uses valuation_rate
     pub death_benefit_rate: Option<f64>,
 
-    /// Optional: spot rate curve for more precise discounting
-    /// Index = month, value = annual spot rate to that point
-    pub spot_rates: Option<Vec<f64>>,
+    /// Optional: term structure for elective benefits
+    pub elective_curve: Option<YieldCurve>,
+
+    /// Optional: term structure for death benefits
+    pub death_benefit_curve: Option<YieldCurve>,
 }
 
 impl DiscountCurve {
@@ -29,7 +295,8 @@ impl DiscountCurve {
         Self {
             valuation_rate: annual_rate,
             death_benefit_rate: None,
-            spot_rates: None,
+            elective_curve: None,
+            death_benefit_curve: None,
         }
     }
 
@@ -38,20 +305,47 @@ impl DiscountCurve {
         Self {
             valuation_rate,
             death_benefit_rate: Some(death_benefit_rate),
-            spot_rates: None,
+            elective_curve: None,
+            death_benefit_curve: None,
+        }
+    }
+
+    /// Create discount curve from a term structure (used for all benefits)
+    pub fn from_yield_curve(curve: YieldCurve) -> Self {
+        Self {
+            valuation_rate: curve.spot_rate(0.0),
+            death_benefit_rate: None,
+            elective_curve: Some(curve),
+            death_benefit_curve: None,
         }
     }
 
     /// Create discount curve from spot rate curve
+    /// Index = month, value = annual spot rate to that point
     pub fn from_spot_curve(spot_rates: Vec<f64>) -> Self {
         let valuation_rate = spot_rates.first().copied().unwrap_or(0.0);
+        if spot_rates.len() < 2 {
+            return Self::single_rate(valuation_rate);
+        }
+
+        let tenors = (1..spot_rates.len()).map(|m| m as f64 / 12.0).collect();
+        let curve = YieldCurve {
+            tenors,
+            spot_rates: spot_rates[1..].to_vec(),
+            interpolation: Interpolation::Linear,
+        };
         Self {
             valuation_rate,
-            death_benefit_rate: None,
-            spot_rates: Some(spot_rates),
+            ..Self::from_yield_curve(curve)
         }
     }
 
+    /// Use a separate term structure for death benefits
+    pub fn with_death_benefit_curve(mut self, curve: YieldCurve) -> Self {
+        self.death_benefit_curve = Some(curve);
+        self
+    }
+
     /// Get monthly discount factor for elective benefits
     pub fn elective_discount_factor(&self) -> f64 {
         1.0 / (1.0 + self.valuation_rate / 12.0)
@@ -65,19 +359,21 @@ impl DiscountCurve {
 
     /// Calculate discount factor to a specific month for elective benefits
     pub fn discount_to_month_elective(&self, months: u32) -> f64 {
-        if let Some(ref spots) = self.spot_rates {
-            if (months as usize) < spots.len() {
-                let spot = spots[months as usize];
-                return (1.0 + spot).powf(-(months as f64) / 12.0);
-            }
+        match &self.elective_curve {
+            Some(curve) => curve.discount_to_month(months),
+            None => self.elective_discount_factor().powi(months as i32),
         }
-
-        self.elective_discount_factor().powi(months as i32)
     }
 
     /// Calculate discount factor to a specific month for death benefits
     pub fn discount_to_month_death(&self, months: u32) -> f64 {
-        self.death_benefit_discount_factor().powi(months as i32)
+        if let Some(curve) = &self.death_benefit_curve {
+            return curve.discount_to_month(months);
+        }
+        match (&self.elective_curve, self.death_benefit_rate) {
+            (Some(curve), None) => curve.discount_to_month(months),
+            _ => self.death_benefit_discount_factor().powi(months as i32),
+        }
     }
 
     /// Calculate present value of a stream of elective benefits
@@ -181,4 +477,110 @@ mod tests {
         // Expected: 100 * (1 - 1.005^-12) / 0.005 ≈ 1162.62
         assert!((pv - 1162.62).abs() < 1.0);
     }
+
+    #[test]
+    fn test_spot_curve_interpolation() {
+        let curve = YieldCurve::new(
+            CurveType::Spot,
+            vec![1.0, 5.0, 10.0],
+            vec![0.03, 0.04, 0.05],
+            Interpolation::Linear,
+        )
+        .unwrap();
+
+        assert!((curve.spot_rate(3.0) - 0.035).abs() < 1e-12);
+        // Flat before the first tenor and beyond the last
+        assert_eq!(curve.spot_rate(0.5), 0.03);
+        assert_eq!(curve.spot_rate(30.0), 0.05);
+        assert!((curve.discount_factor(5.0) - 1.04_f64.powf(-5.0)).abs() < 1e-12);
+
+        // Log-linear interpolation holds the forward rate flat between tenors
+        let flat_forward = YieldCurve::new(
+            CurveType::Spot,
+            vec![1.0, 5.0, 10.0],
+            vec![0.03, 0.04, 0.05],
+            Interpolation::LogLinear,
+        )
+        .unwrap();
+        let f1 = flat_forward.forward_rate(1.0, 2.0);
+        let f2 = flat_forward.forward_rate(3.0, 5.0);
+        assert!((f1 - f2).abs() < 1e-10);
+        assert!((flat_forward.spot_rate(5.0) - 0.04).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_forward_and_par_curves() {
+        let forwards = YieldCurve::new(
+            CurveType::Forward,
+            vec![1.0, 2.0, 3.0],
+            vec![0.02, 0.04, 0.06],
+            Interpolation::LogLinear,
+        )
+        .unwrap();
+        assert!((forwards.forward_rate(1.0, 2.0) - 0.04).abs() < 1e-12);
+        assert!((forwards.forward_rate(2.0, 3.0) - 0.06).abs() < 1e-12);
+        assert!((forwards.spot_rate(2.0) - (1.02_f64 * 1.04).sqrt() + 1.0).abs() < 1e-12);
+
+        // Bootstrapped spots reprice the par yields
+        let par = YieldCurve::new(
+            CurveType::Par,
+            vec![0.5, 1.0, 2.0, 5.0, 10.0],
+            vec![0.030, 0.032, 0.035, 0.040, 0.045],
+            Interpolation::Linear,
+        )
+        .unwrap();
+        for (years, rate) in [(1, 0.032), (2, 0.035), (5, 0.040), (10, 0.045)] {
+            assert!((par.par_rate(years) - rate).abs() < 1e-10);
+        }
+        assert_eq!(par.spot_rate(0.5), 0.030);
+        assert!(par.spot_rate(10.0) > 0.045);
+
+        assert!(YieldCurve::new(CurveType::Spot, vec![2.0, 1.0], vec![0.03, 0.04], Interpolation::Linear).is_err());
+        assert!(YieldCurve::new(CurveType::Spot, vec![1.0], vec![], Interpolation::Linear).is_err());
+    }
+
+    #[test]
+    fn test_curve_from_csv_with_spread() {
+        let data = "tenor,rate\n10,0.042\n1,0.035\n5,0.038\n";
+        let treasury = YieldCurve::from_reader(data.as_bytes(), CurveType::Spot, Interpolation::Linear).unwrap();
+        assert_eq!(treasury.tenors(), &[1.0, 5.0, 10.0]);
+
+        let discount = treasury.with_spread(0.01);
+        assert!((discount.spot_rate(5.0) - 0.048).abs() < 1e-12);
+
+        // A bad row is reported with its line
+        let bad = "tenor,rate\n1,0.035\n5,n/a\n";
+        match YieldCurve::from_reader(bad.as_bytes(), CurveType::Spot, Interpolation::Linear) {
+            Err(ActuarialError::AssumptionMalformed { line, message, .. }) => {
+                assert_eq!(line, 3);
+                assert!(message.contains("rate"), "{}", message);
+            }
+            other => panic!("expected a malformed curve error, got {:?}", other),
+        }
+
+        let missing = YieldCurve::from_csv(Path::new("data/no_such_curve.csv"), CurveType::Spot, Interpolation::Linear);
+        assert!(matches!(missing, Err(ActuarialError::AssumptionFileMissing { .. })));
+    }
+
+    #[test]
+    fn test_discount_curve_uses_term_structure() {
+        let curve = DiscountCurve::from_yield_curve(YieldCurve::flat(0.05));
+        assert!((curve.discount_to_month_elective(12) - 1.0 / 1.05).abs() < 1e-12);
+        assert!((curve.discount_to_month_death(24) - 1.05_f64.powi(-2)).abs() < 1e-12);
+
+        // A separate death benefit rate overrides the elective curve for death benefits
+        let curve = DiscountCurve {
+            death_benefit_rate: Some(0.04),
+            ..curve
+        };
+        let flat_death: f64 = (1.0_f64 / (1.0 + 0.04 / 12.0)).powi(12);
+        assert!((curve.discount_to_month_death(12) - flat_death).abs() < 1e-12);
+
+        let curve = curve.with_death_benefit_curve(YieldCurve::flat(0.03));
+        assert!((curve.discount_to_month_death(12) - 1.0 / 1.03).abs() < 1e-12);
+
+        // Monthly spot curve: index = month
+        let spots = DiscountCurve::from_spot_curve(vec![0.04, 0.04, 0.05]);
+        assert!((spots.discount_to_month_elective(2) - 1.05_f64.powf(-2.0 / 12.0)).abs() < 1e-12);
+    }
 }
@@ -28,7 +28,7 @@
 //! };
 //!
 //! let mut calculator = CARVMCalculator::new(assumptions, config);
-//! let reserve = calculator.calculate_reserve(&policy, 0);
+//! let reserve = calculator.calculate_reserve(&policy, 0)?;
 //! println!("Reserve: {:.2}", reserve.gross_reserve);
 //! ```
 
@@ -37,6 +37,13 @@ mod discount;
 mod benefits;
 mod carvm;
 mod cache;
+mod ag33;
+mod ag35;
+mod vm22;
+mod batch;
+mod attribution;
+mod trace;
+mod guaranteed_benefit;
 
 // Re-export public types
 pub use types::{
@@ -45,22 +52,88 @@ pub use types::{
     ReserveResult,
     ReserveComponents,
     ReserveMethod,
+    InforceSnapshot,
+    BenefitStream,
+    StreamElection,
 };
 
-pub use discount::DiscountCurve;
+pub use discount::{DiscountCurve, YieldCurve, CurveType, Interpolation};
 
 pub use carvm::{
     CARVMCalculator,
     CARVMConfig,
     CARVMMethod,
+    HybridValidationStats,
+    SolverDiscrepancy,
 };
 
 pub use cache::{
     CachedReservePath,
     RollForwardResult,
+    RevalidationCriteria,
+    RevalidationReason,
+    CacheStats,
 };
 
-pub use benefits::BenefitCalculator;
+pub use ag33::{
+    AG33Calculator,
+    AG33Config,
+};
+
+pub use ag35::{
+    AG35Calculator,
+    AG35Config,
+    AG35Type,
+};
+
+pub use vm22::{
+    VM22Calculator,
+    VM22Config,
+    VM22Scenario,
+    VM22ScenarioParams,
+    VM22Valuation,
+    ScenarioReserve,
+    conditional_tail_expectation,
+};
+
+pub use batch::{
+    BatchReserveCalculator,
+    BatchReserveResult,
+    SegmentKey,
+    SegmentTotals,
+    aggregate_by_segment,
+};
+
+pub use guaranteed_benefit::{
+    GuaranteedBenefitCalculator,
+    GuaranteedBenefitConfig,
+    GuaranteedBenefitReserve,
+    StressPath,
+};
+
+pub use trace::{
+    ActivationTest,
+    PathTraceRow,
+    ReserveTrace,
+    write_activation_csv,
+    write_path_csv,
+};
+
+pub use attribution::{
+    AnalysisOfChange,
+    AnalysisOfChangeResult,
+    ChangeStep,
+    ReserveAttribution,
+    StepImpact,
+    ValuationBasis,
+    ValuationPosition,
+};
+
+pub use benefits::{BenefitCalculator, DecrementTable, AccumulationPoint, IndexCreditAssumption};
+
+use crate::assumptions::Assumptions;
+use crate::error::ActuarialError;
+use crate::projection::HedgeParams;
 
 // Re-export the config for external use
 // (ReserveCalcConfig is defined below in this file)
@@ -79,6 +152,21 @@ pub struct ReserveCalcConfig {
 
     /// Valuation month (typically 0 for issue-date reserves)
     pub valuation_month: u32,
+
+    /// Also calculate reserves at every policy anniversary
+    /// (reserves between anniversaries are interpolated for the per-month columns;
+    /// without this the per-month columns are left unpopulated)
+    pub anniversary_reserves: bool,
+
+    /// Option budget assumptions (used when method is AG35)
+    pub hedge_params: HedgeParams,
+
+    /// VM-22 scenario and projection settings (used when method is VM22)
+    pub vm22_config: VM22Config,
+
+    /// GLWB guaranteed-benefit reserve under a stress path, reported
+    /// alongside the base reserve (None = not calculated)
+    pub guaranteed_benefit: Option<GuaranteedBenefitConfig>,
 }
 
 impl Default for ReserveCalcConfig {
@@ -87,6 +175,10 @@ impl Default for ReserveCalcConfig {
             method: ReserveMethod::CARVM,
             carvm_config: CARVMConfig::default(),
             valuation_month: 0,
+            anniversary_reserves: false,
+            hedge_params: HedgeParams::default(),
+            vm22_config: VM22Config::default(),
+            guaranteed_benefit: None,
         }
     }
 }
@@ -105,6 +197,10 @@ impl ReserveCalcConfig {
                 ..Default::default()
             },
             valuation_month: 0,
+            anniversary_reserves: false,
+            hedge_params: HedgeParams::default(),
+            vm22_config: VM22Config::default(),
+            guaranteed_benefit: None,
         }
     }
 
@@ -120,39 +216,123 @@ impl ReserveCalcConfig {
                 ..Default::default()
             },
             valuation_month: 0,
+            anniversary_reserves: false,
+            hedge_params: HedgeParams::default(),
+            vm22_config: VM22Config::default(),
+            guaranteed_benefit: None,
         }
     }
 
+    /// Set the reserve method
+    pub fn with_method(mut self, method: ReserveMethod) -> Self {
+        self.method = method;
+        self
+    }
+
     /// Set the valuation month
     pub fn at_month(mut self, month: u32) -> Self {
         self.valuation_month = month;
         self
     }
+
+    /// Also calculate reserves at every policy anniversary
+    pub fn with_anniversary_reserves(mut self) -> Self {
+        self.anniversary_reserves = true;
+        self
+    }
+
+    /// Also calculate the GLWB guaranteed-benefit reserve at the valuation month
+    pub fn with_guaranteed_benefit(mut self, config: GuaranteedBenefitConfig) -> Self {
+        self.guaranteed_benefit = Some(config);
+        self
+    }
+
+    /// Build the reserve calculator for the configured method
+    pub fn build_calculator(&self, assumptions: Assumptions) -> Box<dyn ReserveCalculator> {
+        match self.method {
+            ReserveMethod::AG35Type1 | ReserveMethod::AG35Type2 => {
+                let config = AG35Config {
+                    carvm_config: self.carvm_config.clone(),
+                    hedge_params: self.hedge_params.clone(),
+                    ..Default::default()
+                };
+                let method = if self.method == ReserveMethod::AG35Type1 {
+                    AG35Type::Type1
+                } else {
+                    AG35Type::Type2
+                };
+                Box::new(AG35Calculator::new(assumptions, config, method))
+            }
+            ReserveMethod::VM22 { .. } => {
+                Box::new(VM22Calculator::new(assumptions, self.vm22_config.clone()))
+            }
+            ReserveMethod::AG33 => {
+                let config = AG33Config {
+                    carvm_config: self.carvm_config.clone(),
+                    ..Default::default()
+                };
+                Box::new(AG33Calculator::new(assumptions, config))
+            }
+            ReserveMethod::CARVM => {
+                Box::new(CARVMCalculator::new(assumptions, self.carvm_config.clone()))
+            }
+        }
+    }
 }
 
 /// Trait for reserve calculators
 ///
 /// Implement this trait to create custom reserve calculation methods.
-pub trait ReserveCalculator {
+/// Calculators are `Send` so batches can run one per shard across threads.
+/// Calculations fail when the assumptions cannot value the policy (a joint
+/// contract without joint payout factors).
+pub trait ReserveCalculator: Send {
     /// Calculate reserve for a policy at a given valuation month
     fn calculate_reserve(
         &mut self,
         policy: &crate::policy::Policy,
         valuation_month: u32,
-    ) -> ReserveResult;
+    ) -> Result<ReserveResult, ActuarialError>;
+
+    /// Calculate reserve from the in-force state at the valuation month
+    ///
+    /// Use this with a `ProjectionState` snapshot so later valuations reflect
+    /// what actually happened to the policy. Such a snapshot is the cohort
+    /// (persistency-weighted AV, BB and guaranteed AV), so the reserve is the
+    /// cohort's; `calculate_reserve` rolls values forward per surviving policy.
+    /// The default re-seasons the policy to the valuation month and values it there.
+    fn calculate_reserve_from_state(
+        &mut self,
+        policy: &crate::policy::Policy,
+        valuation_month: u32,
+        inforce: &InforceSnapshot,
+    ) -> Result<ReserveResult, ActuarialError> {
+        let seasoned = inforce.seasoned_policy(policy, valuation_month);
+        let mut result = self.calculate_reserve(&seasoned, 0)?;
+        result.valuation_date = valuation_month;
+        if result.optimal_activation_month != u32::MAX {
+            result.optimal_activation_month += valuation_month;
+        }
+        Ok(result)
+    }
 
     /// Calculate reserves for multiple policies (can be parallelized)
     fn calculate_reserves_batch(
         &mut self,
         policies: &[crate::policy::Policy],
         valuation_month: u32,
-    ) -> Vec<ReserveResult> {
+    ) -> Result<Vec<ReserveResult>, ActuarialError> {
         policies
             .iter()
             .map(|p| self.calculate_reserve(p, valuation_month))
             .collect()
     }
 
+    /// Cache counters (zero for calculators without a cache)
+    fn cache_statistics(&self) -> CacheStats {
+        CacheStats::default()
+    }
+
     /// Clear any cached data
     fn clear_cache(&mut self);
 }
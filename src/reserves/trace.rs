@@ -0,0 +1,167 @@
+//! Detailed reserve trace for audit
+//!
+//! Records the month-by-month benefit stream of the winning path and the PV
+//! of every tested activation month, so a solve can be reconciled line by
+//! line against the valuation spreadsheets. Enable with
+//! `CARVMConfig::detailed_trace`.
+
+use std::io::Write;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::ActuarialError;
+
+/// One month of the winning benefit stream
+///
+/// Death benefits are paid at the end of the month to lives surviving to its
+/// start; income is paid at the start of the month (annuity due).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PathTraceRow {
+    /// Projection month
+    pub month: u32,
+
+    /// Probability of surviving from valuation to the start of the month
+    pub survival: f64,
+
+    /// Monthly mortality rate
+    pub mortality_rate: f64,
+
+    /// Projected account value at the start of the month
+    pub account_value: f64,
+
+    /// Projected benefit base at the start of the month
+    pub benefit_base: f64,
+
+    /// Death benefit paid on death in the month
+    pub death_benefit: f64,
+
+    /// GLWB income paid at the start of the month
+    pub income_payment: f64,
+
+    /// Death benefit discount factor from valuation
+    pub death_discount: f64,
+
+    /// Elective (income) discount factor from valuation
+    pub elective_discount: f64,
+
+    /// PV at valuation of the month's death benefit and income
+    pub pv_contribution: f64,
+}
+
+/// PV of one tested activation month
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ActivationTest {
+    /// Activation month (None = never activate)
+    pub activation_month: Option<u32>,
+
+    pub death_benefit_pv: f64,
+    pub income_benefit_pv: f64,
+}
+
+impl ActivationTest {
+    /// Total PV of the path
+    pub fn total_pv(&self) -> f64 {
+        self.death_benefit_pv + self.income_benefit_pv
+    }
+}
+
+/// Detailed trace of a reserve solve
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReserveTrace {
+    pub policy_id: u32,
+    pub valuation_month: u32,
+
+    /// Activation month of the winning path (None = never activate)
+    pub activation_month: Option<u32>,
+
+    /// Month-by-month benefit stream of the winning path
+    pub path: Vec<PathTraceRow>,
+
+    /// Every activation month tested (empty once income is active)
+    pub tested: Vec<ActivationTest>,
+}
+
+impl ReserveTrace {
+    /// PV of the winning path (sum of the monthly contributions)
+    pub fn path_pv(&self) -> f64 {
+        self.path.iter().map(|row| row.pv_contribution).sum()
+    }
+}
+
+/// Write the winning paths of several traces as CSV, one row per policy month
+pub fn write_path_csv<W: Write>(traces: &[ReserveTrace], writer: W) -> Result<(), ActuarialError> {
+    let mut csv_writer = csv::Writer::from_writer(writer);
+    csv_writer.write_record([
+        "PolicyID",
+        "ValuationMonth",
+        "ActivationMonth",
+        "Month",
+        "Survival",
+        "MortalityRate",
+        "AccountValue",
+        "BenefitBase",
+        "DeathBenefit",
+        "IncomePayment",
+        "DeathDiscount",
+        "ElectiveDiscount",
+        "PVContribution",
+    ])?;
+
+    for trace in traces {
+        for row in &trace.path {
+            csv_writer.write_record([
+                trace.policy_id.to_string(),
+                trace.valuation_month.to_string(),
+                activation_label(trace.activation_month),
+                row.month.to_string(),
+                row.survival.to_string(),
+                row.mortality_rate.to_string(),
+                row.account_value.to_string(),
+                row.benefit_base.to_string(),
+                row.death_benefit.to_string(),
+                row.income_payment.to_string(),
+                row.death_discount.to_string(),
+                row.elective_discount.to_string(),
+                row.pv_contribution.to_string(),
+            ])?;
+        }
+    }
+
+    csv_writer.flush()?;
+    Ok(())
+}
+
+/// Write the tested activation months of several traces as CSV
+pub fn write_activation_csv<W: Write>(traces: &[ReserveTrace], writer: W) -> Result<(), ActuarialError> {
+    let mut csv_writer = csv::Writer::from_writer(writer);
+    csv_writer.write_record([
+        "PolicyID",
+        "ValuationMonth",
+        "ActivationMonth",
+        "DeathBenefitPV",
+        "IncomeBenefitPV",
+        "TotalPV",
+        "Optimal",
+    ])?;
+
+    for trace in traces {
+        for test in &trace.tested {
+            csv_writer.write_record([
+                trace.policy_id.to_string(),
+                trace.valuation_month.to_string(),
+                activation_label(test.activation_month),
+                test.death_benefit_pv.to_string(),
+                test.income_benefit_pv.to_string(),
+                test.total_pv().to_string(),
+                (test.activation_month == trace.activation_month).to_string(),
+            ])?;
+        }
+    }
+
+    csv_writer.flush()?;
+    Ok(())
+}
+
+fn activation_label(month: Option<u32>) -> String {
+    month.map_or_else(|| "Never".to_string(), |m| m.to_string())
+}
@@ -2,6 +2,13 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::assumptions::JointSurvival;
+use crate::policy::Policy;
+use crate::projection::ProjectionState;
+
+use super::trace::ReserveTrace;
+use super::guaranteed_benefit::GuaranteedBenefitReserve;
+
 /// State of a policy for reserve calculation
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PolicyState {
@@ -92,6 +99,148 @@ impl ReserveProjectionState {
     }
 }
 
+/// In-force values at a valuation date
+///
+/// Comes either from a `ProjectionState` produced by `ProjectionEngine` or
+/// from a seasoned policy record (`current_av`, `current_benefit_base`,
+/// `duration_months`).
+///
+/// The amounts are totals for `lives` lives in force. A projection state gives
+/// the cohort (the projected lives, net of decrements); a policy record and
+/// the reserve roll-forward give values per surviving policy (the record's
+/// `initial_pols`). Reserves scale with the amounts, so either basis values
+/// the same contract; `with_lives` restates one basis as the other.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct InforceSnapshot {
+    /// Account value at valuation
+    pub account_value: f64,
+
+    /// Benefit base at valuation
+    pub benefit_base: f64,
+
+    /// Guaranteed (nonforfeiture) value at valuation, if tracked
+    pub guaranteed_av: Option<f64>,
+
+    /// Whether GLWB income is already active
+    pub income_activated: bool,
+
+    /// Payout rate locked at activation
+    pub locked_payout_rate: Option<f64>,
+
+    /// Lives in force the amounts are for
+    #[serde(default = "default_lives")]
+    pub lives: f64,
+
+    /// Prior month's account value, for the lagged ITM (None = not tracked)
+    #[serde(default)]
+    pub prior_av: Option<f64>,
+
+    /// Prior month's benefit base, for the lagged ITM (None = not tracked)
+    #[serde(default)]
+    pub prior_benefit_base: Option<f64>,
+
+    /// Systematic withdrawals taken so far this policy year
+    #[serde(default)]
+    pub ytd_systematic_wd: f64,
+
+    /// Non-systematic withdrawals taken so far this policy year
+    #[serde(default)]
+    pub ytd_non_systematic_wd: f64,
+
+    /// Survival of each life on a joint contract (None = single life)
+    #[serde(default)]
+    pub joint_survival: Option<JointSurvival>,
+}
+
+/// Default lives for a deserialized snapshot (one policy)
+fn default_lives() -> f64 {
+    1.0
+}
+
+impl InforceSnapshot {
+    /// Values from the policy record (as of its `duration_months`), per surviving policy
+    pub fn from_policy(policy: &Policy) -> Self {
+        Self {
+            account_value: policy.starting_av(),
+            benefit_base: policy.starting_benefit_base(),
+            guaranteed_av: policy.current_guaranteed_av,
+            income_activated: policy.income_activated,
+            locked_payout_rate: policy.locked_payout_rate,
+            lives: policy.initial_pols,
+            prior_av: policy.prior_av,
+            prior_benefit_base: policy.prior_benefit_base,
+            ytd_systematic_wd: policy.ytd_systematic_wd,
+            ytd_non_systematic_wd: policy.ytd_non_systematic_wd,
+            joint_survival: policy.joint_life.as_ref().map(JointSurvival::from),
+        }
+    }
+
+    /// Values at the end of a projected month, for the cohort in force
+    pub fn from_projection_state(state: &ProjectionState) -> Self {
+        Self {
+            account_value: state.eop_av,
+            benefit_base: state.bop_benefit_base,
+            guaranteed_av: Some(state.guaranteed_av),
+            income_activated: state.income_activated,
+            locked_payout_rate: state.locked_payout_rate,
+            lives: state.lives,
+            prior_av: Some(state.prior_bop_av),
+            prior_benefit_base: Some(state.prior_bop_bb),
+            ytd_systematic_wd: state.ytd_systematic_wd,
+            ytd_non_systematic_wd: state.ytd_non_systematic_wd,
+            joint_survival: state.joint_survival,
+        }
+    }
+
+    /// The same in-force restated for `lives` lives (amounts scale with the lives)
+    pub fn with_lives(&self, lives: f64) -> Self {
+        let scale = if self.lives > 0.0 { lives / self.lives } else { 0.0 };
+        Self {
+            account_value: self.account_value * scale,
+            benefit_base: self.benefit_base * scale,
+            guaranteed_av: self.guaranteed_av.map(|gav| gav * scale),
+            lives,
+            prior_av: self.prior_av.map(|av| av * scale),
+            prior_benefit_base: self.prior_benefit_base.map(|bb| bb * scale),
+            ytd_systematic_wd: self.ytd_systematic_wd * scale,
+            ytd_non_systematic_wd: self.ytd_non_systematic_wd * scale,
+            ..*self
+        }
+    }
+
+    /// The policy re-seasoned to the valuation month, carrying these values
+    ///
+    /// The record's `initial_pols` becomes the snapshot's `lives`, so the
+    /// `current_*`, prior and YTD fields stay totals for the lives they
+    /// describe; joint survival carries over so last-survivor decrements
+    /// continue. Valuing or projecting the returned policy from month 0
+    /// continues the original policy from `valuation_month`.
+    pub fn seasoned_policy(&self, policy: &Policy, valuation_month: u32) -> Policy {
+        let mut seasoned = self.policy_with_survival(policy);
+        seasoned.duration_months += valuation_month;
+        seasoned.initial_pols = self.lives;
+        seasoned.current_av = Some(self.account_value);
+        seasoned.current_benefit_base = Some(self.benefit_base);
+        seasoned.current_guaranteed_av = self.guaranteed_av;
+        seasoned.income_activated = self.income_activated;
+        seasoned.locked_payout_rate = self.locked_payout_rate;
+        seasoned.prior_av = self.prior_av;
+        seasoned.prior_benefit_base = self.prior_benefit_base;
+        seasoned.ytd_systematic_wd = self.ytd_systematic_wd;
+        seasoned.ytd_non_systematic_wd = self.ytd_non_systematic_wd;
+        seasoned
+    }
+
+    /// The policy carrying this snapshot's joint survival, so contract
+    /// mortality and the joint payout start from the lives actually in force
+    pub fn policy_with_survival(&self, policy: &Policy) -> Policy {
+        match self.joint_survival {
+            Some(survival) => policy.clone().with_joint_survival(survival.first, survival.second),
+            None => policy.clone(),
+        }
+    }
+}
+
 /// Result of a reserve calculation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReserveResult {
@@ -122,6 +271,18 @@ pub struct ReserveResult {
 
     /// Cash surrender value at valuation date (for reference)
     pub csv_at_valuation: f64,
+
+    /// Integrated benefit stream producing the reserve (AG33)
+    #[serde(default)]
+    pub benefit_stream: Option<BenefitStream>,
+
+    /// Winning path and tested activation months (when detailed tracing is on)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace: Option<ReserveTrace>,
+
+    /// GLWB guaranteed-benefit reserve, held in addition to `gross_reserve`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guaranteed_benefit: Option<GuaranteedBenefitReserve>,
 }
 
 impl ReserveResult {
@@ -129,6 +290,11 @@ impl ReserveResult {
     pub fn is_csv_binding(&self) -> bool {
         (self.gross_reserve - self.csv_at_valuation).abs() < 0.01
     }
+
+    /// Base reserve plus the guaranteed-benefit reserve (if calculated)
+    pub fn total_reserve(&self) -> f64 {
+        self.gross_reserve + self.guaranteed_benefit.as_ref().map_or(0.0, |gb| gb.reserve)
+    }
 }
 
 /// Breakdown of reserve by benefit type
@@ -157,6 +323,45 @@ impl ReserveComponents {
     }
 }
 
+/// How an integrated benefit stream ends
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StreamElection {
+    /// Full surrender for the CSV at the start of the month
+    Surrender { month: u32 },
+
+    /// GLWB income activated at the start of the month
+    Annuitize { month: u32 },
+
+    /// Contract held in accumulation until death
+    Death,
+}
+
+/// Integrated benefit stream tested under AG33
+///
+/// Free partial withdrawals at the next `free_pwd_years` policy
+/// anniversaries, followed by the final election.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BenefitStream {
+    /// Anniversaries at which the full free withdrawal is taken
+    pub free_pwd_years: u32,
+
+    /// How the stream ends
+    pub election: StreamElection,
+}
+
+impl std::fmt::Display for BenefitStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.free_pwd_years > 0 {
+            write!(f, "free PWDs for {} year(s), then ", self.free_pwd_years)?;
+        }
+        match self.election {
+            StreamElection::Surrender { month } => write!(f, "surrender at month {}", month),
+            StreamElection::Annuitize { month } => write!(f, "activate income at month {}", month),
+            StreamElection::Death => write!(f, "hold until death"),
+        }
+    }
+}
+
 /// Method used for reserve calculation
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ReserveMethod {
@@ -251,4 +456,41 @@ mod tests {
 
         assert!((components.total() - 100_000.0).abs() < 0.01);
     }
+
+    #[test]
+    fn test_inforce_snapshot_seasoned_policy() {
+        use crate::policy::{QualStatus, Gender, CreditingStrategy, RollupType};
+
+        let policy = Policy::new(
+            1, QualStatus::Q, 65, Gender::Male, 130_000.0, 1.0, 100_000.0,
+            CreditingStrategy::Indexed, 10, 0.0475, 0.01, 0.3, RollupType::Simple,
+        );
+        let snapshot = InforceSnapshot {
+            account_value: 95_000.0,
+            benefit_base: 150_000.0,
+            guaranteed_av: None,
+            income_activated: false,
+            locked_payout_rate: None,
+            lives: 0.9,
+            prior_av: Some(95_500.0),
+            prior_benefit_base: Some(149_000.0),
+            ytd_systematic_wd: 0.0,
+            ytd_non_systematic_wd: 1_200.0,
+            joint_survival: None,
+        };
+
+        let seasoned = snapshot.seasoned_policy(&policy, 30);
+        assert_eq!(seasoned.attained_age(0), policy.attained_age(30));
+        assert_eq!(seasoned.policy_year(0), policy.policy_year(30));
+        assert!((seasoned.starting_av() - 95_000.0).abs() < 1e-9);
+        assert!((seasoned.starting_benefit_base() - 150_000.0).abs() < 1e-9);
+        assert_eq!(seasoned.initial_pols, 0.9);
+        assert_eq!(InforceSnapshot::from_policy(&seasoned), snapshot);
+
+        // Per surviving policy: the same contract restated for the record's lives
+        let per_policy = snapshot.with_lives(policy.initial_pols);
+        assert!((per_policy.account_value - 95_000.0 / 0.9).abs() < 1e-9);
+        assert!((per_policy.ytd_non_systematic_wd - 1_200.0 / 0.9).abs() < 1e-9);
+        assert_eq!(per_policy.lives, 1.0);
+    }
 }
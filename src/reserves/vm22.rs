@@ -0,0 +1,776 @@
+//! VM-22 principles-based reserve calculator
+//!
+//! Projects the contract with company experience assumptions (the predictive
+//! lapse model, PWD assumptions and mortality with improvement) through
+//! `ProjectionEngine` under each economic scenario:
+//! - **Deterministic reserve (DR)**: PV of net liability cashflows under the
+//!   deterministic scenario
+//! - **Stochastic reserve (SR)**: CTE70 of the scenario reserves, each the
+//!   greatest present value of accumulated deficiencies (GPVAD)
+//! - **Standard projection amount (SPA)**: the same measure under the
+//!   prescribed lapse assumption, flooring the modeled reserve
+//!
+//! The stochastic exclusion test compares the deterministic scenario against
+//! interest and index stresses; passing it waives the stochastic reserve.
+
+use crate::assumptions::Assumptions;
+use crate::error::ActuarialError;
+use crate::policy::Policy;
+use crate::projection::{
+    CashflowRow, CreditingApproach, HedgeParams, ProjectionConfig, ProjectionEngine,
+    DEFAULT_FIXED_ANNUAL_RATE,
+};
+
+use super::discount::YieldCurve;
+use super::benefits::inforce_cash_surrender_value;
+use super::types::{ReserveResult, ReserveComponents, ReserveMethod, InforceSnapshot};
+use super::ReserveCalculator;
+
+/// Scenario ID of the deterministic scenario
+pub const DETERMINISTIC_SCENARIO_ID: u32 = 0;
+
+/// One economic scenario for VM-22 projections
+#[derive(Debug, Clone, PartialEq)]
+pub struct VM22Scenario {
+    /// Scenario identifier (0 = deterministic)
+    pub scenario_id: u32,
+
+    /// Annual index returns by projection year (the last value repeats)
+    pub index_returns: Vec<f64>,
+
+    /// Annual discount (net asset earned) rates by projection year (the last value repeats)
+    pub discount_rates: Vec<f64>,
+
+    /// Treasury rate change for the interest-sensitive lapse term
+    pub treasury_change: f64,
+}
+
+impl VM22Scenario {
+    /// Scenario with a level index return and discount rate
+    pub fn level(scenario_id: u32, index_return: f64, discount_rate: f64) -> Self {
+        Self {
+            scenario_id,
+            index_returns: vec![index_return],
+            discount_rates: vec![discount_rate],
+            treasury_change: 0.0,
+        }
+    }
+
+    /// Index return for a projection year (0-based)
+    pub fn index_return(&self, year_index: usize) -> f64 {
+        path_value(&self.index_returns, year_index)
+    }
+
+    /// Discount rate for a projection year (0-based)
+    pub fn discount_rate(&self, year_index: usize) -> f64 {
+        path_value(&self.discount_rates, year_index)
+    }
+
+    /// Scenario discounting at the one-year forward rates of a term structure
+    /// (e.g. prescribed rates or Treasury + spread)
+    pub fn from_curve(scenario_id: u32, index_return: f64, curve: &YieldCurve, years: u32) -> Self {
+        Self {
+            scenario_id,
+            index_returns: vec![index_return],
+            discount_rates: curve_forwards(curve, years),
+            treasury_change: 0.0,
+        }
+    }
+
+    /// Copy of this scenario with index returns and rates shifted
+    /// Rate shifts also move the treasury change seen by dynamic lapse
+    pub fn shocked(&self, scenario_id: u32, index_shock: f64, rate_shock: f64) -> Self {
+        Self {
+            scenario_id,
+            index_returns: self.index_returns.iter().map(|r| r + index_shock).collect(),
+            discount_rates: self.discount_rates.iter().map(|r| (r + rate_shock).max(0.0)).collect(),
+            treasury_change: self.treasury_change + rate_shock,
+        }
+    }
+}
+
+fn path_value(path: &[f64], year_index: usize) -> f64 {
+    path.get(year_index).or(path.last()).copied().unwrap_or(0.0)
+}
+
+/// One-year forward rates for each projection year
+fn curve_forwards(curve: &YieldCurve, years: u32) -> Vec<f64> {
+    (0..years.max(1))
+        .map(|year| curve.forward_rate(year as f64, (year + 1) as f64))
+        .collect()
+}
+
+/// Parameters of the scenario generator
+///
+/// Index returns are independent normal draws each year; discount rates
+/// follow a random walk around the base path (the starting curve's forward
+/// rates, or the level starting rate), floored at zero.
+#[derive(Debug, Clone)]
+pub struct VM22ScenarioParams {
+    /// Number of stochastic scenarios
+    pub num_scenarios: u32,
+
+    /// Generator seed (the same seed reproduces the same scenario set)
+    pub seed: u64,
+
+    /// Years of each scenario path
+    pub projection_years: u32,
+
+    /// Mean annual index return
+    pub index_mean: f64,
+
+    /// Annual index return volatility
+    pub index_volatility: f64,
+
+    /// Starting discount rate (also the deterministic scenario rate)
+    pub discount_rate: f64,
+
+    /// Annual standard deviation of discount rate changes
+    pub rate_volatility: f64,
+
+    /// Starting term structure for the base rate path
+    /// None = level discount_rate
+    pub discount_curve: Option<YieldCurve>,
+}
+
+impl Default for VM22ScenarioParams {
+    fn default() -> Self {
+        Self {
+            num_scenarios: 200,
+            seed: 2022,
+            projection_years: 64,
+            index_mean: 0.06,
+            index_volatility: 0.15,
+            discount_rate: 0.045,
+            rate_volatility: 0.005,
+            discount_curve: None,
+        }
+    }
+}
+
+impl VM22ScenarioParams {
+    /// Deterministic scenario: mean index return along the base rate path
+    pub fn deterministic(&self) -> VM22Scenario {
+        match &self.discount_curve {
+            Some(curve) => VM22Scenario::from_curve(
+                DETERMINISTIC_SCENARIO_ID,
+                self.index_mean,
+                curve,
+                self.projection_years,
+            ),
+            None => VM22Scenario::level(DETERMINISTIC_SCENARIO_ID, self.index_mean, self.discount_rate),
+        }
+    }
+
+    /// Generate the stochastic scenario set (IDs 1..=num_scenarios)
+    pub fn generate(&self) -> Vec<VM22Scenario> {
+        let mut rng = NormalGenerator::new(self.seed);
+        let years = self.projection_years.max(1) as usize;
+        let base_rates = self.deterministic().discount_rates;
+
+        (1..=self.num_scenarios)
+            .map(|scenario_id| {
+                let index_returns = (0..years)
+                    .map(|_| self.index_mean + self.index_volatility * rng.next_normal())
+                    .collect();
+
+                let mut shock = 0.0;
+                let discount_rates = (0..years)
+                    .map(|year| {
+                        let rate = (path_value(&base_rates, year) + shock).max(0.0);
+                        shock += self.rate_volatility * rng.next_normal();
+                        rate
+                    })
+                    .collect();
+
+                VM22Scenario {
+                    scenario_id,
+                    index_returns,
+                    discount_rates,
+                    treasury_change: 0.0,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Standard normal draws from a seeded SplitMix64 stream (Box-Muller)
+struct NormalGenerator {
+    state: u64,
+    spare: Option<f64>,
+}
+
+impl NormalGenerator {
+    fn new(seed: u64) -> Self {
+        Self { state: seed, spare: None }
+    }
+
+    fn next_uniform(&mut self) -> f64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        // 53 random bits in (0, 1]
+        ((z >> 11) as f64 + 1.0) / (1u64 << 53) as f64
+    }
+
+    fn next_normal(&mut self) -> f64 {
+        if let Some(spare) = self.spare.take() {
+            return spare;
+        }
+        let radius = (-2.0 * self.next_uniform().ln()).sqrt();
+        let angle = 2.0 * std::f64::consts::PI * self.next_uniform();
+        self.spare = Some(radius * angle.sin());
+        radius * angle.cos()
+    }
+}
+
+/// Configuration for VM-22 reserve calculation
+#[derive(Debug, Clone)]
+pub struct VM22Config {
+    /// Scenario generator settings
+    pub scenario_params: VM22ScenarioParams,
+
+    /// Months projected per scenario
+    pub projection_months: u32,
+
+    /// Floor on the annual index credit
+    pub index_floor: f64,
+
+    /// Cap on the annual index credit
+    pub index_cap: f64,
+
+    /// Participation in the scenario index return
+    pub participation: f64,
+
+    /// Annual credited rate for Fixed crediting strategy
+    pub fixed_annual_rate: f64,
+
+    /// Hedge/derivative parameters passed to the projection
+    pub hedge_params: Option<HedgeParams>,
+
+    /// CTE level for the stochastic reserve (0.70 = average of the worst 30%)
+    pub cte_level: f64,
+
+    /// Prescribed annual lapse rate for the standard projection amount
+    pub standard_lapse_rate: f64,
+
+    /// Run the stochastic exclusion test (a pass waives the stochastic reserve)
+    pub exclusion_test: bool,
+
+    /// Exclusion ratio below which the stochastic reserve is waived
+    pub exclusion_threshold: f64,
+
+    /// Index return shock (+/-) for the exclusion test stresses
+    pub exclusion_index_shock: f64,
+
+    /// Discount rate shock (+/-) for the exclusion test stresses
+    pub exclusion_rate_shock: f64,
+}
+
+impl Default for VM22Config {
+    fn default() -> Self {
+        Self {
+            scenario_params: VM22ScenarioParams::default(),
+            projection_months: 768, // 64 years
+            index_floor: 0.0,
+            index_cap: 0.10,
+            participation: 1.0,
+            fixed_annual_rate: DEFAULT_FIXED_ANNUAL_RATE,
+            hedge_params: Some(HedgeParams::default()),
+            cte_level: 0.70,
+            standard_lapse_rate: 0.02,
+            exclusion_test: true,
+            exclusion_threshold: 0.045,
+            exclusion_index_shock: 0.10,
+            exclusion_rate_shock: 0.01,
+        }
+    }
+}
+
+/// Reserve under one scenario
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScenarioReserve {
+    /// Scenario identifier
+    pub scenario_id: u32,
+
+    /// Greatest present value of accumulated deficiencies
+    pub reserve: f64,
+}
+
+/// Full VM-22 valuation of a policy
+#[derive(Debug, Clone)]
+pub struct VM22Valuation {
+    /// Deterministic reserve
+    pub deterministic_reserve: f64,
+
+    /// Stochastic reserve (None when waived by the exclusion test)
+    pub stochastic_reserve: Option<f64>,
+
+    /// Standard projection amount
+    pub standard_projection_amount: f64,
+
+    /// Stochastic exclusion ratio (None when the test is not run)
+    pub exclusion_ratio: Option<f64>,
+
+    /// Scenario reserves under company assumptions (empty when excluded)
+    pub scenario_reserves: Vec<ScenarioReserve>,
+
+    /// PV of death benefits under the deterministic scenario
+    pub death_benefit_pv: f64,
+}
+
+impl VM22Valuation {
+    /// Modeled reserve: greater of DR and SR, floored at the SPA
+    pub fn reserve(&self) -> f64 {
+        self.deterministic_reserve
+            .max(self.stochastic_reserve.unwrap_or(f64::MIN))
+            .max(self.standard_projection_amount)
+    }
+
+    /// Whether the stochastic exclusion test waived the stochastic reserve
+    pub fn stochastic_excluded(&self) -> bool {
+        self.stochastic_reserve.is_none()
+    }
+
+    /// Scenario with the greatest reserve (the deterministic scenario if excluded)
+    pub fn worst_scenario_id(&self) -> u32 {
+        self.scenario_reserves
+            .iter()
+            .max_by(|a, b| a.reserve.total_cmp(&b.reserve))
+            .map(|s| s.scenario_id)
+            .unwrap_or(DETERMINISTIC_SCENARIO_ID)
+    }
+}
+
+/// Conditional tail expectation: average of the worst (1 - level) share of values
+pub fn conditional_tail_expectation(values: &[f64], level: f64) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| b.total_cmp(a));
+
+    // Tolerance keeps e.g. 10 * (1 - 0.7) from rounding up to 4
+    let tail_size = sorted.len() as f64 * (1.0 - level) - 1e-9;
+    let tail = (tail_size.ceil() as usize).clamp(1, sorted.len());
+    sorted[..tail].iter().sum::<f64>() / tail as f64
+}
+
+/// VM-22 principles-based reserve calculator
+pub struct VM22Calculator {
+    assumptions: Assumptions,
+    config: VM22Config,
+    deterministic: VM22Scenario,
+    scenarios: Vec<VM22Scenario>,
+}
+
+impl VM22Calculator {
+    /// Create a calculator, generating the scenario set from the config
+    pub fn new(assumptions: Assumptions, config: VM22Config) -> Self {
+        let deterministic = config.scenario_params.deterministic();
+        let scenarios = config.scenario_params.generate();
+        Self {
+            assumptions,
+            config,
+            deterministic,
+            scenarios,
+        }
+    }
+
+    /// Use a supplied scenario set instead of the generated one
+    pub fn with_scenarios(mut self, scenarios: Vec<VM22Scenario>) -> Self {
+        self.scenarios = scenarios;
+        self
+    }
+
+    /// Use a supplied deterministic scenario
+    pub fn with_deterministic_scenario(mut self, scenario: VM22Scenario) -> Self {
+        self.deterministic = scenario;
+        self
+    }
+
+    /// Stochastic scenario set
+    pub fn scenarios(&self) -> &[VM22Scenario] {
+        &self.scenarios
+    }
+
+    /// Value a policy from its in-force state at the valuation month
+    pub fn valuation(
+        &self,
+        policy: &Policy,
+        valuation_month: u32,
+        inforce: &InforceSnapshot,
+    ) -> Result<VM22Valuation, ActuarialError> {
+        let seasoned = inforce.seasoned_policy(policy, valuation_month);
+        let prescribed_lapse = Some(self.config.standard_lapse_rate);
+
+        let base_rows = self.project(&seasoned, &self.deterministic, None)?;
+        let deterministic_reserve = present_value(&self.deterministic, &base_rows, net_outflow);
+        let death_benefit_pv = present_value(&self.deterministic, &base_rows, |row| row.mortality_dec);
+
+        let exclusion_ratio = if self.config.exclusion_test {
+            Some(self.exclusion_ratio(&seasoned, &base_rows)?)
+        } else {
+            None
+        };
+        let excluded = exclusion_ratio.is_some_and(|ratio| ratio < self.config.exclusion_threshold);
+
+        if excluded {
+            let standard_rows = self.project(&seasoned, &self.deterministic, prescribed_lapse)?;
+            return Ok(VM22Valuation {
+                deterministic_reserve,
+                stochastic_reserve: None,
+                standard_projection_amount: present_value(&self.deterministic, &standard_rows, net_outflow),
+                exclusion_ratio,
+                scenario_reserves: Vec::new(),
+                death_benefit_pv,
+            });
+        }
+
+        let scenario_reserves: Vec<ScenarioReserve> = self
+            .scenarios
+            .iter()
+            .map(|scenario| {
+                Ok(ScenarioReserve {
+                    scenario_id: scenario.scenario_id,
+                    reserve: greatest_pv_accumulated_deficiency(scenario, &self.project(&seasoned, scenario, None)?),
+                })
+            })
+            .collect::<Result<_, ActuarialError>>()?;
+        let standard_reserves: Vec<f64> = self
+            .scenarios
+            .iter()
+            .map(|scenario| {
+                Ok(greatest_pv_accumulated_deficiency(scenario, &self.project(&seasoned, scenario, prescribed_lapse)?))
+            })
+            .collect::<Result<_, ActuarialError>>()?;
+
+        let reserves: Vec<f64> = scenario_reserves.iter().map(|s| s.reserve).collect();
+        Ok(VM22Valuation {
+            deterministic_reserve,
+            stochastic_reserve: Some(conditional_tail_expectation(&reserves, self.config.cte_level)),
+            standard_projection_amount: conditional_tail_expectation(&standard_reserves, self.config.cte_level),
+            exclusion_ratio,
+            scenario_reserves,
+            death_benefit_pv,
+        })
+    }
+
+    /// Stochastic exclusion ratio
+    ///
+    /// (greatest stressed scenario reserve - deterministic scenario reserve)
+    /// / PV of benefits under the deterministic scenario, with stresses
+    /// combining the +/- index and rate shocks.
+    fn exclusion_ratio(&self, policy: &Policy, base_rows: &[CashflowRow]) -> Result<f64, ActuarialError> {
+        let base = &self.deterministic;
+        let baseline = greatest_pv_accumulated_deficiency(base, base_rows);
+        let benefits_pv = present_value(base, base_rows, |row| row.mortality_dec + row.lapse_dec + row.pwd_dec);
+        if benefits_pv <= 0.0 {
+            return Ok(0.0);
+        }
+
+        let index_shock = self.config.exclusion_index_shock;
+        let rate_shock = self.config.exclusion_rate_shock;
+        let stresses = [
+            (-index_shock, -rate_shock),
+            (-index_shock, rate_shock),
+            (index_shock, -rate_shock),
+            (index_shock, rate_shock),
+        ];
+
+        let mut worst = f64::MIN;
+        for &(index, rate) in &stresses {
+            let stressed = base.shocked(base.scenario_id, index, rate);
+            worst = worst.max(greatest_pv_accumulated_deficiency(&stressed, &self.project(policy, &stressed, None)?));
+        }
+
+        Ok((worst - baseline) / benefits_pv)
+    }
+
+    /// Project the policy under a scenario with the projection engine
+    fn project(&self, policy: &Policy, scenario: &VM22Scenario, fixed_lapse_rate: Option<f64>) -> Result<Vec<CashflowRow>, ActuarialError> {
+        let config = ProjectionConfig {
+            fixed_lapse_rate,
+            ..self.projection_config(scenario)
+        };
+
+        Ok(ProjectionEngine::new(self.assumptions.clone(), config)
+            .project_policy(policy)?
+            .cashflows)
+    }
+
+    /// In-force values at the valuation month under the deterministic scenario,
+    /// per surviving policy
+    fn inforce_at_month(&self, policy: &Policy, month: u32) -> Result<InforceSnapshot, ActuarialError> {
+        if month == 0 {
+            return Ok(InforceSnapshot::from_policy(policy));
+        }
+
+        let state = ProjectionEngine::new(
+            self.assumptions.clone(),
+            ProjectionConfig {
+                projection_months: month,
+                ..self.projection_config(&self.deterministic)
+            },
+        )
+        .state_at_month(policy, month)?;
+
+        // A cohort that ran out before the valuation month has nothing in force
+        let state_lives = if state.projection_month < month { 0.0 } else { state.lives };
+        Ok(InforceSnapshot { lives: state_lives, ..InforceSnapshot::from_projection_state(&state) }
+            .with_lives(policy.initial_pols))
+    }
+
+    /// Projection settings for a scenario, company lapse assumptions
+    fn projection_config(&self, scenario: &VM22Scenario) -> ProjectionConfig {
+        ProjectionConfig {
+            projection_months: self.config.projection_months,
+            crediting: CreditingApproach::IndexPath {
+                floor: self.config.index_floor,
+                cap: self.config.index_cap,
+                participation: self.config.participation,
+                annual_returns: scenario.index_returns.clone(),
+                fixed_annual_rate: self.config.fixed_annual_rate,
+            },
+            detailed_output: false,
+            treasury_change: scenario.treasury_change,
+            fixed_lapse_rate: None,
+            hedge_params: self.config.hedge_params.clone(),
+            reserve_config: None,
+            reinsurance: None,
+        }
+    }
+}
+
+/// Net liability outflow for a month: benefits, expenses and commissions
+/// less chargebacks and hedge gains (the premium is the starting asset)
+fn net_outflow(row: &CashflowRow) -> f64 {
+    row.premium - row.total_net_cashflow
+}
+
+/// PV of a per-month amount at the scenario discount rates (end-of-month timing)
+fn present_value(scenario: &VM22Scenario, rows: &[CashflowRow], amount: impl Fn(&CashflowRow) -> f64) -> f64 {
+    let mut discount = 1.0;
+    rows.iter()
+        .enumerate()
+        .map(|(i, row)| {
+            discount /= (1.0 + scenario.discount_rate(i / 12)).powf(1.0 / 12.0);
+            amount(row) * discount
+        })
+        .sum()
+}
+
+/// Greatest present value of accumulated deficiencies, from zero starting assets
+///
+/// Surplus accumulates at the scenario discount rates net of liability
+/// outflows; the scenario reserve is the largest discounted deficiency
+/// (zero if the surplus never goes negative).
+///
+/// Starting at zero rather than at the starting asset amount is deliberate:
+/// assets earn the scenario discount rates, so starting assets A add exactly
+/// A to every discounted surplus, and A plus the greatest discounted
+/// deficiency (-A at the valuation date) is the same reserve for any A.
+fn greatest_pv_accumulated_deficiency(scenario: &VM22Scenario, rows: &[CashflowRow]) -> f64 {
+    let mut surplus = 0.0;
+    let mut discount = 1.0;
+    let mut greatest: f64 = 0.0;
+
+    for (i, row) in rows.iter().enumerate() {
+        let accumulation = (1.0 + scenario.discount_rate(i / 12)).powf(1.0 / 12.0);
+        surplus = surplus * accumulation - net_outflow(row);
+        discount /= accumulation;
+        greatest = greatest.max(-surplus * discount);
+    }
+
+    greatest
+}
+
+impl ReserveCalculator for VM22Calculator {
+    fn calculate_reserve(
+        &mut self,
+        policy: &Policy,
+        valuation_month: u32,
+    ) -> Result<ReserveResult, ActuarialError> {
+        let inforce = self.inforce_at_month(policy, valuation_month)?;
+        self.calculate_reserve_from_state(policy, valuation_month, &inforce)
+    }
+
+    fn calculate_reserve_from_state(
+        &mut self,
+        policy: &Policy,
+        valuation_month: u32,
+        inforce: &InforceSnapshot,
+    ) -> Result<ReserveResult, ActuarialError> {
+        let valuation = self.valuation(policy, valuation_month, inforce)?;
+
+        let csv = inforce_cash_surrender_value(&self.assumptions, policy, valuation_month, inforce);
+        let gross_reserve = valuation.reserve().max(csv);
+
+        Ok(ReserveResult {
+            policy_id: policy.policy_id,
+            valuation_date: valuation_month,
+            gross_reserve,
+            net_reserve: gross_reserve,
+            optimal_activation_month: u32::MAX,
+            reserve_components: ReserveComponents {
+                death_benefit_pv: valuation.death_benefit_pv,
+                income_benefit_pv: 0.0,
+                surrender_value_pv: if gross_reserve == csv { csv } else { 0.0 },
+                elective_benefit_pv: gross_reserve - valuation.death_benefit_pv,
+                free_pwd_pv: 0.0,
+            },
+            method: ReserveMethod::VM22 {
+                scenario_id: valuation.worst_scenario_id(),
+            },
+            from_cache: false,
+            csv_at_valuation: csv,
+            benefit_stream: None,
+            trace: None,
+            guaranteed_benefit: None,
+        })
+    }
+
+    fn clear_cache(&mut self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::{QualStatus, Gender, CreditingStrategy, RollupType};
+
+    fn test_policy() -> Policy {
+        Policy::new(
+            2800,
+            QualStatus::Q,
+            65,
+            Gender::Male,
+            130_000.0,
+            1.0,
+            100_000.0,
+            CreditingStrategy::Indexed,
+            10,
+            0.0475,
+            0.01,
+            0.3,
+            RollupType::Simple,
+        )
+    }
+
+    fn test_config() -> VM22Config {
+        VM22Config {
+            scenario_params: VM22ScenarioParams {
+                num_scenarios: 10,
+                projection_years: 30,
+                ..Default::default()
+            },
+            projection_months: 360,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_conditional_tail_expectation() {
+        let values: Vec<f64> = (1..=10).map(f64::from).collect();
+        // Worst 30% of 10 values: 10, 9, 8
+        assert!((conditional_tail_expectation(&values, 0.70) - 9.0).abs() < 1e-12);
+        assert_eq!(conditional_tail_expectation(&values, 1.0), 10.0);
+        assert_eq!(conditional_tail_expectation(&[], 0.70), 0.0);
+    }
+
+    #[test]
+    fn test_scenario_generation_is_reproducible() {
+        let params = test_config().scenario_params;
+        let first = params.generate();
+        assert_eq!(first, params.generate());
+        assert_eq!(first.len(), 10);
+        assert_eq!(first[0].scenario_id, 1);
+        assert_eq!(first[0].index_returns.len(), 30);
+        assert!(first.iter().flat_map(|s| &s.discount_rates).all(|&r| r >= 0.0));
+
+        let reseeded = VM22ScenarioParams { seed: 7, ..params }.generate();
+        assert_ne!(first, reseeded);
+
+        // Paths repeat their last value
+        let level = VM22Scenario::level(0, 0.05, 0.04);
+        assert_eq!(level.index_return(50), 0.05);
+        assert_eq!(level.discount_rate(50), 0.04);
+    }
+
+    #[test]
+    fn test_gpvad_is_independent_of_starting_assets() {
+        // Outflows of 1,000 a month for a year, then inflows of 2,000 a month
+        let scenario = VM22Scenario::level(0, 0.0, 0.04);
+        let rows: Vec<CashflowRow> = (1..=24)
+            .map(|m| CashflowRow {
+                total_net_cashflow: if m <= 12 { -1_000.0 } else { 2_000.0 },
+                ..CashflowRow::new(m)
+            })
+            .collect();
+        let reserve = greatest_pv_accumulated_deficiency(&scenario, &rows);
+
+        // The deficiency peaks at month 12, before the inflows
+        let v = 1.04_f64.powf(-1.0 / 12.0);
+        let expected: f64 = (1..=12).map(|m| 1_000.0 * v.powi(m)).sum();
+        assert!((reserve - expected).abs() < 1e-6);
+
+        // Starting assets plus the greatest discounted deficiency from them
+        for starting_assets in [5_000.0, expected, 50_000.0] {
+            let mut surplus = starting_assets;
+            let mut discount = 1.0;
+            let mut greatest = -starting_assets;
+            for (i, row) in rows.iter().enumerate() {
+                let accumulation = (1.0 + scenario.discount_rate(i / 12)).powf(1.0 / 12.0);
+                surplus = surplus * accumulation - net_outflow(row);
+                discount /= accumulation;
+                greatest = greatest.max(-surplus * discount);
+            }
+            assert!((starting_assets + greatest - reserve).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_vm22_reserve() {
+        let config = VM22Config {
+            exclusion_test: false,
+            ..test_config()
+        };
+        let mut calculator = VM22Calculator::new(Assumptions::default_pricing(), config);
+        let policy = test_policy();
+
+        let valuation = calculator.valuation(&policy, 0, &InforceSnapshot::from_policy(&policy)).unwrap();
+        assert_eq!(valuation.scenario_reserves.len(), 10);
+        let stochastic = valuation.stochastic_reserve.expect("stochastic reserve should be calculated");
+        assert!(stochastic > 0.0);
+        assert!(valuation.deterministic_reserve > 0.0);
+        assert!(valuation.reserve() >= stochastic);
+        assert!(valuation.reserve() >= valuation.deterministic_reserve);
+        assert!(valuation.reserve() >= valuation.standard_projection_amount);
+
+        let result = calculator.calculate_reserve(&policy, 0).unwrap();
+        assert_eq!(result.method, ReserveMethod::VM22 { scenario_id: valuation.worst_scenario_id() });
+        assert!(result.gross_reserve >= valuation.reserve());
+        assert!(result.gross_reserve >= result.csv_at_valuation);
+    }
+
+    #[test]
+    fn test_stochastic_exclusion() {
+        let config = VM22Config {
+            exclusion_threshold: f64::MAX,
+            ..test_config()
+        };
+        let calculator = VM22Calculator::new(Assumptions::default_pricing(), config);
+        let policy = test_policy();
+
+        let valuation = calculator.valuation(&policy, 0, &InforceSnapshot::from_policy(&policy)).unwrap();
+        assert!(valuation.stochastic_excluded());
+        assert!(valuation.exclusion_ratio.is_some());
+        assert!(valuation.scenario_reserves.is_empty());
+        assert_eq!(valuation.worst_scenario_id(), DETERMINISTIC_SCENARIO_ID);
+        assert_eq!(
+            valuation.reserve(),
+            valuation.deterministic_reserve.max(valuation.standard_projection_amount)
+        );
+    }
+}
@@ -4,6 +4,7 @@
 //! different configurations without re-reading CSV files.
 
 use crate::{Assumptions, Policy};
+use crate::error::ActuarialError;
 use crate::projection::{ProjectionEngine, ProjectionConfig, ProjectionResult};
 
 /// Pre-loaded scenario runner for efficient batch projections
@@ -33,14 +34,14 @@ impl ScenarioRunner {
     }
 
     /// Create runner by loading assumptions from CSV files
-    pub fn from_csv() -> Result<Self, Box<dyn std::error::Error>> {
+    pub fn from_csv() -> Result<Self, ActuarialError> {
         Ok(Self {
             base_assumptions: Assumptions::from_csv()?,
         })
     }
 
     /// Create runner from specific assumptions directory
-    pub fn from_csv_path(path: &std::path::Path) -> Result<Self, Box<dyn std::error::Error>> {
+    pub fn from_csv_path(path: &std::path::Path) -> Result<Self, ActuarialError> {
         Ok(Self {
             base_assumptions: Assumptions::from_csv_path(path)?,
         })
@@ -55,19 +56,37 @@ impl ScenarioRunner {
 
     /// Run a single projection with the given config
     /// Clones the base assumptions internally (very fast ~0.3μs)
-    pub fn run(&self, policy: &Policy, config: ProjectionConfig) -> ProjectionResult {
+    pub fn run(&self, policy: &Policy, config: ProjectionConfig) -> Result<ProjectionResult, ActuarialError> {
         let engine = ProjectionEngine::new(self.base_assumptions.clone(), config);
         engine.project_policy(policy)
     }
 
+    /// Run a single projection after validating the config and checking the
+    /// assumptions cover the policy
+    pub fn try_run(&self, policy: &Policy, config: ProjectionConfig) -> Result<ProjectionResult, ActuarialError> {
+        config.validate()?;
+        self.base_assumptions.check_policy(policy)?;
+        self.run(policy, config)
+    }
+
     /// Run projections for multiple policies with the same config
-    pub fn run_batch(&self, policies: &[Policy], config: ProjectionConfig) -> Vec<ProjectionResult> {
+    pub fn run_batch(&self, policies: &[Policy], config: ProjectionConfig) -> Result<Vec<ProjectionResult>, ActuarialError> {
         let engine = ProjectionEngine::new(self.base_assumptions.clone(), config);
         policies.iter().map(|p| engine.project_policy(p)).collect()
     }
 
+    /// Run a batch after validating the config and checking the assumptions
+    /// cover every policy
+    pub fn try_run_batch(&self, policies: &[Policy], config: ProjectionConfig) -> Result<Vec<ProjectionResult>, ActuarialError> {
+        config.validate()?;
+        for policy in policies {
+            self.base_assumptions.check_policy(policy)?;
+        }
+        self.run_batch(policies, config)
+    }
+
     /// Run multiple scenarios (different configs) for a single policy
-    pub fn run_scenarios(&self, policy: &Policy, configs: &[ProjectionConfig]) -> Vec<ProjectionResult> {
+    pub fn run_scenarios(&self, policy: &Policy, configs: &[ProjectionConfig]) -> Result<Vec<ProjectionResult>, ActuarialError> {
         configs
             .iter()
             .map(|config| {
@@ -133,13 +152,32 @@ mod tests {
                 fixed_lapse_rate: Some(0.05),
                 hedge_params: Some(HedgeParams::default()),
                 reserve_config: None,
+                reinsurance: None,
             })
             .collect();
 
-        let results = runner.run_scenarios(&policy, &configs);
+        let results = runner.run_scenarios(&policy, &configs).unwrap();
         assert_eq!(results.len(), 3);
 
         // Higher credit rate should result in higher final AV
         assert!(results[2].summary().final_av > results[0].summary().final_av);
     }
+
+    #[test]
+    fn test_typed_errors() {
+        let runner = ScenarioRunner::new();
+        let config = ProjectionConfig { fixed_lapse_rate: Some(1.5), ..Default::default() };
+        assert!(matches!(runner.try_run(&test_policy(), config), Err(ActuarialError::Config(_))));
+
+        let config = ProjectionConfig { projection_months: 12, ..Default::default() };
+        assert_eq!(runner.try_run(&test_policy(), config).unwrap().cashflows.len(), 12);
+
+        // No joint payout factors ship with the default assumptions
+        let joint = test_policy().with_joint_life(68, crate::policy::Gender::Female);
+        let config = ProjectionConfig { projection_months: 12, ..Default::default() };
+        assert!(matches!(runner.try_run(&joint, config), Err(ActuarialError::Config(_))));
+
+        let missing = ScenarioRunner::from_csv_path(std::path::Path::new("data/no_such_assumptions"));
+        assert!(matches!(missing, Err(ActuarialError::AssumptionFileMissing { .. })));
+    }
 }